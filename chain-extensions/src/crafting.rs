@@ -0,0 +1,125 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::Encode;
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, Result, RetVal, SysConfig, UncheckedFrom,
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+use web3games_crafting::{CraftId, Ingredient, Outcome, Product, RecipeId};
+
+pub struct CraftingExtension;
+
+impl<C> ChainExtension<C> for CraftingExtension
+where
+	C: pallet_contracts::Config + web3games_crafting::Config,
+	<C as pallet_contracts::Config>::Call: From<web3games_crafting::Call<C>>,
+{
+	fn call<E>(func_id: u32, env: Environment<E, InitState>) -> Result<RetVal>
+	where
+		E: Ext<T = C>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		match func_id {
+			// create_recipe
+			65729 => {
+				let mut env = env.buf_in_buf_out();
+				let caller = env.ext().caller().clone();
+
+				let (ingredients, outcomes, cooldown, player_limit): (
+					Vec<Ingredient>,
+					Vec<Outcome<Vec<Product>>>,
+					<E::T as SysConfig>::BlockNumber,
+					Option<u32>,
+				) = env.read_as_unbounded(env.in_len())?;
+				env.charge_weight(10000)?;
+
+				let recipe_id = web3games_crafting::Pallet::<E::T>::do_create_recipe(
+					&caller,
+					ingredients,
+					outcomes,
+					cooldown,
+					player_limit,
+				)?;
+
+				let recipe_id_slice = recipe_id.encode();
+
+				env.write(&recipe_id_slice, false, None).map_err(|_| {
+					DispatchError::Other("ChainExtension failed to call create recipe")
+				})?;
+			},
+
+			// set_recipe_enabled
+			65730 => {
+				let mut env = env.buf_in_buf_out();
+				let caller = env.ext().caller().clone();
+				let (recipe_id, enabled): (RecipeId, bool) = env.read_as_unbounded(env.in_len())?;
+				env.charge_weight(10000)?;
+
+				web3games_crafting::Pallet::<E::T>::do_set_recipe_enabled(
+					&caller, recipe_id, enabled,
+				)?;
+			},
+
+			// craft
+			65731 => {
+				let mut env = env.buf_in_buf_out();
+				let caller = env.ext().caller().clone();
+				let (recipe_id, non_fungible_tokens): (RecipeId, Vec<u128>) =
+					env.read_as_unbounded(env.in_len())?;
+				env.charge_weight(10000)?;
+
+				let craft_id = web3games_crafting::Pallet::<E::T>::do_craft(
+					&caller,
+					recipe_id,
+					non_fungible_tokens,
+				)?;
+
+				let craft_id_slice = craft_id.encode();
+
+				env.write(&craft_id_slice, false, None)
+					.map_err(|_| DispatchError::Other("ChainExtension failed to call craft"))?;
+			},
+
+			// reveal_craft
+			65732 => {
+				let mut env = env.buf_in_buf_out();
+				let craft_id: CraftId = env.read_as()?;
+				env.charge_weight(10000)?;
+
+				let outcome = web3games_crafting::Pallet::<E::T>::do_reveal_craft(craft_id)?;
+
+				let outcome_slice = outcome.encode();
+
+				env.write(&outcome_slice, false, None).map_err(|_| {
+					DispatchError::Other("ChainExtension failed to call reveal craft")
+				})?;
+			},
+			_ => {
+				log::error!("call an unregistered `func_id`, func_id:{:}", func_id);
+				return Err(DispatchError::Other("Unimplemented func_id"))
+			},
+		}
+		Ok(RetVal::Converging(0))
+	}
+
+	fn enabled() -> bool {
+		true
+	}
+}
@@ -26,10 +26,12 @@ use pallet_contracts::chain_extension::{
 use sp_runtime::DispatchError;
 use sp_std::marker::PhantomData;
 
+mod crafting;
 mod token_fungible;
 mod token_multi;
 mod token_non_fungible;
 
+pub use crafting::CraftingExtension;
 pub use token_fungible::FungibleTokenExtension;
 pub use token_multi::MultiTokenExtension;
 pub use token_non_fungible::NonFungibleTokenExtension;
@@ -38,11 +40,14 @@ pub struct Web3GamesChainExtensions<C>(PhantomData<C>);
 
 impl<C> ChainExtension<C> for Web3GamesChainExtensions<C>
 where
-	C: pallet_contracts::Config + web3games_token_fungible::Config,
-	// + web3games_token_non_fungible::Config
+	C: pallet_contracts::Config
+		+ web3games_token_fungible::Config
+		+ web3games_token_non_fungible::Config
+		+ web3games_crafting::Config,
 	// + web3games_token_multi::Config,
 	<C as pallet_contracts::Config>::Call: From<web3games_token_fungible::Call<C>>,
-	// <C as pallet_contracts::Config>::Call: From<web3games_token_non_fungible::Call<C>>,
+	<C as pallet_contracts::Config>::Call: From<web3games_token_non_fungible::Call<C>>,
+	<C as pallet_contracts::Config>::Call: From<web3games_crafting::Call<C>>,
 	// <C as pallet_contracts::Config>::Call: From<web3games_token_multi::Call<C>>,
 {
 	fn call<E>(func_id: u32, env: Environment<E, InitState>) -> Result<RetVal>
@@ -72,8 +77,14 @@ where
 			// // 0x10041-0x10080(65601-65664): token-non-fungible
 			// id if id >= 65601 && id < 65664 => NonFungibleTokenExtension::call(func_id, env),
 			//
+			// 0x10049-0x1004a(65609-65610): token-non-fungible batch minting
+			65609 | 65610 => NonFungibleTokenExtension::call(func_id, env),
+
 			// // 0x10081-0x100c1(65665-65729): token-multi
 			// id if id >= 65665 && id < 65729 => MultiTokenExtension::call(func_id, env),
+
+			// 0x100c1-0x10100(65729-65792): crafting
+			id if id >= 65729 && id < 65792 => CraftingExtension::call(func_id, env),
 			_ => {
 				log::error!("call an unregistered `func_id`, func_id:{:}", func_id);
 				return Err(DispatchError::Other("Unimplemented func_id"))
@@ -22,6 +22,7 @@ use pallet_contracts::chain_extension::{
 };
 use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
+use web3games_token_non_fungible::WeightInfo;
 
 pub struct NonFungibleTokenExtension;
 
@@ -219,6 +220,59 @@ where
 					DispatchError::Other("ChainExtension failed to call create collection")
 				})?;
 			},
+			// mint_batch
+			65609 => {
+				let mut env = env.buf_in_buf_out();
+
+				let caller = env.ext().caller().clone();
+
+				let (id, to, token_ids): (
+					<E::T as web3games_token_non_fungible::Config>::NonFungibleTokenId,
+					<E::T as SysConfig>::AccountId,
+					Vec<<E::T as web3games_token_non_fungible::Config>::TokenId>,
+				) = env.read_as_unbounded(env.in_len())?;
+				env.charge_weight(
+					<E::T as web3games_token_non_fungible::Config>::WeightInfo::mint_batch(
+						token_ids.len() as u32,
+					),
+				)?;
+
+				let id = web3games_token_non_fungible::Pallet::<E::T>::do_batch_mint(
+					&caller, id, &to, token_ids,
+				)?;
+
+				let id_slice = id.encode();
+
+				env.write(&id_slice, false, None).map_err(|_| {
+					DispatchError::Other("ChainExtension failed to call mint_batch")
+				})?;
+			},
+			// mint_range
+			65610 => {
+				let mut env = env.buf_in_buf_out();
+
+				let caller = env.ext().caller().clone();
+
+				let (id, to, start, count): (
+					<E::T as web3games_token_non_fungible::Config>::NonFungibleTokenId,
+					<E::T as SysConfig>::AccountId,
+					<E::T as web3games_token_non_fungible::Config>::TokenId,
+					u32,
+				) = env.read_as_unbounded(env.in_len())?;
+				env.charge_weight(
+					<E::T as web3games_token_non_fungible::Config>::WeightInfo::mint_range(count),
+				)?;
+
+				let id = web3games_token_non_fungible::Pallet::<E::T>::do_mint_range(
+					&caller, id, &to, start, count,
+				)?;
+
+				let id_slice = id.encode();
+
+				env.write(&id_slice, false, None).map_err(|_| {
+					DispatchError::Other("ChainExtension failed to call mint_range")
+				})?;
+			},
 			_ => {
 				log::error!("call an unregistered `func_id`, func_id:{:}", func_id);
 				return Err(DispatchError::Other("Unimplemented func_id"))
@@ -130,6 +130,8 @@ where
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
 	C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash>,
 	C::Api: web3games_exchange_rpc::ExchangeRuntimeApi<Block, AccountId>,
+	C::Api: web3games_token_fungible_rpc::TokenFungibleRuntimeApi<Block, AccountId, BlockNumber>,
+	C::Api: web3games_token_multi_rpc::TokenMultiRuntimeApi<Block, AccountId>,
 	P: TransactionPool<Block = Block> + 'static,
 	A: ChainApi<Block = Block> + 'static,
 {
@@ -141,6 +143,8 @@ where
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use web3games_exchange_rpc::{ExchangeRpc, ExchangeRpcApiServer};
+	use web3games_token_fungible_rpc::{TokenFungibleRpc, TokenFungibleRpcApiServer};
+	use web3games_token_multi_rpc::{TokenMultiRpc, TokenMultiRpcApiServer};
 
 	let mut io = RpcModule::new(());
 	let FullDeps {
@@ -165,6 +169,8 @@ where
 	io.merge(TransactionPayment::new(Arc::clone(&client)).into_rpc())?;
 	io.merge(Contracts::new(Arc::clone(&client)).into_rpc())?;
 	io.merge(ExchangeRpc::new(Arc::clone(&client)).into_rpc())?;
+	io.merge(TokenFungibleRpc::new(Arc::clone(&client)).into_rpc())?;
+	io.merge(TokenMultiRpc::new(Arc::clone(&client)).into_rpc())?;
 
 	let mut signers = Vec::new();
 	if enable_dev_signer {
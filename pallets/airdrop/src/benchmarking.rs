@@ -0,0 +1,109 @@
+//! Benchmarking setup for web3games-airdrop
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Airdrop;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{assert_ok, traits::Currency};
+use frame_system::{Pallet as System, RawOrigin};
+use sp_runtime::traits::Saturating;
+use web3games_token_fungible::Pallet as TokenFungible;
+
+const W3G: u128 = 1;
+const W3G_DECIMALS: u128 = 1_000_000_000_000_000_000;
+
+fn setup<T: Config>() -> DispatchResult {
+	let alice: T::AccountId = account("alice", 0, 0);
+	<T as web3games_token_fungible::Config>::Currency::make_free_balance_be(
+		&alice,
+		<T as web3games_token_fungible::Config>::CreateTokenDeposit::get()
+			.saturating_mul(1_000u32.into()),
+	);
+
+	assert_ok!(TokenFungible::<T>::create_token(
+		RawOrigin::Signed(alice.clone()).into(),
+		FungibleTokenIdOf::<T>::unique_saturated_from(W3G),
+		b"TestToken".to_vec(),
+		b"TK".to_vec(),
+		18,
+		None
+	));
+	assert_ok!(TokenFungible::<T>::mint(
+		RawOrigin::Signed(alice.clone()).into(),
+		FungibleTokenIdOf::<T>::unique_saturated_from(W3G),
+		alice,
+		100 * W3G_DECIMALS,
+	));
+	Ok(())
+}
+
+/// A root with `bob` as leaf 0, and the proof of that leaf through `depth` levels.
+fn merkle_root<T: Config>(bob: &T::AccountId, amount: Balance, depth: u32) -> (H256, Vec<H256>) {
+	let proof: Vec<H256> = (0..depth).map(|i| H256::repeat_byte(i as u8)).collect();
+	let leaf = H256::from(keccak_256(&(0u32, bob, amount).encode()));
+	let root = proof.iter().fold(leaf, |hash, node| {
+		let (first, second) = if hash <= *node { (hash, *node) } else { (*node, hash) };
+		H256::from(keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+	});
+	(root, proof)
+}
+
+benchmarks! {
+	create_airdrop {
+		let alice: T::AccountId = account("alice", 0, 0);
+		setup::<T>()?;
+	}: _(RawOrigin::Signed(alice), Asset::FungibleToken(W3G), H256::repeat_byte(1), 10 * W3G_DECIMALS, T::BlockNumber::from(100u32))
+
+	claim {
+		let p in 1 .. 32;
+		let alice: T::AccountId = account("alice", 0, 0);
+		let bob: T::AccountId = account("bob", 0, 0);
+		setup::<T>()?;
+		let (root, proof) = merkle_root::<T>(&bob, W3G_DECIMALS, p);
+		assert_ok!(Airdrop::<T>::create_airdrop(
+				RawOrigin::Signed(alice.clone()).into(),
+				Asset::FungibleToken(W3G),
+				root,
+				10 * W3G_DECIMALS,
+				T::BlockNumber::from(100u32),
+		));
+	}: _(RawOrigin::Signed(bob), 0, 0, W3G_DECIMALS, proof)
+
+	reclaim {
+		let c in 0 .. <T as Config>::RemoveItemsLimit::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		setup::<T>()?;
+		assert_ok!(Airdrop::<T>::create_airdrop(
+				RawOrigin::Signed(alice.clone()).into(),
+				Asset::FungibleToken(W3G),
+				H256::repeat_byte(1),
+				10 * W3G_DECIMALS,
+				T::BlockNumber::from(100u32),
+		));
+		for word in 0..c {
+			ClaimedBitMap::<T>::insert(0, word, u128::MAX);
+		}
+		System::<T>::set_block_number(T::BlockNumber::from(100u32));
+	}: _(RawOrigin::Signed(alice), 0)
+
+	clear_claims {
+		let c in 0 .. <T as Config>::RemoveItemsLimit::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		setup::<T>()?;
+		assert_ok!(Airdrop::<T>::create_airdrop(
+				RawOrigin::Signed(alice.clone()).into(),
+				Asset::FungibleToken(W3G),
+				H256::repeat_byte(1),
+				10 * W3G_DECIMALS,
+				T::BlockNumber::from(100u32),
+		));
+		System::<T>::set_block_number(T::BlockNumber::from(100u32));
+		assert_ok!(Airdrop::<T>::reclaim(RawOrigin::Signed(alice.clone()).into(), 0));
+		for word in 0..c {
+			ClaimedBitMap::<T>::insert(0, word, u128::MAX);
+		}
+	}: _(RawOrigin::Signed(alice), 0)
+
+	impl_benchmark_test_suite!(Airdrop, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,322 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, PalletId};
+use frame_system::pallet_prelude::*;
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_runtime::{
+	traits::{AccountIdConversion, UniqueSaturatedFrom},
+	DispatchResult, RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+pub use pallet::*;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type FungibleTokenIdOf<T> = <T as web3games_token_fungible::Config>::FungibleTokenId;
+type MultiTokenIdOf<T> = <T as web3games_token_multi::Config>::MultiTokenId;
+type TokenIdOf<T> = <T as web3games_token_multi::Config>::TokenId;
+
+type FungibleTokenId = u128;
+type MultiTokenId = u128;
+type TokenId = u128;
+
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Asset {
+	FungibleToken(FungibleTokenId),
+	MultiToken(MultiTokenId, TokenId),
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Airdrop<AccountId, BlockNumber> {
+	pub distributor: AccountId,
+	pub escrow_account: AccountId,
+	pub asset: Asset,
+	pub merkle_root: H256,
+	pub total: Balance,
+	pub claimed: Balance,
+	pub expire_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + web3games_token_fungible::Config + web3games_token_multi::Config
+	{
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+
+		/// This pallet id.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of claim words removed by a single `reclaim` or `clear_claims`.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NoAvailableAirdropId,
+		AirdropNotFound,
+		InvalidExpiry,
+		AirdropExpired,
+		AirdropNotExpired,
+		AlreadyClaimed,
+		InvalidProof,
+		NotDistributor,
+		InsufficientAirdropBalance,
+		AirdropNotReclaimed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		AirdropCreated(u64, T::AccountId, Asset, H256, Balance),
+		Claimed(u64, u32, T::AccountId, Balance),
+		Reclaimed(u64, T::AccountId, Balance),
+		ClaimsCleared(u64, u32),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_airdrop_id)]
+	pub type NextAirdropId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn airdrops)]
+	pub type Airdrops<T: Config> =
+		StorageMap<_, Blake2_128, u64, Airdrop<T::AccountId, T::BlockNumber>>;
+
+	/// Claimed leaf indexes of an airdrop, 128 per word.
+	#[pallet::storage]
+	pub type ClaimedBitMap<T: Config> =
+		StorageDoubleMap<_, Blake2_128, u64, Twox64Concat, u32, u128, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow `total` tokens to be claimed by the leaves of `merkle_root` until `expire_at`.
+		///
+		/// A leaf is `keccak_256((index, account, amount).encode())`, pairs are hashed sorted.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_airdrop())]
+		pub fn create_airdrop(
+			origin: OriginFor<T>,
+			asset: Asset,
+			merkle_root: H256,
+			total: Balance,
+			expire_at: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(expire_at > Self::now(), Error::<T>::InvalidExpiry);
+
+			let airdrop_id = NextAirdropId::<T>::try_mutate(|id| -> Result<u64, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(1u64).ok_or(Error::<T>::NoAvailableAirdropId)?;
+				Ok(current_id)
+			})?;
+
+			let escrow_account = Self::escrow_account_id(airdrop_id);
+
+			Self::transfer_asset(asset, &sender, &escrow_account, total)?;
+
+			Airdrops::<T>::insert(
+				airdrop_id,
+				Airdrop {
+					distributor: sender.clone(),
+					escrow_account,
+					asset,
+					merkle_root,
+					total,
+					claimed: 0,
+					expire_at,
+				},
+			);
+
+			Self::deposit_event(Event::AirdropCreated(
+				airdrop_id,
+				sender,
+				asset,
+				merkle_root,
+				total,
+			));
+
+			Ok(())
+		}
+
+		/// Claim the `amount` of leaf `index` for the sender.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim(proof.len() as u32))]
+		pub fn claim(
+			origin: OriginFor<T>,
+			airdrop_id: u64,
+			index: u32,
+			amount: Balance,
+			proof: Vec<H256>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let mut airdrop = Airdrops::<T>::get(airdrop_id).ok_or(Error::<T>::AirdropNotFound)?;
+
+			ensure!(Self::now() < airdrop.expire_at, Error::<T>::AirdropExpired);
+			ensure!(!Self::is_claimed(airdrop_id, index), Error::<T>::AlreadyClaimed);
+
+			let leaf = keccak_256(&(index, &sender, amount).encode());
+			ensure!(
+				Self::verify_proof(airdrop.merkle_root, H256::from(leaf), &proof),
+				Error::<T>::InvalidProof
+			);
+
+			airdrop.claimed = airdrop
+				.claimed
+				.checked_add(amount)
+				.filter(|claimed| *claimed <= airdrop.total)
+				.ok_or(Error::<T>::InsufficientAirdropBalance)?;
+
+			Self::transfer_asset(airdrop.asset, &airdrop.escrow_account, &sender, amount)?;
+
+			ClaimedBitMap::<T>::mutate(airdrop_id, index / 128, |word| {
+				*word |= 1u128 << (index % 128)
+			});
+			Airdrops::<T>::insert(airdrop_id, airdrop);
+
+			Self::deposit_event(Event::Claimed(airdrop_id, index, sender, amount));
+
+			Ok(())
+		}
+
+		/// Return the unclaimed tokens of an expired airdrop to its distributor.
+		///
+		/// Removes up to `RemoveItemsLimit` claim words, `clear_claims` removes the rest.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reclaim(
+			<T as pallet::Config>::RemoveItemsLimit::get(),
+		))]
+		pub fn reclaim(origin: OriginFor<T>, airdrop_id: u64) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let airdrop = Airdrops::<T>::get(airdrop_id).ok_or(Error::<T>::AirdropNotFound)?;
+
+			ensure!(sender == airdrop.distributor, Error::<T>::NotDistributor);
+			ensure!(Self::now() >= airdrop.expire_at, Error::<T>::AirdropNotExpired);
+
+			let remaining = airdrop.total.saturating_sub(airdrop.claimed);
+			if remaining > 0 {
+				Self::transfer_asset(airdrop.asset, &airdrop.escrow_account, &sender, remaining)?;
+			}
+
+			Airdrops::<T>::remove(airdrop_id);
+
+			Self::deposit_event(Event::Reclaimed(airdrop_id, sender, remaining));
+
+			Self::clear_claimed_words(airdrop_id);
+
+			Ok(())
+		}
+
+		/// Remove up to `RemoveItemsLimit` claim words of a reclaimed airdrop.
+		///
+		/// Anyone can call this until all words are removed.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::clear_claims(
+			<T as pallet::Config>::RemoveItemsLimit::get(),
+		))]
+		pub fn clear_claims(origin: OriginFor<T>, airdrop_id: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(!Airdrops::<T>::contains_key(airdrop_id), Error::<T>::AirdropNotReclaimed);
+
+			Self::clear_claimed_words(airdrop_id);
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn now() -> T::BlockNumber {
+		frame_system::Pallet::<T>::block_number()
+	}
+
+	pub fn escrow_account_id(airdrop_id: u64) -> T::AccountId {
+		<T as pallet::Config>::PalletId::get().into_sub_account_truncating(airdrop_id)
+	}
+
+	fn clear_claimed_words(airdrop_id: u64) {
+		let limit = <T as pallet::Config>::RemoveItemsLimit::get() as usize;
+		let removed = ClaimedBitMap::<T>::drain_prefix(airdrop_id).take(limit).count();
+
+		Self::deposit_event(Event::ClaimsCleared(airdrop_id, removed as u32));
+	}
+
+	pub fn is_claimed(airdrop_id: u64, index: u32) -> bool {
+		ClaimedBitMap::<T>::get(airdrop_id, index / 128) & (1u128 << (index % 128)) != 0
+	}
+
+	/// Check `proof` leads from `leaf` to `root`, hashing each pair in sorted order.
+	pub fn verify_proof(root: H256, leaf: H256, proof: &[H256]) -> bool {
+		let computed = proof.iter().fold(leaf, |hash, node| {
+			let (first, second) = if hash <= *node { (hash, *node) } else { (*node, hash) };
+			H256::from(keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+		});
+		computed == root
+	}
+
+	fn transfer_asset(
+		asset: Asset,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		match asset {
+			Asset::FungibleToken(id) => web3games_token_fungible::Pallet::<T>::do_transfer(
+				FungibleTokenIdOf::<T>::unique_saturated_from(id),
+				from,
+				to,
+				amount,
+			),
+			Asset::MultiToken(id, token_id) =>
+				web3games_token_multi::Pallet::<T>::do_transfer_from(
+					from,
+					MultiTokenIdOf::<T>::unique_saturated_from(id),
+					from,
+					to,
+					TokenIdOf::<T>::unique_saturated_from(token_id),
+					amount,
+				),
+		}
+	}
+}
@@ -0,0 +1,193 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+pub use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+const CHARLIE: u64 = 3;
+const DAVE: u64 = 4;
+
+const W3G: u128 = 1;
+const ITEMS: u128 = 1;
+const SWORD: u128 = 7;
+
+fn leaf(index: u32, account: u64, amount: Balance) -> H256 {
+	H256::from(keccak_256(&(index, account, amount).encode()))
+}
+
+fn hash_pair(a: H256, b: H256) -> H256 {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+	H256::from(keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+}
+
+/// A tree of BOB: 100, CHARLIE: 200 and DAVE: 300, with the proof of each leaf.
+fn merkle_tree() -> (H256, Vec<Vec<H256>>) {
+	let leaves = [leaf(0, BOB, 100), leaf(1, CHARLIE, 200), leaf(2, DAVE, 300)];
+	let node = hash_pair(leaves[0], leaves[1]);
+	let root = hash_pair(node, leaves[2]);
+	(root, vec![vec![leaves[1], leaves[2]], vec![leaves[0], leaves[2]], vec![node]])
+}
+
+fn create_tokens() {
+	assert_ok!(TokenFungible::create_token(
+		Origin::signed(ALICE),
+		W3G,
+		b"W3G".to_vec(),
+		b"W3G".to_vec(),
+		18,
+		None
+	));
+	assert_ok!(TokenFungible::mint(Origin::signed(ALICE), W3G, ALICE, 1000));
+	assert_ok!(TokenMulti::create_token(
+		Origin::signed(ALICE),
+		ITEMS,
+		b"https://web3games.com/".to_vec()
+	));
+	assert_ok!(TokenMulti::mint(Origin::signed(ALICE), ITEMS, ALICE, SWORD, 1000));
+}
+
+#[test]
+fn create_airdrop_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		let (root, _) = merkle_tree();
+
+		assert_noop!(
+			Airdrop::create_airdrop(Origin::signed(ALICE), Asset::FungibleToken(W3G), root, 600, 1),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(Airdrop::create_airdrop(
+			Origin::signed(ALICE),
+			Asset::FungibleToken(W3G),
+			root,
+			600,
+			100
+		));
+
+		let escrow_account = Airdrop::escrow_account_id(0);
+		assert_eq!(Airdrop::next_airdrop_id(), 1);
+		assert_eq!(Airdrop::airdrops(0).unwrap().escrow_account, escrow_account);
+		assert_eq!(TokenFungible::balance_of(W3G, escrow_account), 600);
+		assert_eq!(TokenFungible::balance_of(W3G, ALICE), 400);
+	})
+}
+
+#[test]
+fn claim_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		let (root, proofs) = merkle_tree();
+		assert_ok!(Airdrop::create_airdrop(
+			Origin::signed(ALICE),
+			Asset::FungibleToken(W3G),
+			root,
+			600,
+			100
+		));
+
+		assert_noop!(
+			Airdrop::claim(Origin::signed(BOB), 0, 0, 150, proofs[0].clone()),
+			Error::<Test>::InvalidProof
+		);
+		assert_noop!(
+			Airdrop::claim(Origin::signed(CHARLIE), 0, 0, 100, proofs[0].clone()),
+			Error::<Test>::InvalidProof
+		);
+		assert_ok!(Airdrop::claim(Origin::signed(BOB), 0, 0, 100, proofs[0].clone()));
+		assert_noop!(
+			Airdrop::claim(Origin::signed(BOB), 0, 0, 100, proofs[0].clone()),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_ok!(Airdrop::claim(Origin::signed(DAVE), 0, 2, 300, proofs[2].clone()));
+
+		assert!(Airdrop::is_claimed(0, 0));
+		assert!(!Airdrop::is_claimed(0, 1));
+		assert_eq!(TokenFungible::balance_of(W3G, BOB), 100);
+		assert_eq!(TokenFungible::balance_of(W3G, DAVE), 300);
+		assert_eq!(Airdrop::airdrops(0).unwrap().claimed, 400);
+
+		System::set_block_number(100);
+		assert_noop!(
+			Airdrop::claim(Origin::signed(CHARLIE), 0, 1, 200, proofs[1].clone()),
+			Error::<Test>::AirdropExpired
+		);
+	})
+}
+
+#[test]
+fn reclaim_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		let (root, proofs) = merkle_tree();
+		assert_ok!(Airdrop::create_airdrop(
+			Origin::signed(ALICE),
+			Asset::MultiToken(ITEMS, SWORD),
+			root,
+			600,
+			100
+		));
+		assert_ok!(Airdrop::claim(Origin::signed(CHARLIE), 0, 1, 200, proofs[1].clone()));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (SWORD, CHARLIE)), 200);
+
+		assert_noop!(Airdrop::reclaim(Origin::signed(ALICE), 0), Error::<Test>::AirdropNotExpired);
+
+		System::set_block_number(100);
+		assert_noop!(Airdrop::reclaim(Origin::signed(BOB), 0), Error::<Test>::NotDistributor);
+		assert_ok!(Airdrop::reclaim(Origin::signed(ALICE), 0));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (SWORD, ALICE)), 800);
+		assert_eq!(Airdrop::airdrops(0), None);
+		assert_eq!(Airdrop::is_claimed(0, 1), false);
+		assert_noop!(Airdrop::reclaim(Origin::signed(ALICE), 0), Error::<Test>::AirdropNotFound);
+	})
+}
+
+#[test]
+fn clear_claims_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		let (root, _) = merkle_tree();
+		assert_ok!(Airdrop::create_airdrop(
+			Origin::signed(ALICE),
+			Asset::FungibleToken(W3G),
+			root,
+			600,
+			100
+		));
+		// Claims spread over 5 words, more than a call removes.
+		for word in 0..5 {
+			ClaimedBitMap::<Test>::insert(0, word, 1);
+		}
+		assert_noop!(
+			Airdrop::clear_claims(Origin::signed(BOB), 0),
+			Error::<Test>::AirdropNotReclaimed
+		);
+
+		System::set_block_number(100);
+		assert_ok!(Airdrop::reclaim(Origin::signed(ALICE), 0));
+		System::assert_last_event(crate::mock::Event::Airdrop(crate::Event::ClaimsCleared(0, 2)));
+		assert_eq!(ClaimedBitMap::<Test>::iter_prefix(0).count(), 3);
+
+		assert_ok!(Airdrop::clear_claims(Origin::signed(BOB), 0));
+		assert_ok!(Airdrop::clear_claims(Origin::signed(BOB), 0));
+		System::assert_last_event(crate::mock::Event::Airdrop(crate::Event::ClaimsCleared(0, 1)));
+		assert_eq!(ClaimedBitMap::<Test>::iter_prefix(0).count(), 0);
+	})
+}
@@ -0,0 +1,113 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for web3games_airdrop
+//!
+//! These are estimates from the storage accesses of each call, scaled from the benchmarked
+//! token pallets. They have not been benchmarked yet: run `scripts/generate-weights.sh` on
+//! reference hardware to replace this file with measured weights.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for web3games_airdrop.
+pub trait WeightInfo {
+	fn create_airdrop() -> Weight;
+	fn claim(p: u32, ) -> Weight;
+	fn reclaim(c: u32, ) -> Weight;
+	fn clear_claims(c: u32, ) -> Weight;
+}
+
+/// Weights for web3games_airdrop using the Web3Games node and recommended hardware.
+pub struct W3GWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
+	// Storage: Airdrop NextAirdropId (r:1 w:1)
+	// Storage: TokenFungible Balances (r:2 w:2)
+	// Storage: Airdrop Airdrops (r:0 w:1)
+	fn create_airdrop() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Airdrop Airdrops (r:1 w:1)
+	// Storage: Airdrop ClaimedBitMap (r:1 w:1)
+	// Storage: TokenFungible Balances (r:2 w:2)
+	fn claim(p: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((1_200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Airdrop Airdrops (r:1 w:1)
+	// Storage: TokenFungible Balances (r:2 w:2)
+	// Storage: Airdrop ClaimedBitMap (r:1 w:1)
+	fn reclaim(c: u32, ) -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add((1_100_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Airdrop Airdrops (r:1 w:0)
+	// Storage: Airdrop ClaimedBitMap (r:1 w:1)
+	fn clear_claims(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((1_100_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_airdrop() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn claim(p: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((1_200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn reclaim(c: u32, ) -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add((1_100_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn clear_claims(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((1_100_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+}
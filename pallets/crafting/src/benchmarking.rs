@@ -0,0 +1,116 @@
+//! Benchmarking setup for web3games-crafting
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Crafting;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{assert_ok, traits::Currency};
+use frame_system::{Pallet as System, RawOrigin};
+use sp_runtime::traits::{One, Saturating};
+use web3games_token_multi::Pallet as TokenMulti;
+
+const ITEMS: u128 = 1;
+
+fn setup<T: Config>() -> DispatchResult {
+	let alice: T::AccountId = account("alice", 0, 0);
+	<T as web3games_token_multi::Config>::Currency::make_free_balance_be(
+		&alice,
+		<T as web3games_token_multi::Config>::CreateTokenDeposit::get()
+			.saturating_mul(1_000u32.into()),
+	);
+
+	assert_ok!(TokenMulti::<T>::create_token(
+		RawOrigin::Signed(alice).into(),
+		MultiTokenIdOf::<T>::unique_saturated_from(ITEMS),
+		b"https://web3games.com/".to_vec(),
+	));
+	Ok(())
+}
+
+/// `i` ingredients of different multi tokens, each minted to `bob`.
+fn ingredients<T: Config>(bob: &T::AccountId, i: u32) -> Vec<Ingredient> {
+	let alice: T::AccountId = account("alice", 0, 0);
+	(0..i as u128)
+		.map(|token_id| {
+			assert_ok!(TokenMulti::<T>::mint(
+				RawOrigin::Signed(alice.clone()).into(),
+				MultiTokenIdOf::<T>::unique_saturated_from(ITEMS),
+				bob.clone(),
+				MultiTokenTokenIdOf::<T>::unique_saturated_from(token_id),
+				10,
+			));
+			Ingredient::MultiToken(ITEMS, token_id, 1)
+		})
+		.collect()
+}
+
+/// A single outcome of `p` multi tokens, numbered after the ingredients.
+fn outcomes(p: u32) -> Vec<Outcome<Vec<Product>>> {
+	let products = (0..p as u128)
+		.map(|token_id| Product::MultiToken(ITEMS, 1_000 + token_id, 1))
+		.collect();
+	vec![Outcome { weight: 1, products }]
+}
+
+benchmarks! {
+	create_recipe {
+		let i in 1 .. <T as Config>::MaxIngredients::get();
+		let p in 1 .. <T as Config>::MaxProducts::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		let bob: T::AccountId = account("bob", 0, 0);
+		setup::<T>()?;
+		let ingredients = ingredients::<T>(&bob, i);
+	}: _(RawOrigin::Signed(alice), ingredients, outcomes(p), T::BlockNumber::from(10u32), Some(10))
+
+	set_recipe_enabled {
+		let alice: T::AccountId = account("alice", 0, 0);
+		let bob: T::AccountId = account("bob", 0, 0);
+		setup::<T>()?;
+		let ingredients = ingredients::<T>(&bob, 1);
+		assert_ok!(Crafting::<T>::create_recipe(
+			RawOrigin::Signed(alice.clone()).into(),
+			ingredients,
+			outcomes(1),
+			T::BlockNumber::from(10u32),
+			None,
+		));
+	}: _(RawOrigin::Signed(alice), 0, false)
+
+	craft {
+		let i in 1 .. <T as Config>::MaxIngredients::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		let bob: T::AccountId = account("bob", 0, 0);
+		setup::<T>()?;
+		let ingredients = ingredients::<T>(&bob, i);
+		assert_ok!(Crafting::<T>::create_recipe(
+			RawOrigin::Signed(alice).into(),
+			ingredients,
+			outcomes(<T as Config>::MaxProducts::get()),
+			T::BlockNumber::from(10u32),
+			None,
+		));
+	}: _(RawOrigin::Signed(bob), 0, vec![])
+
+	reveal_craft {
+		let i in 1 .. <T as Config>::MaxIngredients::get();
+		let p in 1 .. <T as Config>::MaxProducts::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		let bob: T::AccountId = account("bob", 0, 0);
+		setup::<T>()?;
+		let ingredients = ingredients::<T>(&bob, i);
+		assert_ok!(Crafting::<T>::create_recipe(
+			RawOrigin::Signed(alice).into(),
+			ingredients,
+			outcomes(p),
+			T::BlockNumber::from(10u32),
+			None,
+		));
+		assert_ok!(Crafting::<T>::craft(RawOrigin::Signed(bob.clone()).into(), 0, vec![]));
+		let reveal_at = Crafting::<T>::pending_crafts(0).unwrap().reveal_at;
+		T::Randomness::set_seed(reveal_at, Default::default());
+		System::<T>::set_block_number(reveal_at.saturating_add(One::one()));
+	}: _(RawOrigin::Signed(bob), 0)
+
+	impl_benchmark_test_suite!(Crafting, crate::mock::new_test_ext(), crate::mock::Test);
+}
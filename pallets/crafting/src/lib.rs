@@ -0,0 +1,750 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, storage::with_storage_layer, PalletId};
+use frame_system::pallet_prelude::*;
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Hash, Saturating, UniqueSaturatedFrom, Zero},
+	DispatchResult, RuntimeDebug,
+};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use web3games_support::DelayedSeed;
+
+pub use pallet::*;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type FungibleTokenIdOf<T> = <T as web3games_token_fungible::Config>::FungibleTokenId;
+type NonFungibleTokenIdOf<T> = <T as web3games_token_non_fungible::Config>::NonFungibleTokenId;
+type NonFungibleTokenTokenIdOf<T> = <T as web3games_token_non_fungible::Config>::TokenId;
+type MultiTokenIdOf<T> = <T as web3games_token_multi::Config>::MultiTokenId;
+type MultiTokenTokenIdOf<T> = <T as web3games_token_multi::Config>::TokenId;
+
+type FungibleTokenId = u128;
+type NonFungibleTokenId = u128;
+type MultiTokenId = u128;
+type TokenId = u128;
+
+pub type RecipeId = u64;
+pub type CraftId = u64;
+
+pub type OutcomeOf<T> = Outcome<BoundedVec<Product, <T as Config>::MaxProducts>>;
+
+pub type RecipeOf<T> = Recipe<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	BoundedVec<Ingredient, <T as Config>::MaxIngredients>,
+	BoundedVec<OutcomeOf<T>, <T as Config>::MaxOutcomes>,
+>;
+
+pub type PendingCraftOf<T> = PendingCraft<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	BoundedVec<TokenId, <T as Config>::MaxIngredients>,
+>;
+
+/// What a player puts into a recipe.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Ingredient {
+	/// An amount of a multi token, burned from the player.
+	MultiToken(MultiTokenId, TokenId, Balance),
+	/// Any token of a non-fungible token, burned from the player.
+	NonFungibleToken(NonFungibleTokenId),
+	/// An amount of a fungible token, paid to the recipe owner.
+	FungibleToken(FungibleTokenId, Balance),
+}
+
+/// Ingredients are held in escrow until the outcome of a craft is known.
+#[derive(Clone, Copy)]
+enum Settlement {
+	/// Burn the tokens and pay the fees to the recipe owner.
+	Consume,
+	/// Give everything back to the player.
+	Refund,
+}
+
+/// What a recipe mints to the player.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Product {
+	/// An amount of a multi token.
+	MultiToken(MultiTokenId, TokenId, Balance),
+	/// A non-fungible token out of the given number, numbered from the given token id.
+	///
+	/// The range is reserved for the recipe when it is registered.
+	NonFungibleToken(NonFungibleTokenId, TokenId, u32),
+}
+
+/// The products a craft yields, with a `weight` chance out of the total of its recipe.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Outcome<Products> {
+	pub weight: u32,
+	pub products: Products,
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Recipe<AccountId, BlockNumber, Ingredients, Outcomes> {
+	/// The account that registered the recipe, it mints the products and receives the fees.
+	pub owner: AccountId,
+	pub ingredients: Ingredients,
+	pub outcomes: Outcomes,
+	/// The blocks a player has to wait between two crafts.
+	pub cooldown: BlockNumber,
+	/// The number of times a player can craft the recipe, unlimited if `None`.
+	pub player_limit: Option<u32>,
+	pub enabled: bool,
+	/// The number of times the recipe has been crafted.
+	pub crafted: u32,
+}
+
+/// A craft whose ingredients are in escrow, waiting for the hash of a later block.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PendingCraft<AccountId, BlockNumber, TokenIds> {
+	pub recipe_id: RecipeId,
+	pub who: AccountId,
+	/// The tokens put in for the non-fungible ingredients, in order.
+	pub non_fungible_tokens: TokenIds,
+	/// The block whose hash seeds the outcome.
+	pub reveal_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ web3games_token_fungible::Config
+		+ web3games_token_non_fungible::Config
+		+ web3games_token_multi::Config
+	{
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The crafting pallet id, its account holds the ingredients of pending crafts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The hashes of later blocks that seed the outcomes.
+		type Randomness: DelayedSeed<Self::BlockNumber, Self::Hash>;
+
+		/// The maximum number of ingredients of a recipe.
+		#[pallet::constant]
+		type MaxIngredients: Get<u32>;
+
+		/// The maximum number of outcomes of a recipe.
+		#[pallet::constant]
+		type MaxOutcomes: Get<u32>;
+
+		/// The maximum number of products of an outcome.
+		#[pallet::constant]
+		type MaxProducts: Get<u32>;
+
+		/// The maximum number of token id ranges recipes reserve in a non-fungible token.
+		#[pallet::constant]
+		type MaxReservedRanges: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NoAvailableRecipeId,
+		NoAvailableCraftId,
+		RecipeNotFound,
+		CraftNotFound,
+		NotRecipeOwner,
+		InvalidRecipe,
+		TooManyIngredients,
+		TooManyOutcomes,
+		TooManyProducts,
+		NoPermission,
+		RecipeDisabled,
+		CraftingCooldown,
+		PlayerLimitReached,
+		IngredientMismatch,
+		TokenIdsReserved,
+		TooManyReservedRanges,
+		NumOverflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		RecipeCreated(RecipeId, T::AccountId),
+		RecipeEnabled(RecipeId, bool),
+		/// A player put the ingredients of a recipe in escrow, the outcome is drawn from the
+		/// hash of the given block.
+		CraftStarted(CraftId, RecipeId, T::AccountId, T::BlockNumber),
+		/// A player got the products of the outcome at the given index.
+		Crafted(CraftId, RecipeId, T::AccountId, u32),
+		/// No products could be minted for a craft, the ingredients were returned.
+		CraftRefunded(CraftId, RecipeId, T::AccountId),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_recipe_id)]
+	pub type NextRecipeId<T: Config> = StorageValue<_, RecipeId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn recipes)]
+	pub type Recipes<T: Config> = StorageMap<_, Blake2_128Concat, RecipeId, RecipeOf<T>>;
+
+	/// The number of times each player crafted a recipe and the block they last did.
+	#[pallet::storage]
+	#[pallet::getter(fn crafts_of)]
+	pub type Crafts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RecipeId,
+		Blake2_128Concat,
+		T::AccountId,
+		(u32, T::BlockNumber),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_craft_id)]
+	pub type NextCraftId<T: Config> = StorageValue<_, CraftId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_crafts)]
+	pub type PendingCrafts<T: Config> = StorageMap<_, Blake2_128Concat, CraftId, PendingCraftOf<T>>;
+
+	/// The number of times each product of a recipe was minted, by outcome and product index.
+	#[pallet::storage]
+	#[pallet::getter(fn products_minted)]
+	pub type ProductsMinted<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, RecipeId, Twox64Concat, (u32, u32), u32, ValueQuery>;
+
+	/// The first and last token ids of the ranges reserved by recipes in each non-fungible
+	/// token.
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_ranges)]
+	pub type ReservedRanges<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		NonFungibleTokenId,
+		BoundedVec<(TokenId, TokenId), T::MaxReservedRanges>,
+		ValueQuery,
+	>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a recipe turning `ingredients` into one of `outcomes`.
+		///
+		/// The sender has to be able to mint every product, crafts mint them on its behalf.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_recipe(
+			ingredients.len() as u32,
+			outcomes.iter().map(|outcome| outcome.products.len() as u32).sum(),
+		))]
+		pub fn create_recipe(
+			origin: OriginFor<T>,
+			ingredients: Vec<Ingredient>,
+			outcomes: Vec<Outcome<Vec<Product>>>,
+			cooldown: T::BlockNumber,
+			player_limit: Option<u32>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_create_recipe(&who, ingredients, outcomes, cooldown, player_limit)?;
+			Ok(())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_recipe_enabled())]
+		pub fn set_recipe_enabled(
+			origin: OriginFor<T>,
+			recipe_id: RecipeId,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_recipe_enabled(&who, recipe_id, enabled)
+		}
+
+		/// Put the ingredients of a recipe from the sender in escrow, its outcome is drawn by
+		/// `reveal_craft` once the seed is known.
+		///
+		/// `non_fungible_tokens` are the tokens put in for the non-fungible ingredients, in order.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::craft(
+			<T as pallet::Config>::MaxIngredients::get(),
+		))]
+		pub fn craft(
+			origin: OriginFor<T>,
+			recipe_id: RecipeId,
+			non_fungible_tokens: Vec<TokenId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_craft(&who, recipe_id, non_fungible_tokens)?;
+			Ok(())
+		}
+
+		/// Draw the outcome of a craft and mint its products, anyone can reveal for the player.
+		///
+		/// The ingredients are consumed, or returned to the player if no products can be minted.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reveal_craft(
+			<T as pallet::Config>::MaxIngredients::get(),
+			<T as pallet::Config>::MaxProducts::get(),
+		))]
+		pub fn reveal_craft(origin: OriginFor<T>, craft_id: CraftId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_reveal_craft(craft_id)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn now() -> T::BlockNumber {
+		frame_system::Pallet::<T>::block_number()
+	}
+
+	pub fn account_id() -> T::AccountId {
+		<T as pallet::Config>::PalletId::get().into_account_truncating()
+	}
+
+	pub fn do_create_recipe(
+		who: &T::AccountId,
+		ingredients: Vec<Ingredient>,
+		outcomes: Vec<Outcome<Vec<Product>>>,
+		cooldown: T::BlockNumber,
+		player_limit: Option<u32>,
+	) -> Result<RecipeId, DispatchError> {
+		let ingredients: BoundedVec<Ingredient, <T as Config>::MaxIngredients> =
+			ingredients.try_into().map_err(|_| Error::<T>::TooManyIngredients)?;
+		ensure!(
+			ingredients.iter().all(|ingredient| match *ingredient {
+				Ingredient::MultiToken(_, _, amount) | Ingredient::FungibleToken(_, amount) =>
+					!amount.is_zero(),
+				Ingredient::NonFungibleToken(_) => true,
+			}),
+			Error::<T>::InvalidRecipe
+		);
+
+		ensure!(!outcomes.is_empty(), Error::<T>::InvalidRecipe);
+		ensure!(
+			outcomes.len() as u32 <= <T as Config>::MaxOutcomes::get(),
+			Error::<T>::TooManyOutcomes
+		);
+		// Outcomes are rolled over the total weight, which must not overflow.
+		let mut total_weight: u32 = 0;
+		let mut bounded_outcomes = Vec::with_capacity(outcomes.len());
+		let mut reserved_ranges = BTreeMap::new();
+		for outcome in outcomes {
+			ensure!(!outcome.weight.is_zero(), Error::<T>::InvalidRecipe);
+			total_weight =
+				total_weight.checked_add(outcome.weight).ok_or(Error::<T>::InvalidRecipe)?;
+
+			for product in outcome.products.iter() {
+				let can_mint = match *product {
+					Product::MultiToken(id, _, amount) => {
+						ensure!(!amount.is_zero(), Error::<T>::InvalidRecipe);
+						web3games_token_multi::Pallet::<T>::has_permission(
+							MultiTokenIdOf::<T>::unique_saturated_from(id),
+							who,
+						)
+					},
+					Product::NonFungibleToken(id, first_token_id, count) => {
+						ensure!(!count.is_zero(), Error::<T>::InvalidRecipe);
+						let last_token_id = first_token_id
+							.checked_add((count - 1).into())
+							.ok_or(Error::<T>::NumOverflow)?;
+						let ranges: &mut BoundedVec<_, _> = reserved_ranges
+							.entry(id)
+							.or_insert_with(|| ReservedRanges::<T>::get(id));
+						ensure!(
+							ranges.iter().all(
+								|&(first, last)| last_token_id < first || first_token_id > last
+							),
+							Error::<T>::TokenIdsReserved
+						);
+						ranges
+							.try_push((first_token_id, last_token_id))
+							.map_err(|_| Error::<T>::TooManyReservedRanges)?;
+
+						web3games_token_non_fungible::Pallet::<T>::has_permission(
+							NonFungibleTokenIdOf::<T>::unique_saturated_from(id),
+							who,
+						)
+					},
+				};
+				ensure!(can_mint, Error::<T>::NoPermission);
+			}
+
+			bounded_outcomes.push(Outcome {
+				weight: outcome.weight,
+				products: outcome.products.try_into().map_err(|_| Error::<T>::TooManyProducts)?,
+			});
+		}
+		let outcomes: BoundedVec<OutcomeOf<T>, <T as Config>::MaxOutcomes> =
+			bounded_outcomes.try_into().map_err(|_| Error::<T>::TooManyOutcomes)?;
+
+		let recipe_id = NextRecipeId::<T>::try_mutate(|id| -> Result<RecipeId, DispatchError> {
+			let current_id = *id;
+			*id = id.checked_add(1).ok_or(Error::<T>::NoAvailableRecipeId)?;
+			Ok(current_id)
+		})?;
+
+		Recipes::<T>::insert(
+			recipe_id,
+			Recipe {
+				owner: who.clone(),
+				ingredients,
+				outcomes,
+				cooldown,
+				player_limit,
+				enabled: true,
+				crafted: 0,
+			},
+		);
+		for (id, ranges) in reserved_ranges {
+			ReservedRanges::<T>::insert(id, ranges);
+		}
+
+		Self::deposit_event(Event::RecipeCreated(recipe_id, who.clone()));
+
+		Ok(recipe_id)
+	}
+
+	pub fn do_set_recipe_enabled(
+		who: &T::AccountId,
+		recipe_id: RecipeId,
+		enabled: bool,
+	) -> DispatchResult {
+		Recipes::<T>::try_mutate(recipe_id, |maybe_recipe| -> DispatchResult {
+			let recipe = maybe_recipe.as_mut().ok_or(Error::<T>::RecipeNotFound)?;
+			ensure!(*who == recipe.owner, Error::<T>::NotRecipeOwner);
+			recipe.enabled = enabled;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::RecipeEnabled(recipe_id, enabled));
+
+		Ok(())
+	}
+
+	/// Put the ingredients of a recipe from `who` in escrow, committing to the hash of a later
+	/// block.
+	pub fn do_craft(
+		who: &T::AccountId,
+		recipe_id: RecipeId,
+		non_fungible_tokens: Vec<TokenId>,
+	) -> Result<CraftId, DispatchError> {
+		let recipe = Recipes::<T>::get(recipe_id).ok_or(Error::<T>::RecipeNotFound)?;
+		ensure!(recipe.enabled, Error::<T>::RecipeDisabled);
+
+		let now = Self::now();
+		let crafts = match Crafts::<T>::get(recipe_id, who) {
+			Some((crafts, last_crafted)) => {
+				ensure!(
+					now >= last_crafted.saturating_add(recipe.cooldown),
+					Error::<T>::CraftingCooldown
+				);
+				crafts
+			},
+			None => 0,
+		};
+		if let Some(limit) = recipe.player_limit {
+			ensure!(crafts < limit, Error::<T>::PlayerLimitReached);
+		}
+
+		let non_fungible_tokens: BoundedVec<TokenId, <T as Config>::MaxIngredients> =
+			non_fungible_tokens.try_into().map_err(|_| Error::<T>::IngredientMismatch)?;
+		ensure!(
+			non_fungible_tokens.len() ==
+				recipe
+					.ingredients
+					.iter()
+					.filter(|ingredient| matches!(ingredient, Ingredient::NonFungibleToken(_)))
+					.count(),
+			Error::<T>::IngredientMismatch
+		);
+		Self::escrow_ingredients(who, &recipe, &non_fungible_tokens)?;
+
+		let craft_id = NextCraftId::<T>::try_mutate(|id| -> Result<CraftId, DispatchError> {
+			let current_id = *id;
+			*id = id.checked_add(1).ok_or(Error::<T>::NoAvailableCraftId)?;
+			Ok(current_id)
+		})?;
+
+		let reveal_at = T::Randomness::commit();
+		PendingCrafts::<T>::insert(
+			craft_id,
+			PendingCraft { recipe_id, who: who.clone(), non_fungible_tokens, reveal_at },
+		);
+		Crafts::<T>::insert(recipe_id, who, (crafts.saturating_add(1), now));
+
+		Self::deposit_event(Event::CraftStarted(craft_id, recipe_id, who.clone(), reveal_at));
+
+		Ok(craft_id)
+	}
+
+	/// Draw the outcome of a craft from the hash of its reveal block and mint its products,
+	/// returning the index of the outcome.
+	///
+	/// If no outcome is left or its products can't be minted, the ingredients go back to the
+	/// player, the craft doesn't count towards their limit and `None` is returned.
+	pub fn do_reveal_craft(craft_id: CraftId) -> Result<Option<u32>, DispatchError> {
+		let craft = PendingCrafts::<T>::get(craft_id).ok_or(Error::<T>::CraftNotFound)?;
+		let seed =
+			T::Hashing::hash_of(&(T::Randomness::seed_of(craft.reveal_at)?, b"crafting", craft_id));
+
+		let mut recipe = Recipes::<T>::get(craft.recipe_id).ok_or(Error::<T>::RecipeNotFound)?;
+		let crafted =
+			Self::pick_outcome(craft.recipe_id, &recipe.outcomes, &seed).and_then(|index| {
+				with_storage_layer(|| {
+					Self::mint_products(craft.recipe_id, &recipe, index, &craft.who)?;
+					Self::settle_ingredients(&craft, &recipe, Settlement::Consume)
+				})
+				.ok()
+				.map(|()| index)
+			});
+		match crafted {
+			Some(index) => {
+				recipe.crafted = recipe.crafted.checked_add(1).ok_or(Error::<T>::NumOverflow)?;
+				Recipes::<T>::insert(craft.recipe_id, recipe);
+				Self::deposit_event(Event::Crafted(
+					craft_id,
+					craft.recipe_id,
+					craft.who.clone(),
+					index as u32,
+				));
+			},
+			None => {
+				Self::settle_ingredients(&craft, &recipe, Settlement::Refund)?;
+				Crafts::<T>::mutate_exists(craft.recipe_id, &craft.who, |crafts| {
+					if let Some((crafts, _)) = crafts {
+						*crafts = crafts.saturating_sub(1);
+					}
+				});
+				Self::deposit_event(Event::CraftRefunded(
+					craft_id,
+					craft.recipe_id,
+					craft.who.clone(),
+				));
+			},
+		}
+
+		PendingCrafts::<T>::remove(craft_id);
+		T::Randomness::release(craft.reveal_at);
+
+		Ok(crafted.map(|index| index as u32))
+	}
+
+	/// Mint the products of the outcome at `index` to `who` on behalf of the recipe owner.
+	fn mint_products(
+		recipe_id: RecipeId,
+		recipe: &RecipeOf<T>,
+		index: usize,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		for (product_index, product) in recipe.outcomes[index].products.iter().enumerate() {
+			let key = (index as u32, product_index as u32);
+			let minted = ProductsMinted::<T>::get(recipe_id, key);
+			match *product {
+				Product::MultiToken(id, token_id, amount) =>
+					web3games_token_multi::Pallet::<T>::do_mint(
+						&recipe.owner,
+						MultiTokenIdOf::<T>::unique_saturated_from(id),
+						who,
+						MultiTokenTokenIdOf::<T>::unique_saturated_from(token_id),
+						amount,
+					)?,
+				Product::NonFungibleToken(id, first_token_id, _) => {
+					let token_id =
+						first_token_id.checked_add(minted.into()).ok_or(Error::<T>::NumOverflow)?;
+					web3games_token_non_fungible::Pallet::<T>::do_mint(
+						&recipe.owner,
+						NonFungibleTokenIdOf::<T>::unique_saturated_from(id),
+						who,
+						NonFungibleTokenTokenIdOf::<T>::unique_saturated_from(token_id),
+					)?
+				},
+			}
+			ProductsMinted::<T>::insert(
+				recipe_id,
+				key,
+				minted.checked_add(1).ok_or(Error::<T>::NumOverflow)?,
+			);
+		}
+		Ok(())
+	}
+
+	/// Move the tokens and fees a recipe takes from `who` into escrow.
+	fn escrow_ingredients(
+		who: &T::AccountId,
+		recipe: &RecipeOf<T>,
+		non_fungible_tokens: &[TokenId],
+	) -> DispatchResult {
+		let escrow = Self::account_id();
+		let mut non_fungible_tokens = non_fungible_tokens.iter();
+		for ingredient in recipe.ingredients.iter() {
+			match *ingredient {
+				Ingredient::MultiToken(id, token_id, amount) =>
+					web3games_token_multi::Pallet::<T>::do_deposit_escrow(
+						MultiTokenIdOf::<T>::unique_saturated_from(id),
+						who,
+						&escrow,
+						MultiTokenTokenIdOf::<T>::unique_saturated_from(token_id),
+						amount,
+					)?,
+				Ingredient::NonFungibleToken(id) => {
+					let token_id =
+						non_fungible_tokens.next().ok_or(Error::<T>::IngredientMismatch)?;
+					web3games_token_non_fungible::Pallet::<T>::do_deposit_escrow(
+						NonFungibleTokenIdOf::<T>::unique_saturated_from(id),
+						who,
+						&escrow,
+						NonFungibleTokenTokenIdOf::<T>::unique_saturated_from(*token_id),
+					)?;
+				},
+				Ingredient::FungibleToken(id, amount) =>
+					if *who != recipe.owner {
+						web3games_token_fungible::Pallet::<T>::do_transfer(
+							FungibleTokenIdOf::<T>::unique_saturated_from(id),
+							who,
+							&escrow,
+							amount,
+						)?;
+					},
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Burn the escrowed tokens of a craft and pay its fees to the recipe owner, or give them
+	/// all back to the player.
+	fn settle_ingredients(
+		craft: &PendingCraftOf<T>,
+		recipe: &RecipeOf<T>,
+		settlement: Settlement,
+	) -> DispatchResult {
+		let escrow = Self::account_id();
+		let mut non_fungible_tokens = craft.non_fungible_tokens.iter();
+		let mut multi_tokens: BTreeMap<MultiTokenId, (Vec<_>, Vec<Balance>)> = BTreeMap::new();
+
+		for ingredient in recipe.ingredients.iter() {
+			match (*ingredient, settlement) {
+				(Ingredient::MultiToken(id, token_id, amount), Settlement::Consume) => {
+					let (token_ids, amounts) = multi_tokens.entry(id).or_default();
+					token_ids.push(MultiTokenTokenIdOf::<T>::unique_saturated_from(token_id));
+					amounts.push(amount);
+				},
+				(Ingredient::MultiToken(id, token_id, amount), Settlement::Refund) =>
+					web3games_token_multi::Pallet::<T>::do_release_escrow(
+						MultiTokenIdOf::<T>::unique_saturated_from(id),
+						&escrow,
+						&craft.who,
+						MultiTokenTokenIdOf::<T>::unique_saturated_from(token_id),
+						amount,
+					)?,
+				(Ingredient::NonFungibleToken(id), _) => {
+					let id = NonFungibleTokenIdOf::<T>::unique_saturated_from(id);
+					let token_id = NonFungibleTokenTokenIdOf::<T>::unique_saturated_from(
+						*non_fungible_tokens.next().ok_or(Error::<T>::IngredientMismatch)?,
+					);
+					match settlement {
+						Settlement::Consume => web3games_token_non_fungible::Pallet::<T>::do_burn(
+							&escrow, id, token_id,
+						)?,
+						Settlement::Refund =>
+							web3games_token_non_fungible::Pallet::<T>::do_release_escrow(
+								id, &escrow, &craft.who, token_id,
+							)?,
+					}
+				},
+				(Ingredient::FungibleToken(id, amount), _) =>
+					if craft.who != recipe.owner {
+						let to = match settlement {
+							Settlement::Consume => &recipe.owner,
+							Settlement::Refund => &craft.who,
+						};
+						web3games_token_fungible::Pallet::<T>::do_transfer(
+							FungibleTokenIdOf::<T>::unique_saturated_from(id),
+							&escrow,
+							to,
+							amount,
+						)?;
+					},
+			}
+		}
+
+		for (id, (token_ids, amounts)) in multi_tokens {
+			web3games_token_multi::Pallet::<T>::do_batch_burn(
+				&escrow,
+				MultiTokenIdOf::<T>::unique_saturated_from(id),
+				token_ids,
+				amounts,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Pick an outcome by weight, leaving out outcomes with a non-fungible product that minted
+	/// all its tokens, `None` if none is left.
+	pub fn pick_outcome(
+		recipe_id: RecipeId,
+		outcomes: &[OutcomeOf<T>],
+		seed: &T::Hash,
+	) -> Option<usize> {
+		let weights: Vec<u32> = outcomes
+			.iter()
+			.enumerate()
+			.map(|(index, outcome)| {
+				let exhausted =
+					outcome.products.iter().enumerate().any(|(product_index, product)| {
+						match *product {
+							Product::NonFungibleToken(_, _, count) =>
+								ProductsMinted::<T>::get(
+									recipe_id,
+									(index as u32, product_index as u32),
+								) >= count,
+							Product::MultiToken(..) => false,
+						}
+					});
+				if exhausted {
+					0
+				} else {
+					outcome.weight
+				}
+			})
+			.collect();
+		if weights.iter().all(Zero::is_zero) {
+			return None
+		}
+		Some(web3games_support::draw_weighted(seed.as_ref(), &weights))
+	}
+}
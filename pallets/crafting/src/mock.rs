@@ -0,0 +1,228 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate as web3games_crafting;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
+	PalletId,
+};
+use primitives::Balance;
+use sp_core::{H160, H256};
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+};
+use web3games_support::TokenIdConversion;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const MILLICENTS: Balance = 10_000_000_000_000;
+pub const CENTS: Balance = 1_000 * MILLICENTS; // assume this is worth about a cent.
+pub const DOLLARS: Balance = 100 * CENTS;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		TokenFungible: web3games_token_fungible::{Pallet, Call, Storage, Event<T>},
+		TokenNonFungible: web3games_token_non_fungible::{Pallet, Call, Storage, Event<T>},
+		TokenMulti: web3games_token_multi::{Pallet, Call, Storage, Event<T>},
+		Randomness: web3games_randomness::{Pallet, Storage},
+		Crafting: web3games_crafting::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+pub struct MockAddressMapping;
+impl Convert<H160, u64> for MockAddressMapping {
+	fn convert(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+pub struct MockTokenIdConversion;
+impl TokenIdConversion<u128> for MockTokenIdConversion {
+	fn try_from_address(address: H160) -> Option<u128> {
+		Some(address.to_low_u64_be().into())
+	}
+
+	fn into_address(id: u128) -> H160 {
+		H160::from_low_u64_be(id as u64)
+	}
+}
+
+parameter_types! {
+	pub const TokenFungiblePalletId: PalletId = PalletId(*b"w3g/tfpi");
+	pub const TokenNonFungiblePalletId: PalletId = PalletId(*b"w3g/tnfp");
+	pub const TokenMultiPalletId: PalletId = PalletId(*b"w3g/tmpi");
+	pub const StringLimit: u32 = 50;
+	pub const CreateTokenDeposit: Balance = 500 * MILLICENTS;
+	pub const AttributeDepositBase: Balance = 10 * MILLICENTS;
+	pub const DepositPerByte: Balance = 1 * MILLICENTS;
+}
+
+impl web3games_token_fungible::Config for Test {
+	type Event = Event;
+	type PalletId = TokenFungiblePalletId;
+	type FungibleTokenId = u128;
+	type StringLimit = StringLimit;
+	type CreateTokenDeposit = CreateTokenDeposit;
+	type Currency = Balances;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type UnixTime = Timestamp;
+	type MaxVestingSchedules = ConstU32<8>;
+	type MinVestedTransfer = ConstU128<10>;
+	type RemoveItemsLimit = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+impl web3games_token_non_fungible::Config for Test {
+	type Event = Event;
+	type PalletId = TokenNonFungiblePalletId;
+	type NonFungibleTokenId = u128;
+	type TokenId = u128;
+	type StringLimit = StringLimit;
+	type CreateTokenDeposit = CreateTokenDeposit;
+	type Currency = Balances;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type MultiToken = TokenMulti;
+	type MaxNestingDepth = ConstU32<3>;
+	type MaxChildren = ConstU32<2>;
+	type Fungibles = TokenFungible;
+	type FungibleTokenIdConversion = MockTokenIdConversion;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type MaxMintPhases = ConstU32<3>;
+	type MaxBatchMint = ConstU32<5>;
+	type RemoveItemsLimit = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+impl web3games_token_multi::Config for Test {
+	type Event = Event;
+	type PalletId = TokenMultiPalletId;
+	type MultiTokenId = u128;
+	type TokenId = u128;
+	type StringLimit = StringLimit;
+	type CreateTokenDeposit = CreateTokenDeposit;
+	type Currency = Balances;
+	type Fungibles = TokenFungible;
+	type FungibleTokenIdConversion = MockTokenIdConversion;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type MaxInventorySize = ConstU32<100>;
+	type InventoryDeposit = ConstU128<0>;
+	type RemoveItemsLimit = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+impl web3games_randomness::Config for Test {
+	type RevealDelay = ConstU64<2>;
+}
+
+parameter_types! {
+	pub const CraftingPalletId: PalletId = PalletId(*b"w3g/crft");
+}
+
+impl web3games_crafting::Config for Test {
+	type Event = Event;
+	type PalletId = CraftingPalletId;
+	type Randomness = Randomness;
+	type MaxIngredients = ConstU32<4>;
+	type MaxOutcomes = ConstU32<3>;
+	type MaxProducts = ConstU32<2>;
+	type MaxReservedRanges = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100 * DOLLARS), (2, 100 * DOLLARS)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
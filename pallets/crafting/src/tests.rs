@@ -0,0 +1,292 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+pub use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::Header as _;
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+
+const W3G: u128 = 1;
+const ITEMS: u128 = 1;
+const HEROES: u128 = 2;
+const WOOD: u128 = 1;
+const SWORD: u128 = 7;
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let header = System::finalize();
+		System::initialize(&(header.number + 1), &header.hash(), &Default::default());
+		Randomness::on_initialize(System::block_number());
+	}
+}
+
+fn create_tokens() {
+	assert_ok!(TokenFungible::create_token(
+		Origin::signed(ALICE),
+		W3G,
+		b"W3G".to_vec(),
+		b"W3G".to_vec(),
+		18,
+		None
+	));
+	assert_ok!(TokenFungible::mint(Origin::signed(ALICE), W3G, BOB, 1000));
+	assert_ok!(TokenMulti::create_token(
+		Origin::signed(ALICE),
+		ITEMS,
+		b"https://web3games.com/".to_vec()
+	));
+	assert_ok!(TokenMulti::mint(Origin::signed(ALICE), ITEMS, BOB, WOOD, 10));
+	assert_ok!(TokenNonFungible::create_token(
+		Origin::signed(ALICE),
+		HEROES,
+		b"Heroes".to_vec(),
+		b"HRS".to_vec(),
+		b"https://web3games.com/".to_vec()
+	));
+	assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), HEROES, BOB, 1));
+	assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), HEROES, BOB, 2));
+}
+
+/// 3 WOOD, a hero and 5 W3G for a SWORD or one of 10 new heroes numbered from 100.
+fn create_recipe(cooldown: u64, player_limit: Option<u32>) -> DispatchResult {
+	Crafting::create_recipe(
+		Origin::signed(ALICE),
+		vec![
+			Ingredient::MultiToken(ITEMS, WOOD, 3),
+			Ingredient::NonFungibleToken(HEROES),
+			Ingredient::FungibleToken(W3G, 5),
+		],
+		vec![
+			Outcome { weight: 1, products: vec![Product::MultiToken(ITEMS, SWORD, 1)] },
+			Outcome { weight: 1, products: vec![Product::NonFungibleToken(HEROES, 100, 10)] },
+		],
+		cooldown,
+		player_limit,
+	)
+}
+
+#[test]
+fn create_recipe_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+
+		assert_noop!(
+			Crafting::create_recipe(
+				Origin::signed(ALICE),
+				vec![],
+				vec![Outcome { weight: 0, products: vec![] }],
+				0,
+				None
+			),
+			Error::<Test>::InvalidRecipe
+		);
+		assert_noop!(
+			Crafting::create_recipe(
+				Origin::signed(BOB),
+				vec![],
+				vec![Outcome { weight: 1, products: vec![Product::MultiToken(ITEMS, SWORD, 1)] }],
+				0,
+				None
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Crafting::create_recipe(
+				Origin::signed(ALICE),
+				vec![],
+				vec![Outcome {
+					weight: 1,
+					products: vec![Product::MultiToken(ITEMS, SWORD, 1); 3]
+				}],
+				0,
+				None
+			),
+			Error::<Test>::TooManyProducts
+		);
+
+		assert_ok!(create_recipe(10, Some(2)));
+		let recipe = Crafting::recipes(0).unwrap();
+		assert_eq!(recipe.owner, ALICE);
+		assert_eq!(recipe.outcomes.len(), 2);
+		assert!(recipe.enabled);
+		assert_eq!(Crafting::next_recipe_id(), 1);
+		assert_eq!(Crafting::reserved_ranges(HEROES).to_vec(), vec![(100, 109)]);
+
+		// Recipes can't share token ids.
+		let heroes = |first_token_id, count| {
+			vec![Outcome {
+				weight: 1,
+				products: vec![Product::NonFungibleToken(HEROES, first_token_id, count)],
+			}]
+		};
+		assert_noop!(
+			Crafting::create_recipe(Origin::signed(ALICE), vec![], heroes(109, 5), 0, None),
+			Error::<Test>::TokenIdsReserved
+		);
+		assert_noop!(
+			Crafting::create_recipe(Origin::signed(ALICE), vec![], heroes(110, 0), 0, None),
+			Error::<Test>::InvalidRecipe
+		);
+		assert_ok!(Crafting::create_recipe(Origin::signed(ALICE), vec![], heroes(110, 5), 0, None));
+		assert_noop!(
+			Crafting::create_recipe(Origin::signed(ALICE), vec![], heroes(200, 5), 0, None),
+			Error::<Test>::TooManyReservedRanges
+		);
+
+		assert_noop!(
+			Crafting::set_recipe_enabled(Origin::signed(BOB), 0, false),
+			Error::<Test>::NotRecipeOwner
+		);
+		assert_ok!(Crafting::set_recipe_enabled(Origin::signed(ALICE), 0, false));
+		assert!(!Crafting::recipes(0).unwrap().enabled);
+		assert_noop!(
+			Crafting::craft(Origin::signed(BOB), 0, vec![1]),
+			Error::<Test>::RecipeDisabled
+		);
+	})
+}
+
+#[test]
+fn craft_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		assert_ok!(create_recipe(10, Some(2)));
+
+		assert_noop!(
+			Crafting::craft(Origin::signed(BOB), 0, vec![]),
+			Error::<Test>::IngredientMismatch
+		);
+		assert_noop!(
+			Crafting::craft(Origin::signed(BOB), 0, vec![1, 2]),
+			Error::<Test>::IngredientMismatch
+		);
+
+		// The ingredients are held in escrow until the reveal.
+		let escrow = Crafting::account_id();
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0, vec![1]));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (WOOD, BOB)), 7);
+		assert_eq!(TokenMulti::balance_of(ITEMS, (WOOD, escrow)), 3);
+		assert_eq!(TokenNonFungible::owner_of(HEROES, 1), Some(escrow));
+		assert_eq!(TokenFungible::balance_of(W3G, BOB), 995);
+		assert_eq!(TokenFungible::balance_of(W3G, escrow), 5);
+		assert_eq!(TokenFungible::balance_of(W3G, ALICE), 0);
+		assert_eq!(Crafting::crafts_of(0, BOB), Some((1, 1)));
+		assert_eq!(Crafting::pending_crafts(0).unwrap().reveal_at, 3);
+
+		run_to_block(3);
+		assert_noop!(
+			Crafting::reveal_craft(Origin::signed(ALICE), 0),
+			web3games_randomness::Error::<Test>::RevealTooEarly
+		);
+
+		run_to_block(4);
+		let block_seed = Randomness::seeds(3).unwrap();
+		assert_ok!(Crafting::reveal_craft(Origin::signed(ALICE), 0));
+		let seed =
+			<Test as frame_system::Config>::Hashing::hash_of(&(block_seed, b"crafting", 0u64));
+		let index =
+			Crafting::pick_outcome(0, &Crafting::recipes(0).unwrap().outcomes, &seed).unwrap();
+		System::assert_last_event(crate::mock::Event::Crafting(crate::Event::Crafted(
+			0,
+			0,
+			BOB,
+			index as u32,
+		)));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (WOOD, escrow)), 0);
+		assert_eq!(TokenNonFungible::owner_of(HEROES, 1), None);
+		assert_eq!(TokenFungible::balance_of(W3G, escrow), 0);
+		assert_eq!(TokenFungible::balance_of(W3G, ALICE), 5);
+		assert_eq!(Crafting::products_minted(0, (index as u32, 0)), 1);
+		let crafted = TokenMulti::balance_of(ITEMS, (SWORD, BOB)) +
+			TokenNonFungible::balance_of(HEROES, BOB) as u128 -
+			1;
+		assert_eq!(crafted, 1);
+		assert_eq!(Crafting::recipes(0).unwrap().crafted, 1);
+		assert_eq!(Crafting::pending_crafts(0), None);
+		assert_eq!(Randomness::seeds(3), None);
+		assert_noop!(
+			Crafting::reveal_craft(Origin::signed(ALICE), 0),
+			Error::<Test>::CraftNotFound
+		);
+
+		assert_noop!(
+			Crafting::craft(Origin::signed(BOB), 0, vec![2]),
+			Error::<Test>::CraftingCooldown
+		);
+		run_to_block(11);
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0, vec![2]));
+
+		run_to_block(21);
+		assert_noop!(
+			Crafting::craft(Origin::signed(BOB), 0, vec![]),
+			Error::<Test>::PlayerLimitReached
+		);
+	})
+}
+
+#[test]
+fn failed_reveal_should_refund() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		assert_ok!(Crafting::create_recipe(
+			Origin::signed(ALICE),
+			vec![Ingredient::MultiToken(ITEMS, WOOD, 3), Ingredient::FungibleToken(W3G, 5)],
+			vec![Outcome { weight: 1, products: vec![Product::NonFungibleToken(HEROES, 100, 1)] }],
+			0,
+			None,
+		));
+		// The owner minted the token id of the product already.
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), HEROES, ALICE, 100));
+
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0, vec![]));
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0, vec![]));
+		assert_eq!(Crafting::crafts_of(0, BOB), Some((2, 1)));
+		run_to_block(4);
+		assert_ok!(Crafting::reveal_craft(Origin::signed(ALICE), 0));
+		System::assert_last_event(crate::mock::Event::Crafting(crate::Event::CraftRefunded(
+			0, 0, BOB,
+		)));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (WOOD, BOB)), 7);
+		assert_eq!(TokenFungible::balance_of(W3G, BOB), 995);
+		assert_eq!(Crafting::products_minted(0, (0, 0)), 0);
+		assert_eq!(Crafting::crafts_of(0, BOB), Some((1, 1)));
+		assert_eq!(Crafting::pending_crafts(0), None);
+
+		assert_ok!(TokenNonFungible::burn(Origin::signed(ALICE), HEROES, 100));
+		assert_ok!(Crafting::reveal_craft(Origin::signed(ALICE), 1));
+		assert_eq!(TokenNonFungible::owner_of(HEROES, 100), Some(BOB));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (WOOD, BOB)), 7);
+		assert_eq!(TokenFungible::balance_of(W3G, ALICE), 5);
+		assert_eq!(Crafting::products_minted(0, (0, 0)), 1);
+
+		// The only outcome has no token left.
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0, vec![]));
+		run_to_block(7);
+		assert_ok!(Crafting::reveal_craft(Origin::signed(ALICE), 2));
+		System::assert_last_event(crate::mock::Event::Crafting(crate::Event::CraftRefunded(
+			2, 0, BOB,
+		)));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (WOOD, BOB)), 7);
+		assert_eq!(TokenMulti::balance_of(ITEMS, (WOOD, Crafting::account_id())), 0);
+		assert_eq!(TokenFungible::balance_of(W3G, BOB), 995);
+		assert_eq!(Crafting::recipes(0).unwrap().crafted, 1);
+	})
+}
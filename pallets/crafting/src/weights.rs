@@ -0,0 +1,154 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for web3games_crafting
+//!
+//! These are estimates from the storage accesses of each call, scaled from the benchmarked
+//! token pallets. They have not been benchmarked yet: run `scripts/generate-weights.sh` on
+//! reference hardware to replace this file with measured weights.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for web3games_crafting.
+pub trait WeightInfo {
+	fn create_recipe(i: u32, p: u32, ) -> Weight;
+	fn set_recipe_enabled() -> Weight;
+	fn craft(i: u32, ) -> Weight;
+	fn reveal_craft(i: u32, p: u32, ) -> Weight;
+}
+
+/// Weights for web3games_crafting using the Web3Games node and recommended hardware.
+pub struct W3GWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: Crafting ReservedRanges (r:1 w:1)
+	// Storage: Crafting NextRecipeId (r:1 w:1)
+	// Storage: Crafting Recipes (r:0 w:1)
+	fn create_recipe(i: u32, p: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((210_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 4_000
+			.saturating_add((2_900_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: Crafting Recipes (r:1 w:1)
+	fn set_recipe_enabled() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Crafting Recipes (r:1 w:0)
+	// Storage: Crafting Crafts (r:1 w:1)
+	// Storage: TokenMulti Tokens (r:1 w:1)
+	// Storage: TokenMulti Balances (r:1 w:1)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Storage: TokenNonFungible Tokens (r:1 w:1)
+	// Storage: TokenNonFungible Owners (r:1 w:1)
+	// Storage: TokenNonFungible Balances (r:1 w:1)
+	// Storage: TokenFungible Balances (r:2 w:2)
+	// Storage: Crafting NextCraftId (r:1 w:1)
+	// Storage: Randomness PendingReveals (r:1 w:1)
+	// Storage: Crafting PendingCrafts (r:0 w:1)
+	fn craft(i: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((24_500_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(i as Weight)))
+	}
+	// Storage: Crafting PendingCrafts (r:1 w:1)
+	// Storage: Randomness Seeds (r:1 w:1)
+	// Storage: Crafting Recipes (r:1 w:1)
+	// Storage: Crafting ProductsMinted (r:1 w:1)
+	// Storage: TokenMulti Tokens (r:1 w:1)
+	// Storage: TokenMulti Balances (r:1 w:1)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Storage: TokenNonFungible Tokens (r:1 w:1)
+	// Storage: TokenNonFungible Owners (r:1 w:1)
+	// Storage: TokenNonFungible Balances (r:1 w:1)
+	// Storage: TokenFungible Balances (r:2 w:2)
+	// Storage: Randomness PendingReveals (r:1 w:1)
+	fn reveal_craft(i: u32, p: u32, ) -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add((22_000_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add((29_800_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(p as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_recipe(i: u32, p: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((210_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 4_000
+			.saturating_add((2_900_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+	}
+	fn set_recipe_enabled() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn craft(i: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((24_500_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(i as Weight)))
+	}
+	fn reveal_craft(i: u32, p: u32, ) -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add((22_000_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add((29_800_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(p as Weight)))
+	}
+}
@@ -39,21 +39,24 @@ fn create_token<T: Config>(who: T::AccountId) -> DispatchResult {
 		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(1u128),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::Pallet::<T>::create_token(
 		RawOrigin::Signed(who.clone()).into(),
 		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(2u128),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::Pallet::<T>::create_token(
 		RawOrigin::Signed(who.clone()).into(),
 		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(3u128),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	Ok(())
 }
@@ -222,6 +225,24 @@ benchmarks! {
 		<T as frame_system::Config>::BlockNumber::unique_saturated_from(10000u128)
 	)
 
+	set_swap_fee {
+		let alice: T::AccountId = whitelisted_caller();
+		set_balance::<T>(alice.clone())?;
+		create_token::<T>(alice.clone())?;
+		init_create_pool::<T>(
+			1u128,
+			2u128
+		)?;
+	}: _(
+		RawOrigin::Root,
+		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(1u128),
+		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(2u128),
+		5
+	)
+
+	set_protocol_fee {
+	}: _(RawOrigin::Root, 2_500)
+
 	impl_benchmark_test_suite!(Exchange, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
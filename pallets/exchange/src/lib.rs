@@ -36,6 +36,7 @@ use sp_runtime::{
 use sp_std::{cmp, prelude::*};
 
 pub use pallet::*;
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -54,6 +55,9 @@ type FungibleTokenIdOf<T> = <T as web3games_token_fungible::Config>::FungibleTok
 
 pub const MINIMUM_LIQUIDITY: u128 = 1000; // 10**3;
 
+/// The denominator of swap and protocol fees, which are in basis points.
+pub const FEE_DENOMINATOR: u16 = 10_000;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct Pool<AccountId, FungibleTokenId> {
 	/// The id of first token
@@ -64,6 +68,8 @@ pub struct Pool<AccountId, FungibleTokenId> {
 	pub lp_token: FungibleTokenId,
 	/// The id of liquidity pool token
 	pub lp_token_account_id: AccountId,
+	/// The fee taken from swaps, in basis points
+	pub swap_fee: u16,
 }
 
 #[frame_support::pallet]
@@ -94,12 +100,20 @@ pub mod pallet {
 
 		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
 
+		/// The swap fee of new pools, in basis points.
+		#[pallet::constant]
+		type DefaultSwapFee: Get<u16>;
+
 		/// runtime weights.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -137,6 +151,17 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type FeeTo<T: Config> = StorageValue<_, T::AccountId>;
 
+	#[pallet::type_value]
+	pub fn DefaultProtocolFee() -> u16 {
+		// About a sixth of the swap fees, as in Uniswap V2.
+		1_667
+	}
+
+	/// The share of the swap fees minted to `FeeTo`, in basis points.
+	#[pallet::storage]
+	#[pallet::getter(fn protocol_fee)]
+	pub(super) type ProtocolFee<T: Config> = StorageValue<_, u16, ValueQuery, DefaultProtocolFee>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -145,6 +170,8 @@ pub mod pallet {
 		LiquidityRemoved(T::FungibleTokenId, Balance, Balance, Balance),
 		Swap(T::AccountId, Balance, Balance, Balance, Balance, T::AccountId),
 		SetFeeTo(T::AccountId),
+		SwapFeeSet(T::FungibleTokenId, T::FungibleTokenId, u16),
+		ProtocolFeeSet(u16),
 		Burn(T::AccountId, Balance, Balance, T::AccountId),
 		Mint(T::AccountId, Balance, Balance, T::AccountId),
 		Sync(Balance, Balance),
@@ -170,10 +197,21 @@ pub mod pallet {
 		PoolAlreadyCreated,
 		TokenRepeat,
 		Deadline,
+		InvalidFee,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Create the pool of `token_a` and `token_b`, paying `CreatePoolDeposit`.
+		///
+		/// The pool charges `DefaultSwapFee`, only root changes it with `set_swap_fee`.
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_pool())]
 		#[transactional]
 		pub fn create_pool(
@@ -551,6 +589,38 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_swap_fee())]
+		pub fn set_swap_fee(
+			origin: OriginFor<T>,
+			token_a: T::FungibleTokenId,
+			token_b: T::FungibleTokenId,
+			swap_fee: u16,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(swap_fee < FEE_DENOMINATOR, Error::<T>::InvalidFee);
+
+			let (token_0, token_1) = Self::sort_tokens(token_a, token_b);
+			Pools::<T>::try_mutate((token_0, token_1), |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+				pool.swap_fee = swap_fee;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::SwapFeeSet(token_0, token_1, swap_fee));
+			Ok(())
+		}
+
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_protocol_fee())]
+		pub fn set_protocol_fee(origin: OriginFor<T>, protocol_fee: u16) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(protocol_fee <= FEE_DENOMINATOR, Error::<T>::InvalidFee);
+
+			ProtocolFee::<T>::put(protocol_fee);
+
+			Self::deposit_event(Event::ProtocolFeeSet(protocol_fee));
+			Ok(())
+		}
 	}
 }
 
@@ -602,7 +672,13 @@ impl<T: Config> Pallet<T> {
 			18,
 		)?;
 
-		let pool = Pool { token_0, token_1, lp_token, lp_token_account_id };
+		let pool = Pool {
+			token_0,
+			token_1,
+			lp_token,
+			lp_token_account_id,
+			swap_fee: T::DefaultSwapFee::get(),
+		};
 
 		Pools::<T>::insert((token_0, token_1), pool);
 		LpTokenToToken::<T>::insert(lp_token, (token_0, token_1));
@@ -734,12 +810,17 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::InsufficientInputAmount
 		);
 
-		let balance_0_adjusted = Self::init_balance_adjusted(balance_0, amount_0_in)?;
-		let balance_1_adjusted = Self::init_balance_adjusted(balance_1, amount_1_in)?;
+		let balance_0_adjusted =
+			Self::init_balance_adjusted(balance_0, amount_0_in, pool.swap_fee)?;
+		let balance_1_adjusted =
+			Self::init_balance_adjusted(balance_1, amount_1_in, pool.swap_fee)?;
 
 		ensure!(
-			balance_0_adjusted * balance_1_adjusted >=
-				reserve_0 * reserve_1 * Balance::from(1000u128) * Balance::from(1000u128),
+			U256::from(balance_0_adjusted).saturating_mul(U256::from(balance_1_adjusted)) >=
+				U256::from(reserve_0)
+					.saturating_mul(U256::from(reserve_1))
+					.saturating_mul(U256::from(FEE_DENOMINATOR))
+					.saturating_mul(U256::from(FEE_DENOMINATOR)),
 			Error::<T>::AdjustedError
 		);
 
@@ -765,17 +846,22 @@ impl<T: Config> Pallet<T> {
 		if let Some(fee_on) = FeeTo::<T>::get() {
 			let pool = Pools::<T>::get((token_0, token_1)).ok_or(Error::<T>::PoolNotFound)?;
 			let total_supply = web3games_token_fungible::Pallet::<T>::total_supply(pool.lp_token);
-			if k_last != 0 {
+			let protocol_fee = U256::from(ProtocolFee::<T>::get());
+			if k_last != 0 && !protocol_fee.is_zero() {
 				let root_k =
 					(U256::from(reserve_0).saturating_mul(U256::from(reserve_1))).integer_sqrt();
 				let root_k_last = U256::from(k_last).integer_sqrt();
 				if root_k > root_k_last {
-					let numerator = U256::from(total_supply).saturating_mul(
-						root_k.checked_sub(root_k_last).ok_or(Error::<T>::Overflow)?,
-					);
+					// Mint the `protocol_fee` share of the growth of sqrt(k) since the last
+					// mint or burn, about a sixth by default as in Uniswap V2.
+					let numerator = U256::from(total_supply)
+						.saturating_mul(
+							root_k.checked_sub(root_k_last).ok_or(Error::<T>::Overflow)?,
+						)
+						.saturating_mul(protocol_fee);
 					let denominator = root_k
-						.saturating_mul(U256::from(5u128))
-						.checked_add(root_k_last)
+						.saturating_mul(U256::from(FEE_DENOMINATOR).saturating_sub(protocol_fee))
+						.checked_add(root_k_last.saturating_mul(protocol_fee))
 						.ok_or(Error::<T>::Overflow)?;
 					let liquidity = numerator
 						.checked_div(denominator)
@@ -1070,12 +1156,13 @@ impl<T: Config> Pallet<T> {
 	fn init_balance_adjusted(
 		balance: Balance,
 		amount_in: Balance,
+		swap_fee: u16,
 	) -> Result<Balance, DispatchError> {
 		let temp_0 = U256::from(balance)
-			.checked_mul(U256::from(1000u128))
+			.checked_mul(U256::from(FEE_DENOMINATOR))
 			.ok_or(Error::<T>::Overflow)?;
 		let temp_1 = U256::from(amount_in)
-			.checked_mul(U256::from(3u128))
+			.checked_mul(U256::from(swap_fee))
 			.ok_or(Error::<T>::Overflow)?;
 		Ok(temp_0
 			.checked_sub(temp_1)
@@ -1126,10 +1213,21 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_b)
 	}
 
+	/// The swap fee of the pool of two tokens, in basis points.
+	pub fn get_swap_fee(
+		token_a: T::FungibleTokenId,
+		token_b: T::FungibleTokenId,
+	) -> Result<u16, DispatchError> {
+		let (token_0, token_1) = Self::sort_tokens(token_a, token_b);
+		let pool = Pools::<T>::get((token_0, token_1)).ok_or(Error::<T>::PoolNotFound)?;
+		Ok(pool.swap_fee)
+	}
+
 	pub fn get_amount_out(
 		amount_in: Balance,
 		reserve_in: Balance,
 		reserve_out: Balance,
+		swap_fee: u16,
 	) -> Result<Balance, DispatchError> {
 		ensure!(amount_in > Zero::zero(), Error::<T>::InsufficientInputAmount);
 		ensure!(
@@ -1137,12 +1235,14 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::InsufficientLiquidity
 		);
 
-		let amount_in_with_fee: U256 = U256::from(amount_in).saturating_mul(U256::from(997u128));
+		let amount_in_with_fee: U256 = U256::from(amount_in)
+			.saturating_mul(U256::from(FEE_DENOMINATOR.saturating_sub(swap_fee)));
 
 		let numerator: U256 =
 			U256::from(amount_in_with_fee).saturating_mul(U256::from(reserve_out));
 
-		let denominator: U256 = (U256::from(reserve_in).saturating_mul(U256::from(1000u128)))
+		let denominator: U256 = U256::from(reserve_in)
+			.saturating_mul(U256::from(FEE_DENOMINATOR))
 			.saturating_add(amount_in_with_fee);
 
 		let amount_out = numerator
@@ -1156,6 +1256,7 @@ impl<T: Config> Pallet<T> {
 		amount_out: Balance,
 		reserve_in: Balance,
 		reserve_out: Balance,
+		swap_fee: u16,
 	) -> Result<Balance, DispatchError> {
 		ensure!(amount_out > Zero::zero(), Error::<T>::InsufficientOutputAmount);
 		ensure!(
@@ -1165,9 +1266,9 @@ impl<T: Config> Pallet<T> {
 
 		let numerator: U256 = U256::from(reserve_in)
 			.saturating_mul(U256::from(amount_out))
-			.saturating_mul(U256::from(1000u128));
+			.saturating_mul(U256::from(FEE_DENOMINATOR));
 		let denominator: U256 = (U256::from(reserve_out).saturating_sub(U256::from(amount_out)))
-			.saturating_mul(U256::from(997u128));
+			.saturating_mul(U256::from(FEE_DENOMINATOR.saturating_sub(swap_fee)));
 
 		let amount_in = U256::from(numerator)
 			.checked_div(U256::from(denominator))
@@ -1188,7 +1289,8 @@ impl<T: Config> Pallet<T> {
 		amounts[0] = amount_in;
 		for i in 0..(path.len() - 1) {
 			let (reserve_in, reserve_out) = Self::get_reserves(path[i], path[i + 1])?;
-			amounts[i + 1] = Self::get_amount_out(amounts[i], reserve_in, reserve_out)?;
+			let swap_fee = Self::get_swap_fee(path[i], path[i + 1])?;
+			amounts[i + 1] = Self::get_amount_out(amounts[i], reserve_in, reserve_out, swap_fee)?;
 		}
 		Ok(amounts)
 	}
@@ -1203,7 +1305,8 @@ impl<T: Config> Pallet<T> {
 		amounts[path.len() - 1] = amount_out;
 		for i in (1..path.len()).rev() {
 			let (reserve_in, reserve_out) = Self::get_reserves(path[i - 1], path[i])?;
-			amounts[i - 1] = Self::get_amount_in(amounts[i], reserve_in, reserve_out)?;
+			let swap_fee = Self::get_swap_fee(path[i - 1], path[i])?;
+			amounts[i - 1] = Self::get_amount_in(amounts[i], reserve_in, reserve_out, swap_fee)?;
 		}
 
 		Ok(amounts)
@@ -0,0 +1,64 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the exchange pallet.
+
+use super::*;
+use frame_support::{
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+
+/// Gives the pools created before pools had their own swap fee the fee they were charged.
+pub mod v1 {
+	use super::*;
+
+	/// The swap fee of every pool before pools had their own, in basis points.
+	pub const SWAP_FEE: u16 = 30;
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldPool<AccountId, FungibleTokenId> {
+		pub token_0: FungibleTokenId,
+		pub token_1: FungibleTokenId,
+		pub lp_token: FungibleTokenId,
+		pub lp_token_account_id: AccountId,
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: Weight = 0;
+		Pools::<T>::translate_values(|pool: OldPool<T::AccountId, T::FungibleTokenId>| {
+			translated.saturating_inc();
+			Some(Pool {
+				token_0: pool.token_0,
+				token_1: pool.token_1,
+				lp_token: pool.lp_token,
+				lp_token_account_id: pool.lp_token_account_id,
+				swap_fee: SWAP_FEE,
+			})
+		});
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+	}
+}
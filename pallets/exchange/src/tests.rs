@@ -19,6 +19,7 @@
 use super::*;
 pub use crate::mock::*;
 use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
 
 const ALICE: u64 = 1;
 
@@ -37,21 +38,24 @@ fn create_tokens() {
 		TOKENA,
 		b"W3G1".to_vec(),
 		b"W3G1".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::create_token(
 		Origin::signed(ALICE),
 		TOKENB,
 		b"W3G2".to_vec(),
 		b"W3G2".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::create_token(
 		Origin::signed(ALICE),
 		TOKENC,
 		b"W3G2".to_vec(),
 		b"W3G2".to_vec(),
-		18
+		18,
+		None
 	));
 }
 fn set_balance() {
@@ -216,7 +220,8 @@ fn swap_exact_tokens_for_tokens_should_work() {
 		let (reserve_in, reserve_out) = Exchange::get_reserves(TOKENA, TOKENB).unwrap();
 		assert!(reserve_in == TOKENA_LIQUIDITY && reserve_out == TOKENB_LIQUIDITY);
 
-		let amount_out_1 = Exchange::get_amount_out(SWAP_VALUE, reserve_in, reserve_out).unwrap();
+		let amount_out_1 =
+			Exchange::get_amount_out(SWAP_VALUE, reserve_in, reserve_out, 30).unwrap();
 
 		let amount_in_with_fee: U256 = U256::from(SWAP_VALUE).saturating_mul(U256::from(997u128));
 		let numerator: U256 =
@@ -278,7 +283,7 @@ fn swap_tokens_for_exact_tokens_should_works() {
 		let (reserve_in, reserve_out) = Exchange::get_reserves(TOKENA, TOKENB).unwrap();
 		assert!(reserve_in == TOKENA_LIQUIDITY && reserve_out == TOKENB_LIQUIDITY);
 
-		let amount_in_1 = Exchange::get_amount_in(SWAP_VALUE, reserve_in, reserve_out).unwrap();
+		let amount_in_1 = Exchange::get_amount_in(SWAP_VALUE, reserve_in, reserve_out, 30).unwrap();
 
 		let numerator: U256 = U256::from(reserve_in)
 			.saturating_mul(U256::from(SWAP_VALUE))
@@ -317,3 +322,96 @@ fn swap_tokens_for_exact_tokens_should_works() {
 		);
 	})
 }
+
+#[test]
+fn swap_fee_migration_should_work() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+	use migrations::v1::OldPool;
+
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		assert_ok!(Exchange::create_pool(Origin::signed(ALICE), TOKENA, TOKENB));
+		assert_ok!(Exchange::set_swap_fee(Origin::root(), TOKENA, TOKENB, 5));
+
+		// A pool from before pools had their own swap fee.
+		let pool = Exchange::pools((TOKENA, TOKENB)).unwrap();
+		frame_support::storage::unhashed::put(
+			&Pools::<Test>::hashed_key_for((TOKENA, TOKENB)),
+			&OldPool {
+				token_0: pool.token_0,
+				token_1: pool.token_1,
+				lp_token: pool.lp_token,
+				lp_token_account_id: pool.lp_token_account_id,
+			},
+		);
+		StorageVersion::new(0).put::<Exchange>();
+
+		migrations::v1::migrate::<Test>();
+		assert_eq!(Exchange::get_swap_fee(TOKENA, TOKENB).unwrap(), 30);
+		assert_eq!(Exchange::on_chain_storage_version(), 1);
+	})
+}
+
+#[test]
+fn set_swap_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		assert_ok!(Exchange::create_pool(Origin::signed(ALICE), TOKENA, TOKENB));
+		assert_eq!(Exchange::get_swap_fee(TOKENB, TOKENA).unwrap(), 30);
+
+		set_balance();
+
+		assert_ok!(Exchange::add_liquidity(
+			Origin::signed(ALICE),
+			TOKENA,
+			TOKENB,
+			TOKENA_LIQUIDITY,
+			TOKENB_LIQUIDITY,
+			0u128,
+			0u128,
+			ALICE,
+			100
+		));
+
+		assert_noop!(Exchange::set_swap_fee(Origin::signed(ALICE), TOKENA, TOKENB, 5), BadOrigin);
+		assert_noop!(
+			Exchange::set_swap_fee(Origin::root(), TOKENA, TOKENB, 10_000),
+			Error::<Test>::InvalidFee
+		);
+		assert_noop!(
+			Exchange::set_swap_fee(Origin::root(), TOKENA, TOKENC, 5),
+			Error::<Test>::PoolNotFound
+		);
+		assert_ok!(Exchange::set_swap_fee(Origin::root(), TOKENB, TOKENA, 5));
+		assert_eq!(Exchange::get_swap_fee(TOKENA, TOKENB).unwrap(), 5);
+
+		let path: Vec<u128> = vec![TOKENA, TOKENB];
+		let amount_out = Exchange::get_amounts_out(SWAP_VALUE, path.clone()).unwrap()[1];
+		assert_eq!(
+			amount_out,
+			Exchange::get_amount_out(SWAP_VALUE, TOKENA_LIQUIDITY, TOKENB_LIQUIDITY, 5).unwrap()
+		);
+		assert!(
+			amount_out >
+				Exchange::get_amount_out(SWAP_VALUE, TOKENA_LIQUIDITY, TOKENB_LIQUIDITY, 30)
+					.unwrap()
+		);
+		assert_ok!(Exchange::swap_exact_tokens_for_tokens(
+			Origin::signed(ALICE),
+			SWAP_VALUE,
+			amount_out,
+			path,
+			ALICE,
+			100
+		));
+		assert_eq!(
+			TokenFungible::balance_of(TOKENB, ALICE),
+			INITIAL_BALANCE - TOKENB_LIQUIDITY + amount_out
+		);
+
+		assert_noop!(Exchange::set_protocol_fee(Origin::signed(ALICE), 2_500), BadOrigin);
+		assert_noop!(Exchange::set_protocol_fee(Origin::root(), 10_001), Error::<Test>::InvalidFee);
+		assert_ok!(Exchange::set_protocol_fee(Origin::root(), 2_500));
+		assert_eq!(Exchange::protocol_fee(), 2_500);
+	})
+}
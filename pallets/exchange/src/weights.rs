@@ -51,6 +51,8 @@ pub trait WeightInfo {
 	fn remove_liquidity() -> Weight;
 	fn swap_exact_tokens_for_tokens() -> Weight;
 	fn swap_tokens_for_exact_tokens() -> Weight;
+	fn set_swap_fee() -> Weight;
+	fn set_protocol_fee() -> Weight;
 }
 
 /// Weights for web3games_exchange using the Web3Games node and recommended hardware.
@@ -73,9 +75,10 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: Exchange KLast (r:1 w:0)
 	// Storage: Exchange FeeTo (r:1 w:0)
 	// Storage: TokenFungible Tokens (r:1 w:1)
+	// Storage: Exchange ProtocolFee (r:1 w:0)
 	fn add_liquidity() -> Weight {
 		(84_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	// Storage: Exchange Pools (r:1 w:0)
@@ -84,9 +87,10 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: Exchange KLast (r:1 w:0)
 	// Storage: Exchange FeeTo (r:1 w:0)
 	// Storage: TokenFungible Tokens (r:1 w:1)
+	// Storage: Exchange ProtocolFee (r:1 w:0)
 	fn remove_liquidity() -> Weight {
 		(89_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	// Storage: Exchange Pools (r:1 w:0)
@@ -105,6 +109,19 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	// Storage: Exchange Pools (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_swap_fee() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Exchange ProtocolFee (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_protocol_fee() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -116,12 +133,12 @@ impl WeightInfo for () {
 	}
 	fn add_liquidity() -> Weight {
 		(84_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	fn remove_liquidity() -> Weight {
 		(89_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	fn swap_exact_tokens_for_tokens() -> Weight {
@@ -134,4 +151,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
+	fn set_swap_fee() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_protocol_fee() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
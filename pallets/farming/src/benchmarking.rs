@@ -5,9 +5,9 @@
 use super::*;
 use crate::Pallet as Farming;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::Currency};
 use frame_system::{Pallet as System, RawOrigin};
-use sp_runtime::traits::UniqueSaturatedFrom;
+use sp_runtime::traits::{Saturating, UniqueSaturatedFrom};
 use web3games_token_fungible::Pallet as TokenFungible;
 
 const W3G: u128 = 1;
@@ -19,19 +19,27 @@ fn setup<T: Config>() -> DispatchResult {
 	let alice: T::AccountId = account("alice", 0, 0);
 	let bob: T::AccountId = account("bob", 0, 0);
 
+	<T as web3games_token_fungible::Config>::Currency::make_free_balance_be(
+		&alice,
+		<T as web3games_token_fungible::Config>::CreateTokenDeposit::get()
+			.saturating_mul(1_000u32.into()),
+	);
+
 	assert_ok!(TokenFungible::<T>::create_token(
 		RawOrigin::Signed(alice.clone()).into(),
 		<T as web3games_token_fungible::Config>::FungibleTokenId::unique_saturated_from(W3G),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::<T>::create_token(
 		RawOrigin::Signed(alice.clone()).into(),
 		<T as web3games_token_fungible::Config>::FungibleTokenId::unique_saturated_from(USDT),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::<T>::mint(
 		RawOrigin::Signed(alice.clone()).into(),
@@ -19,15 +19,16 @@
 use crate as web3games_farming;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
 	PalletId,
 };
 use primitives::Balance;
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Convert, IdentityLookup},
 };
+use web3games_support::TokenIdConversion;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -45,6 +46,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		TokenFungible: web3games_token_fungible::{Pallet, Call, Storage, Event<T>},
 		Farming: web3games_farming::{Pallet, Call, Storage, Event<T>},
 	}
@@ -93,6 +95,31 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+pub struct MockAddressMapping;
+impl Convert<H160, u64> for MockAddressMapping {
+	fn convert(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+pub struct MockTokenIdConversion;
+impl TokenIdConversion<u128> for MockTokenIdConversion {
+	fn try_from_address(address: H160) -> Option<u128> {
+		Some(address.to_low_u64_be().into())
+	}
+
+	fn into_address(id: u128) -> H160 {
+		H160::from_low_u64_be(id as u64)
+	}
+}
+
 parameter_types! {
 	pub const TokenFungiblePalletId: PalletId = PalletId(*b"w3g/tfpi");
 	pub const StringLimit: u32 = 50;
@@ -106,6 +133,13 @@ impl web3games_token_fungible::Config for Test {
 	type StringLimit = StringLimit;
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type Currency = Balances;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type UnixTime = Timestamp;
+	type MaxVestingSchedules = ConstU32<8>;
+	type MinVestedTransfer = ConstU128<10>;
+	type RemoveItemsLimit = ConstU32<1000>;
 	type WeightInfo = ();
 }
 
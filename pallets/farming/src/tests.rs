@@ -38,21 +38,24 @@ fn create_tokens() {
 		W3G,
 		b"W3G1".to_vec(),
 		b"W3G1".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::create_token(
 		Origin::signed(ALICE),
 		USDT,
 		b"W3G2".to_vec(),
 		b"W3G2".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::create_token(
 		Origin::signed(ALICE),
 		USDC,
 		b"W3G2".to_vec(),
 		b"W3G2".to_vec(),
-		18
+		18,
+		None
 	));
 }
 fn set_balance() {
@@ -0,0 +1,84 @@
+//! Benchmarking setup for web3games-loot-box
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as LootBox;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{assert_ok, traits::Currency};
+use frame_system::{Pallet as System, RawOrigin};
+use sp_runtime::traits::{One, Saturating};
+use web3games_token_multi::Pallet as TokenMulti;
+
+const ITEMS: u128 = 1;
+const BOX: u128 = 1;
+
+fn setup<T: Config>() -> DispatchResult {
+	let alice: T::AccountId = account("alice", 0, 0);
+	let bob: T::AccountId = account("bob", 0, 0);
+	<T as web3games_token_multi::Config>::Currency::make_free_balance_be(
+		&alice,
+		<T as web3games_token_multi::Config>::CreateTokenDeposit::get()
+			.saturating_mul(1_000u32.into()),
+	);
+
+	assert_ok!(TokenMulti::<T>::create_token(
+		RawOrigin::Signed(alice.clone()).into(),
+		MultiTokenIdOf::<T>::unique_saturated_from(ITEMS),
+		b"https://web3games.com/".to_vec(),
+	));
+	assert_ok!(TokenMulti::<T>::mint(
+		RawOrigin::Signed(alice).into(),
+		MultiTokenIdOf::<T>::unique_saturated_from(ITEMS),
+		bob,
+		MultiTokenTokenIdOf::<T>::unique_saturated_from(BOX),
+		10,
+	));
+	Ok(())
+}
+
+/// `d` drops of different multi tokens.
+fn drops(d: u32) -> Vec<WeightedReward> {
+	(0..d as u128)
+		.map(|token_id| WeightedReward {
+			weight: 1,
+			reward: Reward::MultiToken(ITEMS, 1_000 + token_id, 1),
+		})
+		.collect()
+}
+
+fn create_loot_box<T: Config>() -> DispatchResult {
+	let alice: T::AccountId = account("alice", 0, 0);
+	setup::<T>()?;
+	assert_ok!(LootBox::<T>::create_loot_box(
+		RawOrigin::Signed(alice).into(),
+		ITEMS,
+		BOX,
+		drops(<T as Config>::MaxDrops::get()),
+	));
+	Ok(())
+}
+
+benchmarks! {
+	create_loot_box {
+		let d in 1 .. <T as Config>::MaxDrops::get();
+		let alice: T::AccountId = account("alice", 0, 0);
+		setup::<T>()?;
+	}: _(RawOrigin::Signed(alice), ITEMS, BOX, drops(d))
+
+	open {
+		let bob: T::AccountId = account("bob", 0, 0);
+		create_loot_box::<T>()?;
+	}: _(RawOrigin::Signed(bob), 0)
+
+	reveal {
+		let bob: T::AccountId = account("bob", 0, 0);
+		create_loot_box::<T>()?;
+		assert_ok!(LootBox::<T>::open(RawOrigin::Signed(bob.clone()).into(), 0));
+		let reveal_at = LootBox::<T>::openings(0).unwrap().reveal_at;
+		T::Randomness::set_seed(reveal_at, Default::default());
+		System::<T>::set_block_number(reveal_at.saturating_add(One::one()));
+	}: _(RawOrigin::Signed(bob), 0)
+
+	impl_benchmark_test_suite!(LootBox, crate::mock::new_test_ext(), crate::mock::Test);
+}
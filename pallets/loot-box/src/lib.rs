@@ -0,0 +1,471 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, storage::with_storage_layer, PalletId};
+use frame_system::pallet_prelude::*;
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Hash, UniqueSaturatedFrom, Zero},
+	DispatchResult, RuntimeDebug,
+};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use web3games_support::DelayedSeed;
+
+pub use pallet::*;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type NonFungibleTokenIdOf<T> = <T as web3games_token_non_fungible::Config>::NonFungibleTokenId;
+type NonFungibleTokenTokenIdOf<T> = <T as web3games_token_non_fungible::Config>::TokenId;
+type MultiTokenIdOf<T> = <T as web3games_token_multi::Config>::MultiTokenId;
+type MultiTokenTokenIdOf<T> = <T as web3games_token_multi::Config>::TokenId;
+
+type NonFungibleTokenId = u128;
+type MultiTokenId = u128;
+type TokenId = u128;
+
+pub type LootBoxId = u64;
+pub type OpeningId = u64;
+
+pub type LootBoxOf<T> = LootBoxDetails<
+	<T as frame_system::Config>::AccountId,
+	BoundedVec<WeightedReward, <T as Config>::MaxDrops>,
+>;
+
+pub type OpeningOf<T> =
+	Opening<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+/// What opening a loot box can mint.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Reward {
+	/// An amount of a multi token.
+	MultiToken(MultiTokenId, TokenId, Balance),
+	/// A non-fungible token out of the given number, numbered from the given token id.
+	///
+	/// The range is reserved for the box when it is created.
+	NonFungibleToken(NonFungibleTokenId, TokenId, u32),
+}
+
+/// A reward drawn with a `weight` chance out of the total of its loot box.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct WeightedReward {
+	pub weight: u32,
+	pub reward: Reward,
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct LootBoxDetails<AccountId, Drops> {
+	/// The account that defined the box, it mints the rewards.
+	pub owner: AccountId,
+	/// The multi token held in escrow while a box is opened, and burned once it is revealed.
+	pub multi_token_id: MultiTokenId,
+	pub token_id: TokenId,
+	pub drops: Drops,
+	/// The number of boxes revealed.
+	pub opened: u32,
+}
+
+/// An escrowed box waiting for the hash of a later block.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Opening<AccountId, BlockNumber> {
+	pub loot_box_id: LootBoxId,
+	pub who: AccountId,
+	/// The block whose hash seeds the draw.
+	pub reveal_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + web3games_token_non_fungible::Config + web3games_token_multi::Config
+	{
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The loot box pallet id, its account holds the boxes being opened.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The hashes of later blocks that seed the draws.
+		type Randomness: DelayedSeed<Self::BlockNumber, Self::Hash>;
+
+		/// The maximum number of drops of a loot box.
+		#[pallet::constant]
+		type MaxDrops: Get<u32>;
+
+		/// The maximum number of token id ranges loot boxes reserve in a non-fungible token.
+		#[pallet::constant]
+		type MaxReservedRanges: Get<u32>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NoAvailableLootBoxId,
+		NoAvailableOpeningId,
+		LootBoxNotFound,
+		OpeningNotFound,
+		InvalidDrops,
+		TooManyDrops,
+		NoPermission,
+		TokenIdsReserved,
+		TooManyReservedRanges,
+		NumOverflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		LootBoxCreated(LootBoxId, T::AccountId),
+		/// A box was put in escrow, its reward is drawn from the hash of the given block.
+		LootBoxOpened(OpeningId, LootBoxId, T::AccountId, T::BlockNumber),
+		/// The reward of an opening was drawn from the seed, the drop at the given index.
+		LootBoxRevealed(OpeningId, LootBoxId, T::AccountId, T::Hash, u32),
+		/// No reward could be minted for an opening, the box was returned.
+		LootBoxRefunded(OpeningId, LootBoxId, T::AccountId),
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_loot_box_id)]
+	pub type NextLootBoxId<T: Config> = StorageValue<_, LootBoxId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn loot_boxes)]
+	pub type LootBoxes<T: Config> = StorageMap<_, Blake2_128Concat, LootBoxId, LootBoxOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_opening_id)]
+	pub type NextOpeningId<T: Config> = StorageValue<_, OpeningId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn openings)]
+	pub type Openings<T: Config> = StorageMap<_, Blake2_128Concat, OpeningId, OpeningOf<T>>;
+
+	/// The number of rewards each drop of a loot box minted.
+	#[pallet::storage]
+	#[pallet::getter(fn drops_minted)]
+	pub type DropsMinted<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, LootBoxId, Twox64Concat, u32, u32, ValueQuery>;
+
+	/// The first and last token ids of the ranges reserved by loot boxes in each non-fungible
+	/// token.
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_ranges)]
+	pub type ReservedRanges<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		NonFungibleTokenId,
+		BoundedVec<(TokenId, TokenId), T::MaxReservedRanges>,
+		ValueQuery,
+	>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Define a loot box opened by burning `token_id` of `multi_token_id`.
+		///
+		/// The sender has to be able to mint every reward, reveals mint them on its behalf.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_loot_box(drops.len() as u32))]
+		pub fn create_loot_box(
+			origin: OriginFor<T>,
+			multi_token_id: MultiTokenId,
+			token_id: TokenId,
+			drops: Vec<WeightedReward>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_create_loot_box(&who, multi_token_id, token_id, drops)?;
+			Ok(())
+		}
+
+		/// Put a box of the sender in escrow, its reward is drawn by `reveal` once the seed is
+		/// known.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::open())]
+		pub fn open(origin: OriginFor<T>, loot_box_id: LootBoxId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_open(&who, loot_box_id)?;
+			Ok(())
+		}
+
+		/// Draw and mint the reward of an opening, anyone can reveal for the opener.
+		///
+		/// The box is burned, or returned to the opener if no reward can be minted.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reveal())]
+		pub fn reveal(origin: OriginFor<T>, opening_id: OpeningId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_reveal(opening_id)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn do_create_loot_box(
+		who: &T::AccountId,
+		multi_token_id: MultiTokenId,
+		token_id: TokenId,
+		drops: Vec<WeightedReward>,
+	) -> Result<LootBoxId, DispatchError> {
+		ensure!(
+			web3games_token_multi::Pallet::<T>::has_permission(
+				MultiTokenIdOf::<T>::unique_saturated_from(multi_token_id),
+				who,
+			),
+			Error::<T>::NoPermission
+		);
+
+		let drops: BoundedVec<WeightedReward, <T as Config>::MaxDrops> =
+			drops.try_into().map_err(|_| Error::<T>::TooManyDrops)?;
+		ensure!(!drops.is_empty(), Error::<T>::InvalidDrops);
+		// Drops are drawn over the total weight, which must not overflow.
+		drops
+			.iter()
+			.try_fold(0u32, |total, drop| match drop.weight {
+				0 => None,
+				weight => total.checked_add(weight),
+			})
+			.ok_or(Error::<T>::InvalidDrops)?;
+
+		let mut reserved_ranges = BTreeMap::new();
+		for drop in drops.iter() {
+			let can_mint = match drop.reward {
+				Reward::MultiToken(id, _, amount) => {
+					ensure!(!amount.is_zero(), Error::<T>::InvalidDrops);
+					web3games_token_multi::Pallet::<T>::has_permission(
+						MultiTokenIdOf::<T>::unique_saturated_from(id),
+						who,
+					)
+				},
+				Reward::NonFungibleToken(id, first_token_id, count) => {
+					ensure!(!count.is_zero(), Error::<T>::InvalidDrops);
+					let last_token_id = first_token_id
+						.checked_add((count - 1).into())
+						.ok_or(Error::<T>::NumOverflow)?;
+					let ranges: &mut BoundedVec<_, _> =
+						reserved_ranges.entry(id).or_insert_with(|| ReservedRanges::<T>::get(id));
+					ensure!(
+						ranges
+							.iter()
+							.all(|&(first, last)| last_token_id < first || first_token_id > last),
+						Error::<T>::TokenIdsReserved
+					);
+					ranges
+						.try_push((first_token_id, last_token_id))
+						.map_err(|_| Error::<T>::TooManyReservedRanges)?;
+
+					web3games_token_non_fungible::Pallet::<T>::has_permission(
+						NonFungibleTokenIdOf::<T>::unique_saturated_from(id),
+						who,
+					)
+				},
+			};
+			ensure!(can_mint, Error::<T>::NoPermission);
+		}
+
+		let loot_box_id =
+			NextLootBoxId::<T>::try_mutate(|id| -> Result<LootBoxId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(1).ok_or(Error::<T>::NoAvailableLootBoxId)?;
+				Ok(current_id)
+			})?;
+
+		LootBoxes::<T>::insert(
+			loot_box_id,
+			LootBoxDetails { owner: who.clone(), multi_token_id, token_id, drops, opened: 0 },
+		);
+		for (id, ranges) in reserved_ranges {
+			ReservedRanges::<T>::insert(id, ranges);
+		}
+
+		Self::deposit_event(Event::LootBoxCreated(loot_box_id, who.clone()));
+
+		Ok(loot_box_id)
+	}
+
+	pub fn account_id() -> T::AccountId {
+		<T as pallet::Config>::PalletId::get().into_account_truncating()
+	}
+
+	/// Put a box of `who` in escrow, committing to the hash of a later block.
+	pub fn do_open(who: &T::AccountId, loot_box_id: LootBoxId) -> Result<OpeningId, DispatchError> {
+		let loot_box = LootBoxes::<T>::get(loot_box_id).ok_or(Error::<T>::LootBoxNotFound)?;
+
+		web3games_token_multi::Pallet::<T>::do_deposit_escrow(
+			MultiTokenIdOf::<T>::unique_saturated_from(loot_box.multi_token_id),
+			who,
+			&Self::account_id(),
+			MultiTokenTokenIdOf::<T>::unique_saturated_from(loot_box.token_id),
+			1,
+		)?;
+
+		let opening_id =
+			NextOpeningId::<T>::try_mutate(|id| -> Result<OpeningId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(1).ok_or(Error::<T>::NoAvailableOpeningId)?;
+				Ok(current_id)
+			})?;
+
+		let reveal_at = T::Randomness::commit();
+		Openings::<T>::insert(opening_id, Opening { loot_box_id, who: who.clone(), reveal_at });
+
+		Self::deposit_event(Event::LootBoxOpened(opening_id, loot_box_id, who.clone(), reveal_at));
+
+		Ok(opening_id)
+	}
+
+	/// Draw the reward of an opening from the hash of its reveal block, unknown when the box
+	/// was opened, returning the index of the drop.
+	///
+	/// The draw only depends on the opening, not on the block it is revealed at. If no drop is
+	/// left or its reward can't be minted, the box goes back to the opener and `None` is
+	/// returned.
+	pub fn do_reveal(opening_id: OpeningId) -> Result<Option<u32>, DispatchError> {
+		let opening = Openings::<T>::get(opening_id).ok_or(Error::<T>::OpeningNotFound)?;
+		let seed = T::Hashing::hash_of(&(
+			T::Randomness::seed_of(opening.reveal_at)?,
+			b"loot-box",
+			opening_id,
+		));
+
+		let mut loot_box =
+			LootBoxes::<T>::get(opening.loot_box_id).ok_or(Error::<T>::LootBoxNotFound)?;
+		let multi_token_id = MultiTokenIdOf::<T>::unique_saturated_from(loot_box.multi_token_id);
+		let token_id = MultiTokenTokenIdOf::<T>::unique_saturated_from(loot_box.token_id);
+
+		let revealed = Self::draw(opening.loot_box_id, &loot_box.drops, &seed).and_then(|index| {
+			with_storage_layer(|| {
+				Self::mint_reward(opening.loot_box_id, &loot_box, index, &opening.who)
+			})
+			.ok()
+			.map(|()| index)
+		});
+		match revealed {
+			Some(index) => {
+				web3games_token_multi::Pallet::<T>::do_burn(
+					&Self::account_id(),
+					multi_token_id,
+					token_id,
+					1,
+				)?;
+				loot_box.opened = loot_box.opened.checked_add(1).ok_or(Error::<T>::NumOverflow)?;
+				Self::deposit_event(Event::LootBoxRevealed(
+					opening_id,
+					opening.loot_box_id,
+					opening.who.clone(),
+					seed,
+					index as u32,
+				));
+			},
+			None => {
+				web3games_token_multi::Pallet::<T>::do_release_escrow(
+					multi_token_id,
+					&Self::account_id(),
+					&opening.who,
+					token_id,
+					1,
+				)?;
+				Self::deposit_event(Event::LootBoxRefunded(
+					opening_id,
+					opening.loot_box_id,
+					opening.who.clone(),
+				));
+			},
+		}
+
+		LootBoxes::<T>::insert(opening.loot_box_id, loot_box);
+		Openings::<T>::remove(opening_id);
+		T::Randomness::release(opening.reveal_at);
+
+		Ok(revealed.map(|index| index as u32))
+	}
+
+	/// Mint the reward of the drop at `index` to `who` on behalf of the owner of the box.
+	fn mint_reward(
+		loot_box_id: LootBoxId,
+		loot_box: &LootBoxOf<T>,
+		index: usize,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let minted = DropsMinted::<T>::get(loot_box_id, index as u32);
+		match loot_box.drops[index].reward {
+			Reward::MultiToken(id, token_id, amount) =>
+				web3games_token_multi::Pallet::<T>::do_mint(
+					&loot_box.owner,
+					MultiTokenIdOf::<T>::unique_saturated_from(id),
+					who,
+					MultiTokenTokenIdOf::<T>::unique_saturated_from(token_id),
+					amount,
+				)?,
+			Reward::NonFungibleToken(id, first_token_id, _) => {
+				let token_id =
+					first_token_id.checked_add(minted.into()).ok_or(Error::<T>::NumOverflow)?;
+				web3games_token_non_fungible::Pallet::<T>::do_mint(
+					&loot_box.owner,
+					NonFungibleTokenIdOf::<T>::unique_saturated_from(id),
+					who,
+					NonFungibleTokenTokenIdOf::<T>::unique_saturated_from(token_id),
+				)?
+			},
+		}
+		DropsMinted::<T>::insert(
+			loot_box_id,
+			index as u32,
+			minted.checked_add(1).ok_or(Error::<T>::NumOverflow)?,
+		);
+		Ok(())
+	}
+
+	/// The index of the drop `seed` draws by weight, leaving out non-fungible drops that
+	/// minted all their tokens, `None` if none is left.
+	pub fn draw(loot_box_id: LootBoxId, drops: &[WeightedReward], seed: &T::Hash) -> Option<usize> {
+		let weights: Vec<u32> = drops
+			.iter()
+			.enumerate()
+			.map(|(index, drop)| match drop.reward {
+				Reward::NonFungibleToken(_, _, count)
+					if DropsMinted::<T>::get(loot_box_id, index as u32) >= count =>
+					0,
+				_ => drop.weight,
+			})
+			.collect();
+		if weights.iter().all(Zero::is_zero) {
+			return None
+		}
+		Some(web3games_support::draw_weighted(seed.as_ref(), &weights))
+	}
+}
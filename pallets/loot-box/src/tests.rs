@@ -0,0 +1,210 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+pub use crate::mock::{Event, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::Header as _;
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+
+const ITEMS: u128 = 1;
+const HEROES: u128 = 2;
+const BOX: u128 = 1;
+const SWORD: u128 = 7;
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let header = System::finalize();
+		System::initialize(&(header.number + 1), &header.hash(), &Default::default());
+		Randomness::on_initialize(System::block_number());
+	}
+}
+
+fn create_tokens() {
+	assert_ok!(TokenMulti::create_token(
+		Origin::signed(ALICE),
+		ITEMS,
+		b"https://web3games.com/".to_vec()
+	));
+	assert_ok!(TokenMulti::mint(Origin::signed(ALICE), ITEMS, BOB, BOX, 2));
+	assert_ok!(TokenNonFungible::create_token(
+		Origin::signed(ALICE),
+		HEROES,
+		b"Heroes".to_vec(),
+		b"HRS".to_vec(),
+		b"https://web3games.com/".to_vec()
+	));
+}
+
+/// A box dropping 5 SWORD or one of ten heroes numbered from 100, at even odds.
+fn drops() -> Vec<WeightedReward> {
+	vec![
+		WeightedReward { weight: 1, reward: Reward::MultiToken(ITEMS, SWORD, 5) },
+		WeightedReward { weight: 1, reward: Reward::NonFungibleToken(HEROES, 100, 10) },
+	]
+}
+
+#[test]
+fn create_loot_box_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+
+		assert_noop!(
+			LootBox::create_loot_box(Origin::signed(BOB), ITEMS, BOX, drops()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, vec![]),
+			Error::<Test>::InvalidDrops
+		);
+		assert_noop!(
+			LootBox::create_loot_box(
+				Origin::signed(ALICE),
+				ITEMS,
+				BOX,
+				vec![WeightedReward { weight: 0, reward: Reward::MultiToken(ITEMS, SWORD, 5) }]
+			),
+			Error::<Test>::InvalidDrops
+		);
+
+		assert_ok!(LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, drops()));
+		let loot_box = LootBox::loot_boxes(0).unwrap();
+		assert_eq!(loot_box.owner, ALICE);
+		assert_eq!(loot_box.drops.to_vec(), drops());
+		assert_eq!(LootBox::next_loot_box_id(), 1);
+		assert_eq!(LootBox::reserved_ranges(HEROES).to_vec(), vec![(100, 109)]);
+
+		// Boxes can't share token ids.
+		let heroes = |first_token_id, count| {
+			vec![WeightedReward {
+				weight: 1,
+				reward: Reward::NonFungibleToken(HEROES, first_token_id, count),
+			}]
+		};
+		assert_noop!(
+			LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, heroes(109, 5)),
+			Error::<Test>::TokenIdsReserved
+		);
+		assert_noop!(
+			LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, heroes(110, 0)),
+			Error::<Test>::InvalidDrops
+		);
+		assert_ok!(LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, heroes(110, 5)));
+		assert_noop!(
+			LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, heroes(200, 5)),
+			Error::<Test>::TooManyReservedRanges
+		);
+	})
+}
+
+#[test]
+fn open_and_reveal_should_work() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		assert_ok!(LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, drops()));
+
+		assert_ok!(LootBox::open(Origin::signed(BOB), 0));
+		assert_ok!(LootBox::open(Origin::signed(BOB), 0));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (BOX, BOB)), 0);
+		assert_eq!(TokenMulti::balance_of(ITEMS, (BOX, LootBox::account_id())), 2);
+		assert_eq!(LootBox::openings(0).unwrap().reveal_at, 3);
+		assert_eq!(Randomness::pending_reveals(3), 2);
+
+		run_to_block(3);
+		assert_noop!(
+			LootBox::reveal(Origin::signed(ALICE), 0),
+			web3games_randomness::Error::<Test>::RevealTooEarly
+		);
+
+		run_to_block(4);
+		let block_seed = System::parent_hash();
+		assert_eq!(Randomness::seeds(3), Some(block_seed));
+		assert_ok!(LootBox::reveal(Origin::signed(ALICE), 0));
+		let seed =
+			<Test as frame_system::Config>::Hashing::hash_of(&(block_seed, b"loot-box", 0u64));
+		let index = LootBox::draw(0, &drops(), &seed).unwrap();
+		System::assert_last_event(Event::LootBox(crate::Event::LootBoxRevealed(
+			0,
+			0,
+			BOB,
+			seed,
+			index as u32,
+		)));
+		match index {
+			0 => assert_eq!(TokenMulti::balance_of(ITEMS, (SWORD, BOB)), 5),
+			_ => assert_eq!(TokenNonFungible::owner_of(HEROES, 100), Some(BOB)),
+		}
+		assert_eq!(LootBox::loot_boxes(0).unwrap().opened, 1);
+		assert_eq!(LootBox::drops_minted(0, index as u32), 1);
+		assert_eq!(TokenMulti::balance_of(ITEMS, (BOX, LootBox::account_id())), 1);
+		assert_eq!(LootBox::openings(0), None);
+		assert_noop!(LootBox::reveal(Origin::signed(ALICE), 0), Error::<Test>::OpeningNotFound);
+
+		// A later reveal draws from the same block hash.
+		run_to_block(10);
+		assert_ok!(LootBox::reveal(Origin::signed(ALICE), 1));
+		let seed =
+			<Test as frame_system::Config>::Hashing::hash_of(&(block_seed, b"loot-box", 1u64));
+		System::assert_last_event(Event::LootBox(crate::Event::LootBoxRevealed(
+			1,
+			0,
+			BOB,
+			seed,
+			LootBox::draw(0, &drops(), &seed).unwrap() as u32,
+		)));
+		assert_eq!(Randomness::pending_reveals(3), 0);
+		assert_eq!(Randomness::seeds(3), None);
+	})
+}
+
+#[test]
+fn failed_reveal_should_refund() {
+	new_test_ext().execute_with(|| {
+		create_tokens();
+		let hero =
+			vec![WeightedReward { weight: 1, reward: Reward::NonFungibleToken(HEROES, 100, 1) }];
+		assert_ok!(LootBox::create_loot_box(Origin::signed(ALICE), ITEMS, BOX, hero));
+		// The owner minted the token id of the drop already.
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), HEROES, ALICE, 100));
+
+		assert_ok!(LootBox::open(Origin::signed(BOB), 0));
+		assert_ok!(LootBox::open(Origin::signed(BOB), 0));
+		run_to_block(4);
+		assert_ok!(LootBox::reveal(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::LootBox(crate::Event::LootBoxRefunded(0, 0, BOB)));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (BOX, BOB)), 1);
+		assert_eq!(LootBox::drops_minted(0, 0), 0);
+		assert_eq!(LootBox::openings(0), None);
+
+		assert_ok!(TokenNonFungible::burn(Origin::signed(ALICE), HEROES, 100));
+		assert_ok!(LootBox::reveal(Origin::signed(ALICE), 1));
+		assert_eq!(TokenNonFungible::owner_of(HEROES, 100), Some(BOB));
+		assert_eq!(LootBox::drops_minted(0, 0), 1);
+
+		// The only drop has no token left.
+		assert_ok!(LootBox::open(Origin::signed(BOB), 0));
+		run_to_block(7);
+		assert_ok!(LootBox::reveal(Origin::signed(ALICE), 2));
+		System::assert_last_event(Event::LootBox(crate::Event::LootBoxRefunded(2, 0, BOB)));
+		assert_eq!(TokenMulti::balance_of(ITEMS, (BOX, BOB)), 1);
+		assert_eq!(TokenMulti::balance_of(ITEMS, (BOX, LootBox::account_id())), 0);
+		assert_eq!(LootBox::loot_boxes(0).unwrap().opened, 1);
+	})
+}
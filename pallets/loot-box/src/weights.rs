@@ -0,0 +1,114 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for web3games_loot_box
+//!
+//! These are estimates from the storage accesses of each call, scaled from the benchmarked
+//! token pallets. They have not been benchmarked yet: run `scripts/generate-weights.sh` on
+//! reference hardware to replace this file with measured weights.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for web3games_loot_box.
+pub trait WeightInfo {
+	fn create_loot_box(d: u32, ) -> Weight;
+	fn open() -> Weight;
+	fn reveal() -> Weight;
+}
+
+/// Weights for web3games_loot_box using the Web3Games node and recommended hardware.
+pub struct W3GWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: LootBox ReservedRanges (r:1 w:1)
+	// Storage: LootBox NextLootBoxId (r:1 w:1)
+	// Storage: LootBox LootBoxes (r:0 w:1)
+	fn create_loot_box(d: u32, ) -> Weight {
+		(26_000_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((4_100_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(d as Weight)))
+	}
+	// Storage: LootBox LootBoxes (r:1 w:0)
+	// Storage: TokenMulti FrozenTokens (r:1 w:0)
+	// Storage: TokenMulti Frozen (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti Balances (r:2 w:2)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenMulti Inventory (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Storage: LootBox NextOpeningId (r:1 w:1)
+	// Storage: Randomness PendingReveals (r:1 w:1)
+	// Storage: LootBox Openings (r:0 w:1)
+	fn open() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: LootBox Openings (r:1 w:1)
+	// Storage: Randomness Seeds (r:1 w:1)
+	// Storage: LootBox LootBoxes (r:1 w:1)
+	// Storage: LootBox DropsMinted (r:1 w:1)
+	// Storage: TokenMulti Tokens (r:1 w:2)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti Balances (r:2 w:2)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenMulti TokenIds (r:2 w:2)
+	// Storage: TokenMulti Inventory (r:2 w:2)
+	// Storage: TokenMulti InventorySize (r:2 w:2)
+	// Storage: Randomness PendingReveals (r:1 w:1)
+	fn reveal() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(17 as Weight))
+			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_loot_box(d: u32, ) -> Weight {
+		(26_000_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((4_100_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(d as Weight)))
+	}
+	fn open() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn reveal() -> Weight {
+		(98_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(17 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
+	}
+}
@@ -24,6 +24,12 @@ pub fn lookup_of_account<T: Config>(
 }
 
 fn create_non_fungible_token<T: Config>() {
+	<T as web3games_token_non_fungible::Config>::Currency::make_free_balance_be(
+		&account("alice", 0, 0),
+		<T as web3games_token_non_fungible::Config>::CreateTokenDeposit::get()
+			.saturating_mul(1_000u32.into()),
+	);
+
 	assert_ok!(TokenNonFungible::<T>::create_token(
 		RawOrigin::Signed(account("alice", 0, 0)).into(),
 		NonFungibleTokenIdOf::<T>::unique_saturated_from(1u128),
@@ -20,14 +20,14 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-	dispatch::DispatchResult,
+	dispatch::{DispatchError, DispatchResult},
 	ensure,
 	traits::{Currency, ExistenceRequirement::KeepAlive, Get},
 	PalletId,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Saturating, UniqueSaturatedFrom},
+	traits::{AccountIdConversion, One, Saturating, UniqueSaturatedFrom, Zero},
 	RuntimeDebug,
 };
 
@@ -143,6 +143,7 @@ pub mod pallet {
 		BidCreated(T::AccountId, Asset, OrderOf<T>),
 		BidCancelled(T::AccountId, Asset),
 		BidAccepted(T::AccountId, Asset, OrderOf<T>),
+		RoyaltyPaid(Asset, T::AccountId, BalanceOf<T>),
 	}
 
 	// Errors inform users that something went wrong.
@@ -160,6 +161,7 @@ pub mod pallet {
 		NotSetAdmin,
 		NeedHigherPrice,
 		AssetTypeError,
+		SoulboundAsset,
 	}
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
@@ -195,6 +197,7 @@ pub mod pallet {
 
 			ensure!(price >= BalanceOf::<T>::from(MIN_PRICE), Error::<T>::TooLittlePrice);
 			ensure!(duration >= T::BlockNumber::from(MIN_DURATION), Error::<T>::TooLittleDuration);
+			ensure!(!Self::is_soulbound(asset), Error::<T>::SoulboundAsset);
 
 			// check owner
 			Self::transfer_asset_to(who.clone(), asset, Self::account_id())?;
@@ -219,7 +222,7 @@ pub mod pallet {
 				Self::do_cancel_bid(asset, bid)?;
 			}
 
-			Self::transfer_asset_to(Self::account_id(), asset, who.clone())?;
+			Self::release_asset_to(asset, who.clone())?;
 
 			Orders::<T>::remove(asset);
 
@@ -260,6 +263,8 @@ pub mod pallet {
 			}
 
 			let to_seller = order.price.saturating_sub(service_fee);
+			let royalty = Self::pay_royalty(&who, asset, order.price, to_seller)?;
+			let to_seller = to_seller.saturating_sub(royalty);
 			<T as pallet::Config>::Currency::transfer(&who, &order.creater, to_seller, KeepAlive)?;
 
 			Self::transfer_asset_to(Self::account_id(), asset, who.clone())?;
@@ -333,8 +338,10 @@ pub mod pallet {
 			let service_fee = Self::calculate_service_fee(bid.price, fee_point);
 			let to_seller = bid.price.saturating_sub(service_fee);
 
-			// Transfer bid amount to creater
+			// Transfer royalty and bid amount to creater
 			let admin = Admin::<T>::get().ok_or(Error::<T>::NotSetAdmin)?;
+			let royalty = Self::pay_royalty(&admin, asset, bid.price, to_seller)?;
+			let to_seller = to_seller.saturating_sub(royalty);
 			<T as Config>::Currency::transfer(&admin, &order.creater, to_seller, KeepAlive)?;
 
 			Self::transfer_asset_to(Self::account_id(), asset, bid.creater.clone())?;
@@ -368,6 +375,62 @@ impl<T: Config> Pallet<T> {
 		value / base_point * point
 	}
 
+	/// Whether `asset` is bound to its holder and can not be sold.
+	pub fn is_soulbound(asset: Asset) -> bool {
+		match asset {
+			Asset::NonFungibleToken(group_id, token_id) =>
+				web3games_token_non_fungible::Pallet::<T>::is_soulbound(
+					<T as web3games_token_non_fungible::Config>::NonFungibleTokenId::unique_saturated_from(group_id),
+					<T as web3games_token_non_fungible::Config>::TokenId::unique_saturated_from(token_id),
+				),
+			Asset::MultiToken(group_id, token_id) => web3games_token_multi::Pallet::<T>::is_soulbound(
+				<T as web3games_token_multi::Config>::MultiTokenId::unique_saturated_from(group_id),
+				<T as web3games_token_multi::Config>::TokenId::unique_saturated_from(token_id),
+			),
+			_ => false,
+		}
+	}
+
+	/// The royalty receiver of `asset` and the amount owed to them for a sale at `price`.
+	pub fn royalty_of(asset: Asset, price: BalanceOf<T>) -> Option<(T::AccountId, BalanceOf<T>)> {
+		match asset {
+			Asset::NonFungibleToken(group_id, token_id) =>
+				web3games_token_non_fungible::Pallet::<T>::royalty_info(
+					<T as web3games_token_non_fungible::Config>::NonFungibleTokenId::unique_saturated_from(group_id),
+					<T as web3games_token_non_fungible::Config>::TokenId::unique_saturated_from(token_id),
+					price,
+				),
+			Asset::MultiToken(group_id, token_id) => web3games_token_multi::Pallet::<T>::royalty_info(
+				<T as web3games_token_multi::Config>::MultiTokenId::unique_saturated_from(group_id),
+				<T as web3games_token_multi::Config>::TokenId::unique_saturated_from(token_id),
+				price,
+			),
+			_ => None,
+		}
+	}
+
+	/// Pay the royalty of `asset` sold at `price` from `payer`, at most `max`.
+	fn pay_royalty(
+		payer: &T::AccountId,
+		asset: Asset,
+		price: BalanceOf<T>,
+		max: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let (receiver, royalty) = match Self::royalty_of(asset, price) {
+			Some((receiver, royalty)) => (receiver, royalty.min(max)),
+			None => return Ok(Zero::zero()),
+		};
+		if royalty.is_zero() {
+			return Ok(royalty)
+		}
+
+		<T as Config>::Currency::transfer(payer, &receiver, royalty, KeepAlive)?;
+
+		Self::deposit_event(Event::RoyaltyPaid(asset, receiver, royalty));
+
+		Ok(royalty)
+	}
+
 	fn transfer_asset_to(from: T::AccountId, asset: Asset, to: T::AccountId) -> DispatchResult {
 		match asset {
 			Asset::NonFungibleToken(group_id, token_id) => {
@@ -396,6 +459,33 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Return an escrowed `asset` to the seller, even if it became soulbound while listed.
+	fn release_asset_to(asset: Asset, to: T::AccountId) -> DispatchResult {
+		match asset {
+			Asset::NonFungibleToken(group_id, token_id) => {
+				web3games_token_non_fungible::Pallet::<T>::do_release_escrow(
+					<T as web3games_token_non_fungible::Config>::NonFungibleTokenId::unique_saturated_from(group_id),
+					&Self::account_id(),
+					&to,
+					<T as web3games_token_non_fungible::Config>::TokenId::unique_saturated_from(token_id),
+				)?;
+			},
+			Asset::MultiToken(group_id, token_id) => {
+				web3games_token_multi::Pallet::<T>::do_release_escrow(
+					<T as web3games_token_multi::Config>::MultiTokenId::unique_saturated_from(
+						group_id,
+					),
+					&Self::account_id(),
+					&to,
+					<T as web3games_token_multi::Config>::TokenId::unique_saturated_from(token_id),
+					One::one(),
+				)?;
+			},
+			_ => ensure!(false, Error::<T>::AssetTypeError),
+		}
+		Ok(())
+	}
+
 	fn do_cancel_bid(asset: Asset, bid: OrderOf<T>) -> DispatchResult {
 		let admin = Admin::<T>::get().ok_or(Error::<T>::NotSetAdmin)?;
 		<T as Config>::Currency::transfer(&admin, &bid.creater, bid.price, KeepAlive)?;
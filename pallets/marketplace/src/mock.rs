@@ -22,16 +22,17 @@ use frame_support::{
 	construct_runtime,
 	pallet_prelude::GenesisBuild,
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
 	PalletId,
 };
 use primitives::Balance;
-use sp_core::H256;
+use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Convert, IdentityLookup},
 	Percent,
 };
+use web3games_support::TokenIdConversion;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -51,6 +52,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		TokenFungible: web3games_token_fungible::{Pallet, Call, Storage, Event<T>},
 		TokenNonFungible: web3games_token_non_fungible::{Pallet, Call, Storage, Event<T>},
 		TokenMulti: web3games_token_multi::{Pallet, Call, Storage, Event<T>},
 		Marketplace: web3games_marketplace::{Pallet, Call, Storage,Config<T>, Event<T>},
@@ -107,14 +109,52 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+pub struct MockAddressMapping;
+impl Convert<H160, u64> for MockAddressMapping {
+	fn convert(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+pub struct MockTokenIdConversion;
+impl TokenIdConversion<u32> for MockTokenIdConversion {
+	fn try_from_address(address: H160) -> Option<u32> {
+		Some(address.to_low_u64_be() as u32)
+	}
+
+	fn into_address(id: u32) -> H160 {
+		H160::from_low_u64_be(id.into())
+	}
+}
+
 parameter_types! {
+	pub const TokenFungiblePalletId: PalletId = PalletId(*b"w3g/tfpi");
 	pub const TokenNonFungiblePalletId: PalletId = PalletId(*b"w3g/tnfp");
 	pub const TokenMultiPalletId: PalletId = PalletId(*b"w3g/tmpi");
 	pub const StringLimit: u32 = 50;
 	pub const CreateTokenDeposit: Balance = 500 * MILLICENTS;
+	pub const AttributeDepositBase: Balance = 10 * MILLICENTS;
+	pub const DepositPerByte: Balance = 1 * MILLICENTS;
 	pub const CreateCollectionDeposit: Balance = 500 * MILLICENTS;
 }
 
+impl web3games_token_fungible::Config for Test {
+	type Event = Event;
+	type PalletId = TokenFungiblePalletId;
+	type FungibleTokenId = u32;
+	type StringLimit = StringLimit;
+	type CreateTokenDeposit = CreateTokenDeposit;
+	type Currency = Balances;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type UnixTime = Timestamp;
+	type MaxVestingSchedules = ConstU32<8>;
+	type MinVestedTransfer = ConstU128<10>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type WeightInfo = ();
+}
+
 impl web3games_token_non_fungible::Config for Test {
 	type Event = Event;
 	type PalletId = TokenNonFungiblePalletId;
@@ -123,6 +163,21 @@ impl web3games_token_non_fungible::Config for Test {
 	type StringLimit = StringLimit;
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type Currency = Balances;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type MultiToken = TokenMulti;
+	type MaxNestingDepth = ConstU32<3>;
+	type MaxChildren = ConstU32<2>;
+	type Fungibles = TokenFungible;
+	type FungibleTokenIdConversion = MockTokenIdConversion;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type MaxMintPhases = ConstU32<3>;
+	type MaxBatchMint = ConstU32<5>;
+	type RemoveItemsLimit = ConstU32<1000>;
 	type WeightInfo = ();
 }
 
@@ -134,6 +189,14 @@ impl web3games_token_multi::Config for Test {
 	type StringLimit = StringLimit;
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type Currency = Balances;
+	type Fungibles = TokenFungible;
+	type FungibleTokenIdConversion = MockTokenIdConversion;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type MaxInventorySize = ConstU32<100>;
+	type InventoryDeposit = ConstU128<0>;
+	type RemoveItemsLimit = ConstU32<1000>;
 	type WeightInfo = ();
 }
 
@@ -2,7 +2,7 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 
 const ALICE: u64 = 1;
 const BOB: u64 = 2;
@@ -65,3 +65,64 @@ fn execute_order_should_work() {
 		assert_ok!(Marketplace::execute_order(Origin::signed(BOB), Asset::NonFungibleToken(1, 2),));
 	})
 }
+
+#[test]
+fn execute_order_should_pay_royalty() {
+	new_test_ext().execute_with(|| {
+		create_non_fungible_token();
+		assert_ok!(TokenNonFungible::set_royalty(Origin::signed(ALICE), 1, None, 3, 500));
+		assert_ok!(Marketplace::create_order(
+			Origin::signed(ALICE),
+			Asset::NonFungibleToken(1, 2),
+			100 * W3G,
+			100 * BLOCK
+		));
+
+		assert_ok!(Marketplace::execute_order(Origin::signed(BOB), Asset::NonFungibleToken(1, 2),));
+		assert_eq!(Balances::free_balance(3), 1005 * W3G);
+		System::assert_has_event(Event::Marketplace(crate::Event::RoyaltyPaid(
+			Asset::NonFungibleToken(1, 2),
+			3,
+			5 * W3G,
+		)));
+	})
+}
+
+#[test]
+fn create_order_should_reject_soulbound_asset() {
+	new_test_ext().execute_with(|| {
+		create_non_fungible_token();
+		assert_ok!(TokenNonFungible::set_soulbound(Origin::signed(ALICE), 1, Some(2), true));
+
+		assert_noop!(
+			Marketplace::create_order(
+				Origin::signed(ALICE),
+				Asset::NonFungibleToken(1, 2),
+				100 * W3G,
+				100 * BLOCK
+			),
+			Error::<Test>::SoulboundAsset
+		);
+	})
+}
+
+#[test]
+fn cancel_order_should_return_asset_made_soulbound() {
+	new_test_ext().execute_with(|| {
+		create_non_fungible_token();
+		assert_ok!(Marketplace::create_order(
+			Origin::signed(ALICE),
+			Asset::NonFungibleToken(1, 2),
+			100 * W3G,
+			100 * BLOCK
+		));
+		assert_ok!(TokenNonFungible::set_soulbound(Origin::signed(ALICE), 1, Some(2), true));
+
+		assert_noop!(
+			Marketplace::execute_order(Origin::signed(BOB), Asset::NonFungibleToken(1, 2)),
+			web3games_token_non_fungible::Error::<Test>::Soulbound
+		);
+		assert_ok!(Marketplace::cancel_order(Origin::signed(ALICE), Asset::NonFungibleToken(1, 2)));
+		assert_eq!(TokenNonFungible::owner_of(1, 2), Some(ALICE));
+	})
+}
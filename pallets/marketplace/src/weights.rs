@@ -78,9 +78,11 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: Martketplace Orders (r:0 w:1)
 	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
 	// Storage: TokenNonFungible OwnedTokens (r:0 w:2)
+	// Storage: TokenNonFungible SoulboundCollections (r:1 w:0)
+	// Storage: TokenNonFungible SoulboundTokens (r:1 w:0)
 	fn create_order() -> Weight {
 		(47_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
 	// Storage: Martketplace Orders (r:1 w:1)
@@ -99,16 +101,18 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: Martketplace Admin (r:1 w:0)
 	// Storage: Martketplace Point (r:1 w:0)
 	// Storage: Martketplace Bids (r:1 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: TokenNonFungible TokenRoyalties (r:1 w:0)
+	// Storage: TokenNonFungible Royalties (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
 	// Storage: TokenNonFungible Owners (r:1 w:1)
 	// Storage: TokenNonFungible Balances (r:2 w:2)
 	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:2)
 	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
 	// Storage: TokenNonFungible OwnedTokens (r:0 w:2)
 	fn execute_order() -> Weight {
-		(72_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
-			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+		(80_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
 	// Storage: Martketplace Orders (r:1 w:0)
 	// Storage: Martketplace Bids (r:1 w:1)
@@ -131,16 +135,18 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: Martketplace Bids (r:1 w:1)
 	// Storage: Martketplace Point (r:1 w:0)
 	// Storage: Martketplace Admin (r:1 w:0)
-	// Storage: System Account (r:2 w:2)
+	// Storage: TokenNonFungible TokenRoyalties (r:1 w:0)
+	// Storage: TokenNonFungible Royalties (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
 	// Storage: TokenNonFungible Owners (r:1 w:1)
 	// Storage: TokenNonFungible Balances (r:2 w:2)
 	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:2)
 	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
 	// Storage: TokenNonFungible OwnedTokens (r:0 w:2)
 	fn accept_bid() -> Weight {
-		(74_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+		(82_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 	}
 }
 
@@ -158,7 +164,7 @@ impl WeightInfo for () {
 	}
 	fn create_order() -> Weight {
 		(47_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
 	fn cancel_order() -> Weight {
@@ -167,9 +173,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
 	fn execute_order() -> Weight {
-		(72_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+		(80_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
 	}
 	fn place_bid() -> Weight {
 		(37_000_000 as Weight)
@@ -182,8 +188,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	fn accept_bid() -> Weight {
-		(74_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+		(82_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
 	}
 }
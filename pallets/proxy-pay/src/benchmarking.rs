@@ -39,21 +39,24 @@ fn create_token<T: Config>(who: T::AccountId) -> DispatchResult {
 		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(1u128),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::Pallet::<T>::create_token(
 		RawOrigin::Signed(who.clone()).into(),
 		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(2u128),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::Pallet::<T>::create_token(
 		RawOrigin::Signed(who.clone()).into(),
 		<T as TokenFungible::Config>::FungibleTokenId::unique_saturated_from(3u128),
 		b"TestToken".to_vec(),
 		b"TK".to_vec(),
-		18
+		18,
+		None
 	));
 	Ok(())
 }
@@ -132,6 +132,7 @@ impl web3games_exchange::Config for Test {
 	type Currency = Balances;
 	type Randomness = Randomness;
 	type W3GFungibleTokenId = W3GFungibleTokenId;
+	type DefaultSwapFee = ConstU16<30>;
 	type WeightInfo = ();
 }
 
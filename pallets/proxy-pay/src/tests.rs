@@ -40,21 +40,24 @@ fn create_tokens() {
 		TOKENA,
 		b"W3G1".to_vec(),
 		b"W3G1".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::create_token(
 		Origin::signed(ALICE),
 		TOKENB,
 		b"W3G2".to_vec(),
 		b"W3G2".to_vec(),
-		18
+		18,
+		None
 	));
 	assert_ok!(TokenFungible::create_token(
 		Origin::signed(ALICE),
 		TOKENC,
 		b"W3G2".to_vec(),
 		b"W3G2".to_vec(),
-		18
+		18,
+		None
 	));
 }
 fn set_balance() {
@@ -226,7 +229,8 @@ fn swap_exact_tokens_for_tokens_should_work() {
 		let (reserve_in, reserve_out) = Exchange::get_reserves(TOKENA, TOKENB).unwrap();
 		assert!(reserve_in == TOKENA_LIQUIDITY && reserve_out == TOKENB_LIQUIDITY);
 
-		let amount_out_1 = Exchange::get_amount_out(SWAP_VALUE, reserve_in, reserve_out).unwrap();
+		let amount_out_1 =
+			Exchange::get_amount_out(SWAP_VALUE, reserve_in, reserve_out, 30).unwrap();
 
 		let amount_in_with_fee: U256 = U256::from(SWAP_VALUE).saturating_mul(U256::from(997u128));
 		let numerator: U256 =
@@ -288,7 +292,7 @@ fn swap_tokens_for_exact_tokens_should_works() {
 		let (reserve_in, reserve_out) = Exchange::get_reserves(TOKENA, TOKENB).unwrap();
 		assert!(reserve_in == TOKENA_LIQUIDITY && reserve_out == TOKENB_LIQUIDITY);
 
-		let amount_in_1 = Exchange::get_amount_in(SWAP_VALUE, reserve_in, reserve_out).unwrap();
+		let amount_in_1 = Exchange::get_amount_in(SWAP_VALUE, reserve_in, reserve_out, 30).unwrap();
 
 		let numerator: U256 = U256::from(reserve_in)
 			.saturating_mul(U256::from(SWAP_VALUE))
@@ -0,0 +1,109 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	DispatchError,
+};
+use web3games_support::DelayedSeed;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The number of blocks between committing a draw and the block whose hash seeds it,
+		/// at least one.
+		#[pallet::constant]
+		type RevealDelay: Get<Self::BlockNumber>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		RevealTooEarly,
+		RandomnessNotReady,
+	}
+
+	/// The number of unrevealed draws committed to the hash of each block.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_reveals)]
+	pub type PendingReveals<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, u32, ValueQuery>;
+
+	/// The hash of each block with pending reveals, recorded at the start of the next block.
+	#[pallet::storage]
+	#[pallet::getter(fn seeds)]
+	pub type Seeds<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, T::Hash>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let parent = n.saturating_sub(One::one());
+			if PendingReveals::<T>::contains_key(parent) {
+				Seeds::<T>::insert(parent, frame_system::Pallet::<T>::parent_hash());
+				T::DbWeight::get().reads_writes(1, 1)
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+	}
+}
+
+impl<T: Config> DelayedSeed<T::BlockNumber, T::Hash> for Pallet<T> {
+	fn commit() -> T::BlockNumber {
+		let reveal_at = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::RevealDelay::get().max(One::one()));
+		PendingReveals::<T>::mutate(reveal_at, |pending| *pending = pending.saturating_add(1));
+		reveal_at
+	}
+
+	fn seed_of(reveal_at: T::BlockNumber) -> Result<T::Hash, DispatchError> {
+		ensure!(frame_system::Pallet::<T>::block_number() > reveal_at, Error::<T>::RevealTooEarly);
+		let seed = Seeds::<T>::get(reveal_at).ok_or(Error::<T>::RandomnessNotReady)?;
+		Ok(seed)
+	}
+
+	fn release(reveal_at: T::BlockNumber) {
+		let pending = PendingReveals::<T>::get(reveal_at).saturating_sub(1);
+		if pending.is_zero() {
+			PendingReveals::<T>::remove(reveal_at);
+			Seeds::<T>::remove(reveal_at);
+		} else {
+			PendingReveals::<T>::insert(reveal_at, pending);
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_seed(reveal_at: T::BlockNumber, seed: T::Hash) {
+		Seeds::<T>::insert(reveal_at, seed);
+	}
+}
@@ -0,0 +1,72 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, traits::Hooks};
+use sp_runtime::traits::Header as _;
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let header = System::finalize();
+		System::initialize(&(header.number + 1), &header.hash(), &Default::default());
+		Randomness::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn delayed_seed_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(<Randomness as DelayedSeed<_, _>>::commit(), 3);
+		assert_eq!(<Randomness as DelayedSeed<_, _>>::commit(), 3);
+		assert_eq!(Randomness::pending_reveals(3), 2);
+
+		run_to_block(3);
+		assert_noop!(<Randomness as DelayedSeed<_, _>>::seed_of(3), Error::<Test>::RevealTooEarly);
+
+		run_to_block(4);
+		let block_seed = System::parent_hash();
+		assert_eq!(Randomness::seeds(3), Some(block_seed));
+		assert_eq!(<Randomness as DelayedSeed<_, _>>::seed_of(3), Ok(block_seed));
+		// Blocks without pending reveals don't record a seed.
+		assert_noop!(
+			<Randomness as DelayedSeed<_, _>>::seed_of(2),
+			Error::<Test>::RandomnessNotReady
+		);
+
+		<Randomness as DelayedSeed<_, _>>::release(3);
+		assert_eq!(Randomness::seeds(3), Some(block_seed));
+		<Randomness as DelayedSeed<_, _>>::release(3);
+		assert_eq!(Randomness::pending_reveals(3), 0);
+		assert_eq!(Randomness::seeds(3), None);
+	})
+}
+
+#[test]
+fn draw_weighted_should_work() {
+	let seed = |roll: u32| {
+		let mut seed = [0u8; 32];
+		seed[..4].copy_from_slice(&roll.to_le_bytes());
+		seed
+	};
+	assert_eq!(web3games_support::draw_weighted(&seed(0), &[1, 2, 3]), 0);
+	assert_eq!(web3games_support::draw_weighted(&seed(1), &[1, 2, 3]), 1);
+	assert_eq!(web3games_support::draw_weighted(&seed(2), &[1, 2, 3]), 1);
+	assert_eq!(web3games_support::draw_weighted(&seed(3), &[1, 2, 3]), 2);
+	assert_eq!(web3games_support::draw_weighted(&seed(6), &[1, 2, 3]), 0);
+}
@@ -18,10 +18,44 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use primitives::TokenIndex;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{dispatch::DispatchResult, Parameter};
+use primitives::{Balance, TokenIndex};
+use scale_info::TypeInfo;
 use sp_core::H160;
+use sp_runtime::{DispatchError, RuntimeDebug};
 use sp_std::prelude::*;
 
+/// Roles that the owner of a token collection can grant to other accounts.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum TokenRole {
+	/// Can grant and revoke every role but `Admin`.
+	Admin,
+	/// Can mint tokens.
+	Minter,
+	/// Can burn tokens held by any account.
+	Burner,
+	/// Can freeze and thaw accounts and the whole collection.
+	Freezer,
+	/// Can set token attributes.
+	Metadata,
+}
+
+impl TryFrom<u8> for TokenRole {
+	type Error = ();
+
+	fn try_from(value: u8) -> Result<Self, Self::Error> {
+		match value {
+			0 => Ok(TokenRole::Admin),
+			1 => Ok(TokenRole::Minter),
+			2 => Ok(TokenRole::Burner),
+			3 => Ok(TokenRole::Freezer),
+			4 => Ok(TokenRole::Metadata),
+			_ => Err(()),
+		}
+	}
+}
+
 /// This trait ensure we can convert EVM Address to FungibleTokenId,
 /// NonFungibleTokenId, or MultiTokenId.
 /// We will require each mod to have this trait implemented
@@ -52,6 +86,11 @@ pub trait NonFungibleMetadata {
 	fn token_name(id: Self::NonFungibleTokenId) -> Vec<u8>;
 	fn token_symbol(id: Self::NonFungibleTokenId) -> Vec<u8>;
 	fn token_uri(id: Self::NonFungibleTokenId, token_id: Self::TokenId) -> Vec<u8>;
+	fn attribute(
+		id: Self::NonFungibleTokenId,
+		token_id: Self::TokenId,
+		key: &[u8],
+	) -> Option<Vec<u8>>;
 }
 
 pub trait NonFungibleEnumerable<AccountId> {
@@ -73,3 +112,56 @@ pub trait MultiMetadata {
 
 	fn uri(id: Self::MultiTokenId, token_id: Self::TokenId) -> Vec<u8>;
 }
+
+/// Multi token balances that other pallets can move for their holders.
+pub trait MultiTokenTransfer<AccountId> {
+	type MultiTokenId: Parameter + MaxEncodedLen + Copy;
+	type TokenId;
+
+	fn balance_of(id: Self::MultiTokenId, token_id: Self::TokenId, who: &AccountId) -> Balance;
+	fn transfer(
+		id: Self::MultiTokenId,
+		token_id: Self::TokenId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+/// A token nested in a non-fungible token.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum NestedToken<NonFungibleTokenId, MultiTokenId, TokenId> {
+	/// A non-fungible token of a collection.
+	NonFungible(NonFungibleTokenId, TokenId),
+	/// A balance of a multi token.
+	Multi(MultiTokenId, TokenId),
+}
+
+/// Seeds taken from the hash of a block that is not known yet when a draw is committed to it.
+pub trait DelayedSeed<BlockNumber, Hash> {
+	/// Commit a draw to the hash of a later block, returning its number.
+	fn commit() -> BlockNumber;
+	/// The hash of `reveal_at`, once it is recorded at the start of the next block.
+	fn seed_of(reveal_at: BlockNumber) -> Result<Hash, DispatchError>;
+	/// Count a draw committed to `reveal_at` as done, dropping its seed after the last.
+	fn release(reveal_at: BlockNumber);
+	/// Record the seed of `reveal_at` without running blocks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_seed(_reveal_at: BlockNumber, _seed: Hash) {}
+}
+
+/// The index of the entry `seed` draws from `weights`: the first four bytes of the seed as a
+/// little endian number, modulo the total weight, falls into the weights in order.
+///
+/// `weights` must not be empty and their total must fit in a `u32` and not be zero.
+pub fn draw_weighted(seed: &[u8], weights: &[u32]) -> usize {
+	let total_weight: u32 = weights.iter().sum();
+	let mut roll = u32::decode(&mut &seed[..]).unwrap_or_default() % total_weight;
+	for (index, weight) in weights.iter().enumerate() {
+		if roll < *weight {
+			return index
+		}
+		roll -= weight;
+	}
+	weights.len() - 1
+}
@@ -0,0 +1,114 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use primitives::{Balance, BlockNumber};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+pub use web3games_token_fungible_rpc_runtime_api::TokenFungibleRuntimeApi;
+
+pub struct TokenFungibleRpc<Client, Block> {
+	client: Arc<Client>,
+	_marker: PhantomData<Block>,
+}
+
+impl<Client, Block> TokenFungibleRpc<Client, Block> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+#[rpc(client, server)]
+pub trait TokenFungibleRpcApi<BlockHash, AccountId> {
+	#[method(name = "tokenFungible_holderCount")]
+	fn holder_count(&self, id: u128, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	#[method(name = "tokenFungible_holders")]
+	fn holders(
+		&self,
+		id: u128,
+		start_after: Option<AccountId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, Balance)>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+#[async_trait]
+impl<C, Block, AccountId> TokenFungibleRpcApiServer<<Block as BlockT>::Hash, AccountId>
+	for TokenFungibleRpc<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: TokenFungibleRuntimeApi<Block, AccountId, BlockNumber>,
+	AccountId: Codec + Send + Sync + 'static,
+{
+	fn holder_count(&self, id: u128, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.holder_count(&at, id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn holders(
+		&self,
+		id: u128,
+		start_after: Option<AccountId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, Balance)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.holders(&at, id, start_after, limit).map_err(runtime_error_into_rpc_err)
+	}
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Display) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"error in token fungible pallet",
+		Some(err.to_string()),
+	))
+	.into()
+}
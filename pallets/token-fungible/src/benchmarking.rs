@@ -9,55 +9,237 @@ use crate::Pallet as TokenFungible;
 use codec::alloc::string::ToString;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
+use sp_core::{crypto::KeyTypeId, ecdsa};
 
 const SEED: u32 = 0;
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"perm");
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+	let caller: T::AccountId = account(name, 0, SEED);
+	T::Currency::make_free_balance_be(
+		&caller,
+		T::CreateTokenDeposit::get().saturating_mul(1_000u32.into()),
+	);
+	caller
+}
+
+fn evm_address(public: &ecdsa::Public) -> H160 {
+	let message = [0u8; 32];
+	let signature =
+		sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, public, &message).expect("key exists; qed");
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message)
+		.expect("signature is valid; qed");
+	H160::from_slice(&keccak_256(&public)[12..])
+}
 
 benchmarks! {
 	create_token {
-		let alice: T::AccountId = account("alice", 0, SEED);
-	}: _(RawOrigin::Signed(alice), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18)
+		let alice = funded_account::<T>("alice");
+	}: _(RawOrigin::Signed(alice), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None)
 
 	mint {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
 		// let recipient: T::AccountId = account("recipient", 0, SEED);
-		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18);
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
 	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), 100_000_000_000_000u128)
 
 	approve {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
-		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18);
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
 		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
 	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, 100_000_000_000u128)
 
 	burn {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
-		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18);
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
 		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
 	}: _(RawOrigin::Signed(alice), 1u32.into(), 100_000_000_000_000u128)
 
 	transfer {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
-		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18);
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
 		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
 	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, 100_000_000_000u128)
 
 	transfer_from {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 		let charlie: T::AccountId = account("charlie", 0, SEED);
 
-		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18);
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
 		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
 		let _ = TokenFungible::<T>::approve(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), charlie.clone(), 100_000_000_000_000u128);
 	}: _(RawOrigin::Signed(charlie), 1u32.into(), alice, bob, 100_000_000_000u128)
+
+	permit {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+		let owner = evm_address(&public);
+		let spender = H160::repeat_byte(1);
+		let digest = TokenFungible::<T>::permit_digest(1u32.into(), owner, spender, 100_000_000_000u128, 0, U256::MAX);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &digest).unwrap().0;
+	}: _(RawOrigin::Signed(alice), 1u32.into(), owner, spender, 100_000_000_000u128, U256::MAX, signature)
+
+	burn_from {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
+		let _ = TokenFungible::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Burner, bob.clone());
+	}: _(RawOrigin::Signed(bob), 1u32.into(), alice, 100_000_000_000u128)
+
+	grant_role {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), TokenRole::Minter, bob)
+
+	revoke_role {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Minter, bob.clone());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), TokenRole::Minter, bob)
+
+	transfer_ownership {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	set_max_supply {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 100_000_000_000_000u128)
+
+	set_emission_schedule {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1_000_000_000u128, 100_000_000_000_000u128)
+
+	mint_emission {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::set_max_supply(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), 100_000_000_000_000u128);
+		let _ = TokenFungible::<T>::set_emission_schedule(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), 1_000_000_000u128, 100_000_000_000_000u128);
+		let _ = TokenFungible::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Minter, bob.clone());
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 10u32.into());
+	}: _(RawOrigin::Signed(bob), 1u32.into(), alice, 1_000_000_000u128)
+
+	vested_transfer {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, 100_000_000_000u128, 0u32.into(), 0u32.into(), 1u32.into(), 100)
+
+	vest {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
+		let _ = TokenFungible::<T>::vested_transfer(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), bob.clone(), 100_000_000_000u128, 0u32.into(), 0u32.into(), 1u32.into(), 100);
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 10u32.into());
+	}: _(RawOrigin::Signed(bob), 1u32.into())
+
+	merge_schedules {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
+		let _ = TokenFungible::<T>::vested_transfer(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), bob.clone(), 100_000_000_000u128, 0u32.into(), 0u32.into(), 1u32.into(), 100);
+		let _ = TokenFungible::<T>::vested_transfer(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), bob.clone(), 100_000_000_000u128, 0u32.into(), 0u32.into(), 1u32.into(), 100);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, 0, 1)
+
+	revoke_schedule {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
+		let _ = TokenFungible::<T>::vested_transfer(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), bob.clone(), 100_000_000_000u128, 0u32.into(), 0u32.into(), 1u32.into(), 100);
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 10u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, 0)
+
+	enable_checkpoints {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 100_000_000_000_000u128);
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	start_destroy {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	destroy_accounts {
+		let c in 0 .. T::RemoveItemsLimit::get();
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		for i in 0 .. c {
+			let _ = TokenFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), account("holder", i, SEED), 100_000_000_000_000u128);
+		}
+		let _ = TokenFungible::<T>::start_destroy(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	finish_destroy {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::start_destroy(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	freeze {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	thaw {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::freeze(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), bob.clone());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	freeze_token {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	thaw_token {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), "TestToken".to_string().into(), "TK".to_string().into(), 18, None);
+		let _ = TokenFungible::<T>::freeze_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
 }
 
 impl_benchmark_test_suite!(TokenFungible, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -0,0 +1,175 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementations of the `frame_support::traits::tokens::fungibles` traits, so the tokens of
+//! this pallet can be plugged into any pallet expecting a `fungibles` implementation.
+
+use super::*;
+use frame_support::{
+	dispatch::DispatchError,
+	traits::tokens::{
+		fungibles::{approvals, metadata, Inspect, Mutate, Transfer},
+		DepositConsequence, WithdrawConsequence,
+	},
+};
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+	type AssetId = T::FungibleTokenId;
+	type Balance = Balance;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		Tokens::<T>::get(asset).map(|token| token.total_supply).unwrap_or_default()
+	}
+
+	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
+		// Token balances have no existential deposit.
+		Balance::default()
+	}
+
+	fn balance(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		Balances::<T>::get(asset, who)
+	}
+
+	fn reducible_balance(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		_keep_alive: bool,
+	) -> Self::Balance {
+		Self::liquid_balance(asset, who)
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		mint: bool,
+	) -> DepositConsequence {
+		let token = match Tokens::<T>::get(asset) {
+			Some(token) => token,
+			None => return DepositConsequence::UnknownAsset,
+		};
+		if mint {
+			match token.total_supply.checked_add(amount) {
+				None => return DepositConsequence::Overflow,
+				Some(new_total_supply) =>
+					if MaxSupply::<T>::get(asset).map_or(false, |max| new_total_supply > max) {
+						return DepositConsequence::Overflow
+					},
+			}
+		}
+		if Balances::<T>::get(asset, who).checked_add(amount).is_none() {
+			return DepositConsequence::Overflow
+		}
+		DepositConsequence::Success
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if !Self::exists(asset) {
+			return WithdrawConsequence::UnknownAsset
+		}
+		if Balances::<T>::get(asset, who) < amount {
+			return WithdrawConsequence::NoFunds
+		}
+		if Self::liquid_balance(asset, who) < amount {
+			return WithdrawConsequence::Frozen
+		}
+		if Self::ensure_not_frozen(asset, who).is_err() {
+			return WithdrawConsequence::Frozen
+		}
+		WithdrawConsequence::Success
+	}
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+	fn mint_into(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		// Tokens with an emission schedule are only minted through `mint_emission`.
+		ensure!(!EmissionSchedules::<T>::contains_key(asset), Error::<T>::EmissionScheduled);
+		Self::internal_mint(asset, who, amount)
+	}
+
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::do_burn(asset, who, amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(
+		asset: Self::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		_keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::do_transfer(asset, source, dest, amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config> metadata::Inspect<T::AccountId> for Pallet<T> {
+	fn name(asset: &Self::AssetId) -> Vec<u8> {
+		Tokens::<T>::get(asset).map(|token| token.name.to_vec()).unwrap_or_default()
+	}
+
+	fn symbol(asset: &Self::AssetId) -> Vec<u8> {
+		Tokens::<T>::get(asset).map(|token| token.symbol.to_vec()).unwrap_or_default()
+	}
+
+	fn decimals(asset: &Self::AssetId) -> u8 {
+		Tokens::<T>::get(asset).map(|token| token.decimals).unwrap_or_default()
+	}
+}
+
+impl<T: Config> approvals::Inspect<T::AccountId> for Pallet<T> {
+	fn allowance(asset: Self::AssetId, owner: &T::AccountId, delegate: &T::AccountId) -> Balance {
+		Allowances::<T>::get(asset, (owner, delegate))
+	}
+}
+
+impl<T: Config> approvals::Mutate<T::AccountId> for Pallet<T> {
+	fn approve(
+		asset: Self::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::do_approve(asset, owner, delegate, amount)
+	}
+
+	fn transfer_from(
+		asset: Self::AssetId,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::do_transfer_from(asset, delegate.clone(), owner.clone(), dest.clone(), amount)
+	}
+}
@@ -20,23 +20,31 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
-	dispatch::DispatchResult,
+	dispatch::{DispatchError, DispatchResult},
 	ensure,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{Currency, Get, ReservableCurrency, UnixTime},
 	BoundedVec, PalletId,
 };
 use primitives::Balance;
 use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, TrailingZeroInput},
+	traits::{
+		AtLeast32BitUnsigned, Convert, One, Saturating, TrailingZeroInput, UniqueSaturatedInto,
+		Zero,
+	},
 	RuntimeDebug,
 };
 use sp_std::prelude::*;
-use web3games_support::FungibleMetadata;
+use web3games_support::{FungibleMetadata, TokenIdConversion, TokenRole};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod impl_fungibles;
+pub mod migrations;
+
 pub use pallet::*;
 
 pub mod weights;
@@ -51,6 +59,20 @@ mod tests;
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// EIP-712 type of the domain a `permit` is signed for.
+pub const EIP712_DOMAIN_TYPE: &[u8] =
+	b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+/// EIP-2612 type of a `permit` message.
+pub const PERMIT_TYPE: &[u8] =
+	b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)";
+
+/// Version of the signing domain.
+pub const PERMIT_VERSION: &[u8] = b"1";
+
+/// The most holders returned by one `holders` query.
+pub const MAX_HOLDERS_LIMIT: u32 = 100;
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct Token<AccountId, BoundedString> {
 	owner: AccountId,
@@ -60,14 +82,69 @@ pub struct Token<AccountId, BoundedString> {
 	total_supply: Balance,
 }
 
+/// Tokens a minter can draw each block, up to `ceiling` in total.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct EmissionSchedule<BlockNumber> {
+	start: BlockNumber,
+	per_block: Balance,
+	ceiling: Balance,
+	minted: Balance,
+}
+
+/// Tokens locked for an account and released in `period_count` equal steps of `period` blocks.
+///
+/// Nothing is released before `start + cliff`; a `period` of one block gives a linear schedule.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<AccountId, BlockNumber> {
+	creator: AccountId,
+	locked: Balance,
+	start: BlockNumber,
+	cliff: BlockNumber,
+	period: BlockNumber,
+	period_count: u32,
+}
+
+impl<AccountId, BlockNumber> VestingSchedule<AccountId, BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// The amount released at block `now`.
+	pub fn vested(&self, now: BlockNumber) -> Balance {
+		if now < self.start.saturating_add(self.cliff) {
+			return Balance::default()
+		}
+		let periods: u32 = (now.saturating_sub(self.start) / self.period).unique_saturated_into();
+		if periods >= self.period_count {
+			self.locked
+		} else {
+			(self.locked / Balance::from(self.period_count)).saturating_mul(periods.into())
+		}
+	}
+
+	/// The amount still locked at block `now`.
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		self.locked.saturating_sub(self.vested(now))
+	}
+
+	/// The block from which everything is released.
+	pub fn end(&self) -> BlockNumber {
+		self.start
+			.saturating_add(self.cliff.max(self.period.saturating_mul(self.period_count.into())))
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -94,6 +171,30 @@ pub mod pallet {
 
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 
+		/// The chain id `permit` signatures are bound to.
+		type ChainId: Get<u64>;
+
+		/// Mapping from an EVM address to the account it controls.
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Mapping from a token id to the address of its ERC20 precompile.
+		type TokenIdConversion: TokenIdConversion<Self::FungibleTokenId>;
+
+		/// Time provider used to check `permit` deadlines.
+		type UnixTime: UnixTime;
+
+		/// The maximum number of vesting schedules of an account per token.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// The minimum amount locked by a vested transfer.
+		#[pallet::constant]
+		type MinVestedTransfer: Get<Balance>;
+
+		/// The maximum number of storage items removed by a single `destroy_accounts`.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
 		/// runtime weights.
 		type WeightInfo: WeightInfo;
 	}
@@ -131,6 +232,149 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Roles granted on a token, keyed by `(role, account)`.
+	#[pallet::storage]
+	#[pallet::getter(fn has_role)]
+	pub(super) type Roles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::FungibleTokenId,
+		Blake2_128Concat,
+		(TokenRole, T::AccountId),
+		bool,
+		ValueQuery,
+	>;
+
+	/// The irreversible cap on the total supply of a token.
+	#[pallet::storage]
+	#[pallet::getter(fn max_supply)]
+	pub(super) type MaxSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, Balance>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn emission_schedule)]
+	pub(super) type EmissionSchedules<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, EmissionSchedule<T::BlockNumber>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn vesting)]
+	pub(super) type Vesting<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::FungibleTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingSchedule<T::AccountId, T::BlockNumber>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	/// The part of a balance that can not be transferred until it is vested.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_locked)]
+	pub(super) type VestingLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::FungibleTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		Balance,
+		ValueQuery,
+	>;
+
+	/// The block from which balances of a token are checkpointed.
+	#[pallet::storage]
+	#[pallet::getter(fn checkpoints_enabled_at)]
+	pub(super) type CheckpointsEnabled<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, T::BlockNumber>;
+
+	/// `(block, balance)` after each change of a checkpointed balance, oldest first.
+	#[pallet::storage]
+	pub(super) type BalanceCheckpoints<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::FungibleTokenId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		(T::BlockNumber, Balance),
+	>;
+
+	#[pallet::storage]
+	pub(super) type BalanceCheckpointCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::FungibleTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// `(block, total_supply)` after each change of a checkpointed supply, oldest first.
+	#[pallet::storage]
+	pub(super) type SupplyCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::FungibleTokenId,
+		Twox64Concat,
+		u32,
+		(T::BlockNumber, Balance),
+	>;
+
+	#[pallet::storage]
+	pub(super) type SupplyCheckpointCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, u32, ValueQuery>;
+
+	/// The next `permit` nonce of an owner.
+	#[pallet::storage]
+	#[pallet::getter(fn nonces)]
+	pub(super) type Nonces<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::FungibleTokenId,
+		Blake2_128Concat,
+		H160,
+		u64,
+		ValueQuery,
+	>;
+
+	/// Accounts that can not transfer a token.
+	#[pallet::storage]
+	#[pallet::getter(fn is_frozen)]
+	pub(super) type Frozen<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::FungibleTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Tokens that can not be transferred by any account.
+	#[pallet::storage]
+	#[pallet::getter(fn is_token_frozen)]
+	pub(super) type FrozenTokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, bool, ValueQuery>;
+
+	/// The creator of a token and the deposit reserved from them.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit)]
+	pub(super) type Deposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, (T::AccountId, BalanceOf<T>)>;
+
+	/// The number of accounts holding a non-zero balance of a token.
+	#[pallet::storage]
+	#[pallet::getter(fn holder_count)]
+	pub(super) type HolderCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, u32, ValueQuery>;
+
+	/// Tokens that are being destroyed and can no longer be used.
+	#[pallet::storage]
+	#[pallet::getter(fn is_destroying)]
+	pub(super) type Destroying<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FungibleTokenId, bool, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub genesis_tokens: Vec<(T::AccountId, u8, Vec<u8>, Vec<u8>, u8)>,
@@ -166,6 +410,23 @@ pub mod pallet {
 		Mint(T::FungibleTokenId, T::AccountId, T::AccountId, Balance),
 		Transfer(T::FungibleTokenId, T::AccountId, T::AccountId, Balance),
 		Approval(T::FungibleTokenId, T::AccountId, T::AccountId, Balance),
+		RoleGranted(T::FungibleTokenId, TokenRole, T::AccountId),
+		RoleRevoked(T::FungibleTokenId, TokenRole, T::AccountId),
+		OwnershipTransferred(T::FungibleTokenId, T::AccountId, T::AccountId),
+		MaxSupplySet(T::FungibleTokenId, Balance),
+		EmissionScheduleSet(T::FungibleTokenId, Balance, Balance),
+		VestingScheduleAdded(T::FungibleTokenId, T::AccountId, T::AccountId, Balance),
+		VestingUpdated(T::FungibleTokenId, T::AccountId, Balance),
+		VestingSchedulesMerged(T::FungibleTokenId, T::AccountId, u32, u32),
+		VestingScheduleRevoked(T::FungibleTokenId, T::AccountId, T::AccountId, Balance),
+		CheckpointsEnabled(T::FungibleTokenId, T::BlockNumber),
+		DestructionStarted(T::FungibleTokenId),
+		AccountsDestroyed(T::FungibleTokenId, u32),
+		Destroyed(T::FungibleTokenId),
+		AccountFrozen(T::FungibleTokenId, T::AccountId),
+		AccountThawed(T::FungibleTokenId, T::AccountId),
+		TokenFrozen(T::FungibleTokenId),
+		TokenThawed(T::FungibleTokenId),
 	}
 
 	#[pallet::error]
@@ -182,10 +443,36 @@ pub mod pallet {
 		InsufficientTokens,
 		ConfuseBehavior,
 		ApproveToCurrentOwner,
+		PermitExpired,
+		InvalidSignature,
+		MaxSupplyAlreadySet,
+		MaxSupplyTooLow,
+		MaxSupplyExceeded,
+		EmissionScheduleAlreadySet,
+		EmissionScheduled,
+		NoEmissionSchedule,
+		EmissionExceeded,
+		InvalidVestingSchedule,
+		VestedTransferTooLow,
+		TooManyVestingSchedules,
+		ScheduleIndexOutOfBounds,
+		NotScheduleCreator,
+		NotVesting,
+		LiquidityRestrictions,
+		CheckpointsAlreadyEnabled,
+		TokenDestroying,
+		NotDestroying,
+		AccountsRemaining,
+		AccountFrozen,
+		TokenFrozen,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -196,10 +483,18 @@ pub mod pallet {
 			name: Vec<u8>,
 			symbol: Vec<u8>,
 			decimals: u8,
+			max_supply: Option<Balance>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(!Self::exists(id.clone()), Error::<T>::InvalidId);
-			Self::do_create_token(&who, id, name, symbol, decimals)
+			let deposit = T::CreateTokenDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			Deposits::<T>::insert(id, (who.clone(), deposit));
+			Self::do_create_token(&who, id, name, symbol, decimals)?;
+			if let Some(max_supply) = max_supply {
+				Self::do_set_max_supply(id, &who, max_supply)?;
+			}
+			Ok(())
 		}
 
 		#[pallet::weight(T::WeightInfo::approve())]
@@ -257,6 +552,233 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			Self::do_burn(id, &who, amount)
 		}
+
+		/// Burn tokens held by `account`, requires the `Burner` role.
+		#[pallet::weight(T::WeightInfo::burn_from())]
+		pub fn burn_from(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			account: T::AccountId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_owner_or_role(id, &who, TokenRole::Burner)?;
+			Self::do_burn(id, &account, amount)
+		}
+
+		#[pallet::weight(T::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			role: TokenRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_grant_role(id, &who, role, &account)
+		}
+
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			role: TokenRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_revoke_role(id, &who, role, &account)
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_transfer_ownership(id, &who, &new_owner)
+		}
+
+		/// Cap the total supply of a token, the cap can not be changed once set.
+		#[pallet::weight(T::WeightInfo::set_max_supply())]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			max_supply: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_max_supply(id, &who, max_supply)
+		}
+
+		/// Release `per_block` tokens each block to minters, up to `ceiling` in total.
+		///
+		/// Once a schedule is set, tokens can only be minted through `mint_emission`.
+		#[pallet::weight(T::WeightInfo::set_emission_schedule())]
+		pub fn set_emission_schedule(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			per_block: Balance,
+			ceiling: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_emission_schedule(id, &who, per_block, ceiling)
+		}
+
+		/// Mint tokens released so far by the emission schedule.
+		#[pallet::weight(T::WeightInfo::mint_emission())]
+		pub fn mint_emission(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			account: T::AccountId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_mint_emission(id, &who, account, amount)
+		}
+
+		/// Transfer `locked` tokens to `target` under a new vesting schedule.
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			target: T::AccountId,
+			locked: Balance,
+			start: T::BlockNumber,
+			cliff: T::BlockNumber,
+			period: T::BlockNumber,
+			period_count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = VestingSchedule {
+				creator: who.clone(),
+				locked,
+				start,
+				cliff,
+				period,
+				period_count,
+			};
+			Self::do_vested_transfer(id, &who, &target, schedule)
+		}
+
+		/// Unlock the tokens of the sender that have vested so far.
+		#[pallet::weight(T::WeightInfo::vest())]
+		pub fn vest(origin: OriginFor<T>, id: T::FungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Vesting::<T>::contains_key(id, &who), Error::<T>::NotVesting);
+			Self::update_vesting(id, &who)
+		}
+
+		/// Merge two vesting schedules of `target` created by the sender into a linear one.
+		#[pallet::weight(T::WeightInfo::merge_schedules())]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			target: T::AccountId,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_merge_schedules(id, &who, &target, schedule1_index, schedule2_index)
+		}
+
+		/// Revoke a vesting schedule of `target` created by the sender.
+		///
+		/// The vested part stays with `target`, the rest is returned to the sender.
+		#[pallet::weight(T::WeightInfo::revoke_schedule())]
+		pub fn revoke_schedule(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			target: T::AccountId,
+			schedule_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_revoke_schedule(id, &who, &target, schedule_index)
+		}
+
+		/// Start recording the history of balances and total supply of a token.
+		///
+		/// Checkpoints can not be disabled once enabled.
+		#[pallet::weight(T::WeightInfo::enable_checkpoints())]
+		pub fn enable_checkpoints(origin: OriginFor<T>, id: T::FungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_enable_checkpoints(id, &who)
+		}
+
+		/// Set the allowance of `spender` over the tokens of `owner` from an EIP-2612 signature.
+		///
+		/// Anyone can submit the signature, so a relayer can pay the fees for the owner.
+		#[pallet::weight(T::WeightInfo::permit())]
+		pub fn permit(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			owner: H160,
+			spender: H160,
+			amount: Balance,
+			deadline: U256,
+			signature: [u8; 65],
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(Self::exists(id), Error::<T>::InvalidId);
+			Self::do_permit(id, owner, spender, amount, deadline, signature)
+		}
+
+		/// Start destroying a token. Only the owner can do this.
+		///
+		/// Once started, the token can no longer be minted, transferred or approved.
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub fn start_destroy(origin: OriginFor<T>, id: T::FungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_start_destroy(id, &who)
+		}
+
+		/// Remove up to `RemoveItemsLimit` account entries of a token being destroyed.
+		///
+		/// Anyone can call this until all entries are removed.
+		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		pub fn destroy_accounts(origin: OriginFor<T>, id: T::FungibleTokenId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_destroy_accounts(id)
+		}
+
+		/// Remove a token whose accounts are all destroyed and refund the creation deposit.
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub fn finish_destroy(origin: OriginFor<T>, id: T::FungibleTokenId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_finish_destroy(id)
+		}
+
+		/// Stop `account` from transferring the token. Needs the `Freezer` role.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_freeze(id, &who, &account)
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(
+			origin: OriginFor<T>,
+			id: T::FungibleTokenId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_thaw(id, &who, &account)
+		}
+
+		/// Stop every account from transferring the token. Needs the `Freezer` role.
+		#[pallet::weight(T::WeightInfo::freeze_token())]
+		pub fn freeze_token(origin: OriginFor<T>, id: T::FungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_freeze_token(id, &who)
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw_token())]
+		pub fn thaw_token(origin: OriginFor<T>, id: T::FungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_thaw_token(id, &who)
+		}
 	}
 }
 
@@ -306,6 +828,7 @@ impl<T: Config> Pallet<T> {
 		spender: &T::AccountId,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_live(id)?;
 		ensure!(spender != who, Error::<T>::ApproveToCurrentOwner);
 
 		ensure!(
@@ -368,6 +891,8 @@ impl<T: Config> Pallet<T> {
 		recipient: &T::AccountId,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_not_frozen(id, sender)?;
+		Self::ensure_liquid(id, sender, amount)?;
 		Self::decrease_balance(id, sender, amount)?;
 		Self::increase_balance(id, recipient, amount)?;
 
@@ -383,6 +908,7 @@ impl<T: Config> Pallet<T> {
 		amount: Balance,
 	) -> DispatchResult {
 		Self::maybe_check_permission(id, &who)?;
+		ensure!(!EmissionSchedules::<T>::contains_key(id), Error::<T>::EmissionScheduled);
 
 		Self::internal_mint(id, &account, amount)?;
 
@@ -397,10 +923,16 @@ impl<T: Config> Pallet<T> {
 		Tokens::<T>::try_mutate_exists(id, |maybe_token| -> DispatchResult {
 			let token = maybe_token.as_mut().ok_or(Error::<T>::Unknown)?;
 
+			let new_total_supply =
+				token.total_supply.checked_add(amount).ok_or(Error::<T>::NumOverflow)?;
+			if let Some(max_supply) = MaxSupply::<T>::get(id) {
+				ensure!(new_total_supply <= max_supply, Error::<T>::MaxSupplyExceeded);
+			}
+
 			Self::increase_balance(id, account, amount)?;
 
-			let new_total_supply = token.total_supply.saturating_add(amount);
 			token.total_supply = new_total_supply;
+			Self::checkpoint_supply(id, new_total_supply);
 			Ok(())
 		})?;
 
@@ -409,11 +941,91 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub fn do_set_max_supply(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		max_supply: Balance,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+		ensure!(!MaxSupply::<T>::contains_key(id), Error::<T>::MaxSupplyAlreadySet);
+		ensure!(max_supply >= token.total_supply, Error::<T>::MaxSupplyTooLow);
+
+		MaxSupply::<T>::insert(id, max_supply);
+
+		Self::deposit_event(Event::MaxSupplySet(id, max_supply));
+
+		Ok(())
+	}
+
+	pub fn do_set_emission_schedule(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		per_block: Balance,
+		ceiling: Balance,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+		ensure!(!EmissionSchedules::<T>::contains_key(id), Error::<T>::EmissionScheduleAlreadySet);
+
+		let schedule = EmissionSchedule {
+			start: frame_system::Pallet::<T>::block_number(),
+			per_block,
+			ceiling,
+			minted: Balance::default(),
+		};
+		EmissionSchedules::<T>::insert(id, schedule);
+
+		Self::deposit_event(Event::EmissionScheduleSet(id, per_block, ceiling));
+
+		Ok(())
+	}
+
+	pub fn do_mint_emission(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		account: T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::maybe_check_permission(id, who)?;
+
+		EmissionSchedules::<T>::try_mutate(id, |maybe_schedule| -> DispatchResult {
+			let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::NoEmissionSchedule)?;
+
+			let minted = schedule.minted.checked_add(amount).ok_or(Error::<T>::NumOverflow)?;
+			ensure!(minted <= Self::released(schedule), Error::<T>::EmissionExceeded);
+
+			schedule.minted = minted;
+			Ok(())
+		})?;
+
+		Self::internal_mint(id, &account, amount)
+	}
+
+	/// The amount of tokens an emission schedule has released so far.
+	fn released(schedule: &EmissionSchedule<T::BlockNumber>) -> Balance {
+		let elapsed: Balance = frame_system::Pallet::<T>::block_number()
+			.saturating_sub(schedule.start)
+			.unique_saturated_into();
+		schedule.per_block.saturating_mul(elapsed).min(schedule.ceiling)
+	}
+
+	/// The amount of tokens minters can still draw from the emission schedule.
+	pub fn available_emission(id: T::FungibleTokenId) -> Balance {
+		EmissionSchedules::<T>::get(id)
+			.map(|schedule| Self::released(&schedule).saturating_sub(schedule.minted))
+			.unwrap_or_default()
+	}
+
 	pub fn do_burn(
 		id: T::FungibleTokenId,
 		account: &T::AccountId,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_liquid(id, account, amount)?;
+
 		Tokens::<T>::try_mutate_exists(id, |maybe_token| -> DispatchResult {
 			let token = maybe_token.as_mut().ok_or(Error::<T>::Unknown)?;
 
@@ -421,6 +1033,7 @@ impl<T: Config> Pallet<T> {
 
 			let new_total_supply = token.total_supply.saturating_sub(amount);
 			token.total_supply = new_total_supply;
+			Self::checkpoint_supply(id, new_total_supply);
 			Ok(())
 		})?;
 
@@ -434,8 +1047,15 @@ impl<T: Config> Pallet<T> {
 		to: &T::AccountId,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_live(id)?;
+
 		Balances::<T>::try_mutate(id, to, |balance| -> DispatchResult {
+			let old_balance = *balance;
 			*balance = balance.checked_add(amount).ok_or(Error::<T>::NumOverflow)?;
+			if old_balance.is_zero() && !balance.is_zero() {
+				HolderCount::<T>::mutate(id, |count| *count = count.saturating_add(1));
+			}
+			Self::checkpoint_balance(id, to, old_balance, *balance);
 			Ok(())
 		})?;
 
@@ -447,20 +1067,611 @@ impl<T: Config> Pallet<T> {
 		from: &T::AccountId,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_live(id)?;
+
 		Balances::<T>::try_mutate(id, from, |balance| -> DispatchResult {
+			let old_balance = *balance;
 			*balance = balance.checked_sub(amount).ok_or(Error::<T>::NumOverflow)?;
+			if !old_balance.is_zero() && balance.is_zero() {
+				HolderCount::<T>::mutate(id, |count| *count = count.saturating_sub(1));
+			}
+			Self::checkpoint_balance(id, from, old_balance, *balance);
 			Ok(())
 		})?;
 
 		Ok(())
 	}
 
-	fn maybe_check_permission(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
-		let token = Tokens::<T>::get(id);
-		ensure!(*who == token.unwrap().owner, Error::<T>::NoPermission);
+	pub fn do_enable_checkpoints(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+		ensure!(!CheckpointsEnabled::<T>::contains_key(id), Error::<T>::CheckpointsAlreadyEnabled);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		CheckpointsEnabled::<T>::insert(id, now);
+		SupplyCheckpoints::<T>::insert(id, 0, (now, token.total_supply));
+		SupplyCheckpointCount::<T>::insert(id, 1);
+
+		Self::deposit_event(Event::CheckpointsEnabled(id, now));
+
+		Ok(())
+	}
+
+	fn checkpoint_balance(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		old_balance: Balance,
+		new_balance: Balance,
+	) {
+		let enabled_at = match CheckpointsEnabled::<T>::get(id) {
+			Some(enabled_at) => enabled_at,
+			None => return,
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut count = BalanceCheckpointCount::<T>::get(id, who);
+
+		// Balances are not copied when checkpoints are enabled, so the first change of an
+		// account records what it held until then.
+		if count == 0 && old_balance > 0 && enabled_at < now {
+			BalanceCheckpoints::<T>::insert((id, who, 0), (enabled_at, old_balance));
+			count = 1;
+		}
+		match count
+			.checked_sub(1)
+			.and_then(|last| BalanceCheckpoints::<T>::get((id, who, last)))
+		{
+			Some((block, _)) if block == now => {
+				BalanceCheckpoints::<T>::insert((id, who, count - 1), (now, new_balance));
+			},
+			_ => {
+				BalanceCheckpoints::<T>::insert((id, who, count), (now, new_balance));
+				count = count.saturating_add(1);
+			},
+		}
+		BalanceCheckpointCount::<T>::insert(id, who, count);
+	}
+
+	fn checkpoint_supply(id: T::FungibleTokenId, total_supply: Balance) {
+		if !CheckpointsEnabled::<T>::contains_key(id) {
+			return
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let count = SupplyCheckpointCount::<T>::get(id);
+
+		match count.checked_sub(1).and_then(|last| SupplyCheckpoints::<T>::get(id, last)) {
+			Some((block, _)) if block == now => {
+				SupplyCheckpoints::<T>::insert(id, count - 1, (now, total_supply));
+			},
+			_ => {
+				SupplyCheckpoints::<T>::insert(id, count, (now, total_supply));
+				SupplyCheckpointCount::<T>::insert(id, count.saturating_add(1));
+			},
+		}
+	}
+
+	/// Binary search for the last of `count` checkpoints recorded at or before `block`.
+	fn find_checkpoint(
+		count: u32,
+		block: T::BlockNumber,
+		checkpoint: impl Fn(u32) -> Option<(T::BlockNumber, Balance)>,
+	) -> Option<Balance> {
+		let (mut low, mut high) = (0u32, count);
+		while low < high {
+			let mid = low + (high - low) / 2;
+			match checkpoint(mid) {
+				Some((at, _)) if at > block => high = mid,
+				_ => low = mid + 1,
+			}
+		}
+		low.checked_sub(1).and_then(checkpoint).map(|(_, balance)| balance)
+	}
+
+	/// The balance of `who` at the end of `block`, if the token was checkpointed by then.
+	pub fn balance_of_at(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		block: T::BlockNumber,
+	) -> Option<Balance> {
+		let enabled_at = CheckpointsEnabled::<T>::get(id)?;
+		if block < enabled_at || block > frame_system::Pallet::<T>::block_number() {
+			return None
+		}
+
+		let count = BalanceCheckpointCount::<T>::get(id, who);
+		if count == 0 {
+			// Untouched since checkpoints were enabled.
+			return Some(Balances::<T>::get(id, who))
+		}
+		Some(
+			Self::find_checkpoint(count, block, |index| {
+				BalanceCheckpoints::<T>::get((id, who, index))
+			})
+			.unwrap_or_default(),
+		)
+	}
+
+	/// The total supply of a token at the end of `block`, if it was checkpointed by then.
+	pub fn total_supply_at(id: T::FungibleTokenId, block: T::BlockNumber) -> Option<Balance> {
+		let enabled_at = CheckpointsEnabled::<T>::get(id)?;
+		if block < enabled_at || block > frame_system::Pallet::<T>::block_number() {
+			return None
+		}
+
+		let count = SupplyCheckpointCount::<T>::get(id);
+		Self::find_checkpoint(count, block, |index| SupplyCheckpoints::<T>::get(id, index))
+	}
+
+	/// Up to `limit` accounts holding a non-zero balance of a token, starting after `start_after`.
+	///
+	/// `limit` is capped at `MAX_HOLDERS_LIMIT`.
+	pub fn holders(
+		id: T::FungibleTokenId,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, Balance)> {
+		let iter = match start_after {
+			Some(who) =>
+				Balances::<T>::iter_prefix_from(id, Balances::<T>::hashed_key_for(id, who)),
+			None => Balances::<T>::iter_prefix(id),
+		};
+		iter.filter(|(_, balance)| !balance.is_zero())
+			.take(limit.min(MAX_HOLDERS_LIMIT) as usize)
+			.collect()
+	}
+
+	/// The part of the balance of `who` that is not locked by vesting.
+	pub fn liquid_balance(id: T::FungibleTokenId, who: &T::AccountId) -> Balance {
+		Balances::<T>::get(id, who).saturating_sub(VestingLocks::<T>::get(id, who))
+	}
+
+	fn ensure_liquid(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		ensure!(Self::liquid_balance(id, who) >= amount, Error::<T>::LiquidityRestrictions);
+		Ok(())
+	}
+
+	pub fn do_vested_transfer(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		target: &T::AccountId,
+		schedule: VestingSchedule<T::AccountId, T::BlockNumber>,
+	) -> DispatchResult {
+		ensure!(Self::exists(id), Error::<T>::InvalidId);
+		ensure!(
+			schedule.locked > 0 && !schedule.period.is_zero() && schedule.period_count > 0,
+			Error::<T>::InvalidVestingSchedule
+		);
+		ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::VestedTransferTooLow);
+		ensure!(who != target, Error::<T>::ConfuseBehavior);
+
+		let locked = schedule.locked;
+		Self::internal_transfer(id, who, target, locked)?;
+		Vesting::<T>::try_mutate(id, target, |schedules| -> DispatchResult {
+			schedules.try_push(schedule).map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+			Ok(())
+		})?;
+		Self::update_vesting(id, target)?;
+
+		Self::deposit_event(Event::VestingScheduleAdded(id, who.clone(), target.clone(), locked));
+
+		Ok(())
+	}
+
+	pub fn do_merge_schedules(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		target: &T::AccountId,
+		schedule1_index: u32,
+		schedule2_index: u32,
+	) -> DispatchResult {
+		ensure!(schedule1_index != schedule2_index, Error::<T>::ScheduleIndexOutOfBounds);
+		let now = frame_system::Pallet::<T>::block_number();
+
+		Vesting::<T>::try_mutate(id, target, |schedules| -> DispatchResult {
+			let schedule1 = schedules
+				.get(schedule1_index as usize)
+				.cloned()
+				.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+			let schedule2 = schedules
+				.get(schedule2_index as usize)
+				.cloned()
+				.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+			ensure!(
+				schedule1.creator == *who && schedule2.creator == *who,
+				Error::<T>::NotScheduleCreator
+			);
+
+			// Remove the higher index first so the lower one stays valid.
+			schedules.remove(schedule1_index.max(schedule2_index) as usize);
+			schedules.remove(schedule1_index.min(schedule2_index) as usize);
+
+			let locked = schedule1.locked_at(now).saturating_add(schedule2.locked_at(now));
+			if locked > 0 {
+				let start = now.max(schedule1.start).max(schedule2.start);
+				let end = schedule1.end().max(schedule2.end());
+				let period_count: u32 = end.saturating_sub(start).unique_saturated_into();
+				let schedule = VestingSchedule {
+					creator: who.clone(),
+					locked,
+					start,
+					cliff: Zero::zero(),
+					period: One::one(),
+					period_count: period_count.max(1),
+				};
+				schedules.try_push(schedule).map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+			}
+			Ok(())
+		})?;
+		Self::update_vesting(id, target)?;
+
+		Self::deposit_event(Event::VestingSchedulesMerged(
+			id,
+			target.clone(),
+			schedule1_index,
+			schedule2_index,
+		));
+
+		Ok(())
+	}
+
+	pub fn do_revoke_schedule(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		target: &T::AccountId,
+		schedule_index: u32,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let schedule = Vesting::<T>::try_mutate(
+			id,
+			target,
+			|schedules| -> Result<VestingSchedule<T::AccountId, T::BlockNumber>, DispatchError> {
+				let schedule = schedules
+					.get(schedule_index as usize)
+					.cloned()
+					.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				ensure!(schedule.creator == *who, Error::<T>::NotScheduleCreator);
+
+				schedules.remove(schedule_index as usize);
+				Ok(schedule)
+			},
+		)?;
+		Self::update_vesting(id, target)?;
+
+		let returned = schedule.locked_at(now);
+		if returned > 0 {
+			Self::internal_transfer(id, target, who, returned)?;
+		}
+
+		Self::deposit_event(Event::VestingScheduleRevoked(
+			id,
+			target.clone(),
+			who.clone(),
+			returned,
+		));
+
+		Ok(())
+	}
+
+	/// Drop the finished schedules of `who` and lock what is still vesting.
+	fn update_vesting(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_live(id)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let mut schedules = Vesting::<T>::get(id, who);
+		schedules.retain(|schedule| schedule.locked_at(now) > 0);
+		let locked = schedules
+			.iter()
+			.fold(Balance::default(), |acc, schedule| acc.saturating_add(schedule.locked_at(now)));
+
+		if schedules.is_empty() {
+			Vesting::<T>::remove(id, who);
+		} else {
+			Vesting::<T>::insert(id, who, schedules);
+		}
+		if locked > 0 {
+			VestingLocks::<T>::insert(id, who, locked);
+		} else {
+			VestingLocks::<T>::remove(id, who);
+		}
+
+		Self::deposit_event(Event::VestingUpdated(id, who.clone(), locked));
 
 		Ok(())
 	}
+
+	pub fn do_permit(
+		id: T::FungibleTokenId,
+		owner: H160,
+		spender: H160,
+		amount: Balance,
+		deadline: U256,
+		signature: [u8; 65],
+	) -> DispatchResult {
+		ensure!(U256::from(T::UnixTime::now().as_secs()) <= deadline, Error::<T>::PermitExpired);
+		Self::ensure_live(id)?;
+
+		let nonce = Nonces::<T>::get(id, owner);
+		let digest = Self::permit_digest(id, owner, spender, amount, nonce, deadline);
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+			.map(|public| H160::from_slice(&keccak_256(&public)[12..]))
+			.map_err(|_| Error::<T>::InvalidSignature)?;
+		ensure!(signer == owner, Error::<T>::InvalidSignature);
+
+		let owner_account = T::AddressMapping::convert(owner);
+		let spender_account = T::AddressMapping::convert(spender);
+		ensure!(spender_account != owner_account, Error::<T>::ApproveToCurrentOwner);
+
+		Nonces::<T>::insert(id, owner, nonce.saturating_add(1));
+		Allowances::<T>::insert(id, (&owner_account, &spender_account), amount);
+
+		Self::deposit_event(Event::Approval(id, owner_account, spender_account, amount));
+
+		Ok(())
+	}
+
+	/// The EIP-712 domain separator of a token.
+	pub fn domain_separator(id: T::FungibleTokenId) -> H256 {
+		let mut data = Vec::with_capacity(5 * 32);
+		data.extend_from_slice(&keccak_256(EIP712_DOMAIN_TYPE));
+		data.extend_from_slice(&keccak_256(&Self::token_name(id)));
+		data.extend_from_slice(&keccak_256(PERMIT_VERSION));
+		data.extend_from_slice(&Self::abi_encode_uint(T::ChainId::get().into()));
+		data.extend_from_slice(&Self::abi_encode_address(T::TokenIdConversion::into_address(id)));
+		H256::from(keccak_256(&data))
+	}
+
+	/// The EIP-712 digest an owner signs to `permit` a spender.
+	pub fn permit_digest(
+		id: T::FungibleTokenId,
+		owner: H160,
+		spender: H160,
+		amount: Balance,
+		nonce: u64,
+		deadline: U256,
+	) -> [u8; 32] {
+		let mut data = Vec::with_capacity(6 * 32);
+		data.extend_from_slice(&keccak_256(PERMIT_TYPE));
+		data.extend_from_slice(&Self::abi_encode_address(owner));
+		data.extend_from_slice(&Self::abi_encode_address(spender));
+		data.extend_from_slice(&Self::abi_encode_uint(amount.into()));
+		data.extend_from_slice(&Self::abi_encode_uint(nonce.into()));
+		data.extend_from_slice(&Self::abi_encode_uint(deadline));
+		let struct_hash = keccak_256(&data);
+
+		let mut message = Vec::with_capacity(2 + 2 * 32);
+		message.extend_from_slice(b"\x19\x01");
+		message.extend_from_slice(Self::domain_separator(id).as_bytes());
+		message.extend_from_slice(&struct_hash);
+		keccak_256(&message)
+	}
+
+	fn abi_encode_uint(value: U256) -> [u8; 32] {
+		let mut data = [0u8; 32];
+		value.to_big_endian(&mut data);
+		data
+	}
+
+	fn abi_encode_address(address: H160) -> [u8; 32] {
+		let mut data = [0u8; 32];
+		data[12..32].copy_from_slice(address.as_bytes());
+		data
+	}
+
+	pub fn do_grant_role(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_role_admin(id, who, role)?;
+		Self::ensure_live(id)?;
+
+		Roles::<T>::insert(id, (role, account), true);
+
+		Self::deposit_event(Event::RoleGranted(id, role, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_revoke_role(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_role_admin(id, who, role)?;
+
+		Roles::<T>::remove(id, (role, account));
+
+		Self::deposit_event(Event::RoleRevoked(id, role, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_transfer_ownership(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		new_owner: &T::AccountId,
+	) -> DispatchResult {
+		Tokens::<T>::try_mutate(id, |maybe_token| -> DispatchResult {
+			let token = maybe_token.as_mut().ok_or(Error::<T>::InvalidId)?;
+			ensure!(*who == token.owner, Error::<T>::NotOwner);
+			Self::ensure_live(id)?;
+
+			token.owner = new_owner.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::OwnershipTransferred(id, who.clone(), new_owner.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_start_destroy(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+
+		Destroying::<T>::insert(id, true);
+
+		Self::deposit_event(Event::DestructionStarted(id));
+
+		Ok(())
+	}
+
+	pub fn do_destroy_accounts(id: T::FungibleTokenId) -> DispatchResult {
+		ensure!(Self::is_destroying(id), Error::<T>::NotDestroying);
+
+		let limit = T::RemoveItemsLimit::get() as usize;
+		let mut removed = 0;
+		removed += Balances::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Allowances::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Roles::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Nonces::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Vesting::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += VestingLocks::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += BalanceCheckpoints::<T>::drain_prefix((id,)).take(limit - removed).count();
+		removed += BalanceCheckpointCount::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += SupplyCheckpoints::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Frozen::<T>::drain_prefix(id).take(limit - removed).count();
+
+		Self::deposit_event(Event::AccountsDestroyed(id, removed as u32));
+
+		Ok(())
+	}
+
+	pub fn do_finish_destroy(id: T::FungibleTokenId) -> DispatchResult {
+		ensure!(Self::is_destroying(id), Error::<T>::NotDestroying);
+		ensure!(
+			Balances::<T>::iter_prefix(id).next().is_none() &&
+				Allowances::<T>::iter_prefix(id).next().is_none() &&
+				Roles::<T>::iter_prefix(id).next().is_none() &&
+				Nonces::<T>::iter_prefix(id).next().is_none() &&
+				Vesting::<T>::iter_prefix(id).next().is_none() &&
+				VestingLocks::<T>::iter_prefix(id).next().is_none() &&
+				BalanceCheckpoints::<T>::iter_prefix((id,)).next().is_none() &&
+				BalanceCheckpointCount::<T>::iter_prefix(id).next().is_none() &&
+				SupplyCheckpoints::<T>::iter_prefix(id).next().is_none() &&
+				Frozen::<T>::iter_prefix(id).next().is_none(),
+			Error::<T>::AccountsRemaining
+		);
+
+		Tokens::<T>::remove(id);
+		MaxSupply::<T>::remove(id);
+		EmissionSchedules::<T>::remove(id);
+		CheckpointsEnabled::<T>::remove(id);
+		SupplyCheckpointCount::<T>::remove(id);
+		FrozenTokens::<T>::remove(id);
+		HolderCount::<T>::remove(id);
+		Destroying::<T>::remove(id);
+		if let Some((depositor, deposit)) = Deposits::<T>::take(id) {
+			T::Currency::unreserve(&depositor, deposit);
+		}
+
+		Self::deposit_event(Event::Destroyed(id));
+
+		Ok(())
+	}
+
+	pub fn do_freeze(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+		Self::ensure_live(id)?;
+
+		Frozen::<T>::insert(id, account, true);
+
+		Self::deposit_event(Event::AccountFrozen(id, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_thaw(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+
+		Frozen::<T>::remove(id, account);
+
+		Self::deposit_event(Event::AccountThawed(id, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_freeze_token(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+		Self::ensure_live(id)?;
+
+		FrozenTokens::<T>::insert(id, true);
+
+		Self::deposit_event(Event::TokenFrozen(id));
+
+		Ok(())
+	}
+
+	pub fn do_thaw_token(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+
+		FrozenTokens::<T>::remove(id);
+
+		Self::deposit_event(Event::TokenThawed(id));
+
+		Ok(())
+	}
+
+	fn ensure_not_frozen(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		ensure!(!Self::is_token_frozen(id), Error::<T>::TokenFrozen);
+		ensure!(!Self::is_frozen(id, who), Error::<T>::AccountFrozen);
+
+		Ok(())
+	}
+
+	fn ensure_live(id: T::FungibleTokenId) -> DispatchResult {
+		ensure!(!Self::is_destroying(id), Error::<T>::TokenDestroying);
+
+		Ok(())
+	}
+
+	/// The owner manages every role, admins manage every role but `Admin`.
+	fn ensure_role_admin(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(
+			*who == token.owner ||
+				(role != TokenRole::Admin && Self::has_role(id, (TokenRole::Admin, who))),
+			Error::<T>::NoPermission
+		);
+
+		Ok(())
+	}
+
+	fn ensure_owner_or_role(
+		id: T::FungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner || Self::has_role(id, (role, who)), Error::<T>::NoPermission);
+
+		Ok(())
+	}
+
+	fn maybe_check_permission(id: T::FungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Minter)
+	}
 }
 
 impl<T: Config> FungibleMetadata for Pallet<T> {
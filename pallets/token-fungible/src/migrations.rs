@@ -0,0 +1,55 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the fungible token pallet.
+
+use super::*;
+use frame_support::{
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Counts the holders of the tokens that existed before `HolderCount` was tracked.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		for id in Tokens::<T>::iter_keys() {
+			let mut count = 0u32;
+			for balance in Balances::<T>::iter_prefix_values(id) {
+				reads.saturating_inc();
+				if !balance.is_zero() {
+					count.saturating_inc();
+				}
+			}
+			HolderCount::<T>::insert(id, count);
+			reads.saturating_inc();
+			writes.saturating_inc();
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -19,6 +19,8 @@
 use super::*;
 use crate::mock::*;
 use frame_support::{assert_noop, assert_ok};
+use sp_core::{ecdsa, Pair};
+use sp_runtime::traits::Convert;
 
 const ALICE: u64 = 1;
 const BOB: u64 = 2;
@@ -32,7 +34,8 @@ fn create_token_should_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_eq!(TokenFungible::exists(1), true);
 		assert_eq!(TokenFungible::token_name(1), b"W3G".to_vec());
@@ -50,7 +53,8 @@ fn create_token_should_not_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_noop!(
 			TokenFungible::create_token(
@@ -58,7 +62,8 @@ fn create_token_should_not_work() {
 				1,
 				b"W3G".to_vec(),
 				b"W3G".to_vec(),
-				18
+				18,
+				None
 			),
 			Error::<Test>::InvalidId
 		);
@@ -73,7 +78,8 @@ fn mint_should_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
 		assert_eq!(TokenFungible::balance_of(1, ALICE), 100);
@@ -96,7 +102,8 @@ fn mint_should_not_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_noop!(
 			TokenFungible::mint(Origin::signed(BOB), 1, ALICE, 100),
@@ -113,7 +120,8 @@ fn burn_should_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, BOB, 100));
@@ -142,7 +150,8 @@ fn burn_should_not_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 
 		assert_noop!(TokenFungible::burn(Origin::signed(BOB), 1, 100), Error::<Test>::NumOverflow);
@@ -157,7 +166,8 @@ fn transfer_should_works() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
 		assert_eq!(TokenFungible::balance_of(1, ALICE), 100);
@@ -178,7 +188,8 @@ fn approve_should_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
 		assert_eq!(TokenFungible::balance_of(1, ALICE), 100);
@@ -201,7 +212,8 @@ fn approve_should_not_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
 		assert_noop!(
@@ -225,7 +237,8 @@ fn transfer_from_should_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
 		assert_eq!(TokenFungible::balance_of(1, ALICE), 100);
@@ -258,7 +271,8 @@ fn transfer_from_should_not_work() {
 			1,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
 		assert_eq!(TokenFungible::balance_of(1, ALICE), 100);
@@ -279,3 +293,670 @@ fn transfer_from_should_not_work() {
 		assert_eq!(TokenFungible::total_supply(1), 100);
 	})
 }
+
+#[test]
+fn fungibles_traits_should_work() {
+	use frame_support::traits::tokens::{
+		fungibles::{approvals, metadata, Inspect, Mutate, Transfer},
+		DepositConsequence, WithdrawConsequence,
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			<TokenFungible as Inspect<u64>>::can_deposit(1, &ALICE, 100, true),
+			DepositConsequence::UnknownAsset
+		);
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_eq!(<TokenFungible as metadata::Inspect<u64>>::name(&1), b"W3G".to_vec());
+		assert_eq!(<TokenFungible as metadata::Inspect<u64>>::decimals(&1), 18);
+
+		assert_ok!(<TokenFungible as Mutate<u64>>::mint_into(1, &ALICE, 100));
+		assert_eq!(<TokenFungible as Inspect<u64>>::total_issuance(1), 100);
+		assert_eq!(<TokenFungible as Inspect<u64>>::balance(1, &ALICE), 100);
+		assert_eq!(
+			<TokenFungible as Inspect<u64>>::can_withdraw(1, &ALICE, 101),
+			WithdrawConsequence::NoFunds
+		);
+
+		assert_ok!(<TokenFungible as Transfer<u64>>::transfer(1, &ALICE, &BOB, 30, false));
+		assert_eq!(TokenFungible::balance_of(1, BOB), 30);
+
+		assert_ok!(<TokenFungible as approvals::Mutate<u64>>::approve(1, &ALICE, &BOB, 50));
+		assert_eq!(<TokenFungible as approvals::Inspect<u64>>::allowance(1, &ALICE, &BOB), 50);
+		assert_ok!(<TokenFungible as approvals::Mutate<u64>>::transfer_from(
+			1, &ALICE, &BOB, &CHARLIE, 20
+		));
+		assert_eq!(TokenFungible::balance_of(1, CHARLIE), 20);
+		assert_eq!(TokenFungible::allowances(1, (ALICE, BOB)), 30);
+
+		assert_eq!(<TokenFungible as Mutate<u64>>::burn_from(1, &BOB, 30), Ok(30));
+		assert_eq!(<TokenFungible as Inspect<u64>>::total_issuance(1), 70);
+	})
+}
+
+fn evm_address(pair: &ecdsa::Pair) -> H160 {
+	let message = [0u8; 32];
+	let signature = pair.sign_prehashed(&message);
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message).unwrap();
+	H160::from_slice(&keccak_256(&public)[12..])
+}
+
+#[test]
+fn permit_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let owner = evm_address(&pair);
+		let owner_account = MockAddressMapping::convert(owner);
+		let spender = H160::from_low_u64_be(BOB);
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, owner_account, 100));
+
+		let digest = TokenFungible::permit_digest(1, owner, spender, 100, 0, U256::MAX);
+		let signature = pair.sign_prehashed(&digest).0;
+		assert_ok!(TokenFungible::permit(
+			Origin::signed(CHARLIE),
+			1,
+			owner,
+			spender,
+			100,
+			U256::MAX,
+			signature
+		));
+		assert_eq!(TokenFungible::allowances(1, (owner_account, BOB)), 100);
+		assert_eq!(TokenFungible::nonces(1, owner), 1);
+
+		assert_ok!(TokenFungible::transfer_from(
+			Origin::signed(BOB),
+			1,
+			owner_account,
+			CHARLIE,
+			60
+		));
+		assert_eq!(TokenFungible::balance_of(1, CHARLIE), 60);
+		assert_eq!(TokenFungible::allowances(1, (owner_account, BOB)), 40);
+
+		// A signature can only be used once.
+		assert_noop!(
+			TokenFungible::permit(
+				Origin::signed(CHARLIE),
+				1,
+				owner,
+				spender,
+				100,
+				U256::MAX,
+				signature
+			),
+			Error::<Test>::InvalidSignature
+		);
+	})
+}
+
+#[test]
+fn permit_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let owner = evm_address(&pair);
+		let spender = H160::from_low_u64_be(BOB);
+
+		let digest = TokenFungible::permit_digest(1, owner, spender, 100, 0, 5.into());
+		let signature = pair.sign_prehashed(&digest).0;
+		Timestamp::set_timestamp(10_000);
+		assert_noop!(
+			TokenFungible::permit(
+				Origin::signed(CHARLIE),
+				1,
+				owner,
+				spender,
+				100,
+				5.into(),
+				signature
+			),
+			Error::<Test>::PermitExpired
+		);
+
+		let other = ecdsa::Pair::from_seed(&[2u8; 32]);
+		let digest = TokenFungible::permit_digest(1, owner, spender, 100, 0, U256::MAX);
+		let signature = other.sign_prehashed(&digest).0;
+		assert_noop!(
+			TokenFungible::permit(
+				Origin::signed(CHARLIE),
+				1,
+				owner,
+				spender,
+				100,
+				U256::MAX,
+				signature
+			),
+			Error::<Test>::InvalidSignature
+		);
+	})
+}
+
+#[test]
+fn roles_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_noop!(
+			TokenFungible::mint(Origin::signed(BOB), 1, BOB, 100),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(TokenFungible::grant_role(Origin::signed(ALICE), 1, TokenRole::Admin, BOB));
+		assert_ok!(TokenFungible::grant_role(Origin::signed(BOB), 1, TokenRole::Minter, CHARLIE));
+		assert_noop!(
+			TokenFungible::grant_role(Origin::signed(BOB), 1, TokenRole::Admin, CHARLIE),
+			Error::<Test>::NoPermission
+		);
+		assert_eq!(TokenFungible::has_role(1, (TokenRole::Minter, CHARLIE)), true);
+
+		assert_ok!(TokenFungible::mint(Origin::signed(CHARLIE), 1, BOB, 100));
+		assert_eq!(TokenFungible::balance_of(1, BOB), 100);
+
+		assert_noop!(
+			TokenFungible::burn_from(Origin::signed(CHARLIE), 1, BOB, 40),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenFungible::grant_role(Origin::signed(BOB), 1, TokenRole::Burner, CHARLIE));
+		assert_ok!(TokenFungible::burn_from(Origin::signed(CHARLIE), 1, BOB, 40));
+		assert_eq!(TokenFungible::balance_of(1, BOB), 60);
+		assert_eq!(TokenFungible::total_supply(1), 60);
+
+		assert_ok!(TokenFungible::revoke_role(Origin::signed(BOB), 1, TokenRole::Minter, CHARLIE));
+		assert_eq!(TokenFungible::has_role(1, (TokenRole::Minter, CHARLIE)), false);
+		assert_noop!(
+			TokenFungible::mint(Origin::signed(CHARLIE), 1, BOB, 100),
+			Error::<Test>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn transfer_ownership_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_noop!(
+			TokenFungible::transfer_ownership(Origin::signed(BOB), 1, BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TokenFungible::transfer_ownership(Origin::signed(ALICE), 1, BOB));
+
+		assert_noop!(
+			TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenFungible::mint(Origin::signed(BOB), 1, ALICE, 100));
+		assert_eq!(TokenFungible::balance_of(1, ALICE), 100);
+	})
+}
+
+#[test]
+fn max_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			Some(100)
+		));
+		assert_eq!(TokenFungible::max_supply(1), Some(100));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 60));
+		assert_noop!(
+			TokenFungible::mint(Origin::signed(ALICE), 1, BOB, 50),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, BOB, 40));
+		assert_eq!(TokenFungible::total_supply(1), 100);
+		assert_noop!(
+			TokenFungible::set_max_supply(Origin::signed(ALICE), 1, 200),
+			Error::<Test>::MaxSupplyAlreadySet
+		);
+
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			2,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 2, ALICE, 100));
+		assert_noop!(
+			TokenFungible::set_max_supply(Origin::signed(BOB), 2, 100),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			TokenFungible::set_max_supply(Origin::signed(ALICE), 2, 99),
+			Error::<Test>::MaxSupplyTooLow
+		);
+		assert_ok!(TokenFungible::set_max_supply(Origin::signed(ALICE), 2, 100));
+		assert_noop!(
+			TokenFungible::mint(Origin::signed(ALICE), 2, ALICE, 1),
+			Error::<Test>::MaxSupplyExceeded
+		);
+	})
+}
+
+#[test]
+fn emission_schedule_should_work() {
+	use frame_support::traits::tokens::fungibles::Mutate;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_noop!(
+			TokenFungible::mint_emission(Origin::signed(ALICE), 1, ALICE, 10),
+			Error::<Test>::NoEmissionSchedule
+		);
+		assert_ok!(TokenFungible::set_emission_schedule(Origin::signed(ALICE), 1, 10, 25));
+		assert_noop!(
+			TokenFungible::set_emission_schedule(Origin::signed(ALICE), 1, 20, 50),
+			Error::<Test>::EmissionScheduleAlreadySet
+		);
+		assert_noop!(
+			TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 10),
+			Error::<Test>::EmissionScheduled
+		);
+		assert_noop!(
+			<TokenFungible as Mutate<u64>>::mint_into(1, &ALICE, 10),
+			Error::<Test>::EmissionScheduled
+		);
+		assert_eq!(TokenFungible::available_emission(1), 0);
+
+		System::set_block_number(3);
+		assert_eq!(TokenFungible::available_emission(1), 20);
+		assert_noop!(
+			TokenFungible::mint_emission(Origin::signed(BOB), 1, BOB, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenFungible::grant_role(Origin::signed(ALICE), 1, TokenRole::Minter, BOB));
+		assert_ok!(TokenFungible::mint_emission(Origin::signed(BOB), 1, BOB, 15));
+		assert_noop!(
+			TokenFungible::mint_emission(Origin::signed(BOB), 1, BOB, 10),
+			Error::<Test>::EmissionExceeded
+		);
+
+		System::set_block_number(10);
+		assert_eq!(TokenFungible::available_emission(1), 10);
+		assert_ok!(TokenFungible::mint_emission(Origin::signed(ALICE), 1, ALICE, 10));
+		assert_eq!(TokenFungible::total_supply(1), 25);
+		assert_eq!(TokenFungible::available_emission(1), 0);
+	})
+}
+
+#[test]
+fn vesting_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 1000));
+		assert_noop!(
+			TokenFungible::vested_transfer(Origin::signed(ALICE), 1, BOB, 100, 1, 10, 0, 4),
+			Error::<Test>::InvalidVestingSchedule
+		);
+		assert_noop!(
+			TokenFungible::vested_transfer(Origin::signed(ALICE), 1, BOB, 9, 1, 10, 5, 4),
+			Error::<Test>::VestedTransferTooLow
+		);
+
+		// 25 tokens every 5 blocks after a cliff of 10 blocks
+		assert_ok!(TokenFungible::vested_transfer(Origin::signed(ALICE), 1, BOB, 100, 1, 10, 5, 4));
+		assert_eq!(TokenFungible::balance_of(1, BOB), 100);
+		assert_eq!(TokenFungible::vesting_locked(1, BOB), 100);
+		assert_noop!(
+			TokenFungible::transfer(Origin::signed(BOB), 1, CHARLIE, 1),
+			Error::<Test>::LiquidityRestrictions
+		);
+
+		System::set_block_number(8);
+		assert_ok!(TokenFungible::vest(Origin::signed(BOB), 1));
+		assert_eq!(TokenFungible::vesting_locked(1, BOB), 100);
+
+		System::set_block_number(11);
+		assert_ok!(TokenFungible::vest(Origin::signed(BOB), 1));
+		assert_eq!(TokenFungible::vesting_locked(1, BOB), 50);
+		assert_ok!(TokenFungible::transfer(Origin::signed(BOB), 1, CHARLIE, 50));
+		assert_noop!(
+			TokenFungible::transfer(Origin::signed(BOB), 1, CHARLIE, 1),
+			Error::<Test>::LiquidityRestrictions
+		);
+
+		System::set_block_number(21);
+		assert_ok!(TokenFungible::vest(Origin::signed(BOB), 1));
+		assert_eq!(TokenFungible::vesting_locked(1, BOB), 0);
+		assert_eq!(TokenFungible::vesting(1, BOB).len(), 0);
+		assert_noop!(TokenFungible::vest(Origin::signed(BOB), 1), Error::<Test>::NotVesting);
+		assert_ok!(TokenFungible::transfer(Origin::signed(BOB), 1, CHARLIE, 50));
+	})
+}
+
+#[test]
+fn merge_and_revoke_schedules_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 1000));
+		assert_ok!(TokenFungible::vested_transfer(Origin::signed(ALICE), 1, BOB, 100, 1, 0, 1, 10));
+		assert_ok!(TokenFungible::vested_transfer(Origin::signed(ALICE), 1, BOB, 200, 1, 0, 10, 2));
+		assert_eq!(TokenFungible::vesting_locked(1, BOB), 300);
+
+		assert_noop!(
+			TokenFungible::merge_schedules(Origin::signed(CHARLIE), 1, BOB, 0, 1),
+			Error::<Test>::NotScheduleCreator
+		);
+		assert_noop!(
+			TokenFungible::merge_schedules(Origin::signed(ALICE), 1, BOB, 0, 2),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+
+		System::set_block_number(6);
+		assert_ok!(TokenFungible::merge_schedules(Origin::signed(ALICE), 1, BOB, 0, 1));
+		assert_eq!(TokenFungible::vesting(1, BOB).len(), 1);
+		assert_eq!(TokenFungible::vesting_locked(1, BOB), 250);
+
+		System::set_block_number(11);
+		assert_noop!(
+			TokenFungible::revoke_schedule(Origin::signed(BOB), 1, BOB, 0),
+			Error::<Test>::NotScheduleCreator
+		);
+		assert_ok!(TokenFungible::revoke_schedule(Origin::signed(ALICE), 1, BOB, 0));
+		assert_eq!(TokenFungible::vesting_locked(1, BOB), 0);
+		assert_eq!(TokenFungible::balance_of(1, BOB), 130);
+		assert_eq!(TokenFungible::balance_of(1, ALICE), 870);
+	})
+}
+
+#[test]
+fn checkpoints_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
+		assert_eq!(TokenFungible::balance_of_at(1, &ALICE, 1), None);
+		assert_noop!(
+			TokenFungible::enable_checkpoints(Origin::signed(BOB), 1),
+			Error::<Test>::NotOwner
+		);
+
+		System::set_block_number(2);
+		assert_ok!(TokenFungible::enable_checkpoints(Origin::signed(ALICE), 1));
+		assert_noop!(
+			TokenFungible::enable_checkpoints(Origin::signed(ALICE), 1),
+			Error::<Test>::CheckpointsAlreadyEnabled
+		);
+
+		System::set_block_number(5);
+		assert_ok!(TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 30));
+		assert_ok!(TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 10));
+
+		System::set_block_number(8);
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, CHARLIE, 50));
+		assert_ok!(TokenFungible::burn(Origin::signed(BOB), 1, 20));
+
+		System::set_block_number(10);
+		assert_eq!(TokenFungible::balance_of_at(1, &ALICE, 1), None);
+		assert_eq!(TokenFungible::balance_of_at(1, &ALICE, 11), None);
+		assert_eq!(TokenFungible::balance_of_at(1, &ALICE, 2), Some(100));
+		assert_eq!(TokenFungible::balance_of_at(1, &ALICE, 4), Some(100));
+		assert_eq!(TokenFungible::balance_of_at(1, &ALICE, 5), Some(60));
+		assert_eq!(TokenFungible::balance_of_at(1, &ALICE, 10), Some(60));
+		assert_eq!(TokenFungible::balance_of_at(1, &BOB, 4), Some(0));
+		assert_eq!(TokenFungible::balance_of_at(1, &BOB, 7), Some(40));
+		assert_eq!(TokenFungible::balance_of_at(1, &BOB, 8), Some(20));
+		assert_eq!(TokenFungible::balance_of_at(1, &CHARLIE, 7), Some(0));
+		assert_eq!(TokenFungible::balance_of_at(1, &CHARLIE, 9), Some(50));
+
+		assert_eq!(TokenFungible::total_supply_at(1, 2), Some(100));
+		assert_eq!(TokenFungible::total_supply_at(1, 7), Some(100));
+		assert_eq!(TokenFungible::total_supply_at(1, 8), Some(130));
+	})
+}
+
+#[test]
+fn holders_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_eq!(TokenFungible::holder_count(1), 0);
+
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
+		assert_ok!(TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 30));
+		assert_eq!(TokenFungible::holder_count(1), 2);
+
+		assert_ok!(TokenFungible::transfer(Origin::signed(ALICE), 1, CHARLIE, 70));
+		assert_eq!(TokenFungible::holder_count(1), 2);
+
+		let mut holders = TokenFungible::holders(1, None, 10);
+		holders.sort();
+		assert_eq!(holders, vec![(BOB, 30), (CHARLIE, 70)]);
+
+		let first = TokenFungible::holders(1, None, 1);
+		assert_eq!(first.len(), 1);
+		let second = TokenFungible::holders(1, Some(first[0].0), 1);
+		assert_eq!(second.len(), 1);
+		assert_ne!(first, second);
+		assert!(TokenFungible::holders(1, Some(second[0].0), 1).is_empty());
+
+		assert_ok!(TokenFungible::burn(Origin::signed(BOB), 1, 30));
+		assert_eq!(TokenFungible::holder_count(1), 1);
+		assert_eq!(TokenFungible::holders(1, None, 10), vec![(CHARLIE, 70)]);
+	})
+}
+
+#[test]
+fn holder_count_migration_should_work() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
+		assert_ok!(TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 30));
+		crate::Balances::<Test>::insert(1, CHARLIE, 0);
+
+		// Balances from before holders were counted.
+		HolderCount::<Test>::remove(1);
+		StorageVersion::new(0).put::<TokenFungible>();
+
+		migrations::v1::migrate::<Test>();
+		assert_eq!(TokenFungible::holder_count(1), 2);
+		assert_eq!(TokenFungible::on_chain_storage_version(), 1);
+	})
+}
+
+#[test]
+fn destroy_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			Some(1000)
+		));
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&ALICE), CreateTokenDeposit::get());
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 100));
+		assert_ok!(TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 30));
+		assert_ok!(TokenFungible::approve(Origin::signed(ALICE), 1, CHARLIE, 10));
+		assert_ok!(TokenFungible::grant_role(Origin::signed(ALICE), 1, TokenRole::Minter, BOB));
+
+		assert_noop!(
+			TokenFungible::destroy_accounts(Origin::signed(BOB), 1),
+			Error::<Test>::NotDestroying
+		);
+		assert_noop!(TokenFungible::start_destroy(Origin::signed(BOB), 1), Error::<Test>::NotOwner);
+		assert_ok!(TokenFungible::start_destroy(Origin::signed(ALICE), 1));
+		assert_eq!(TokenFungible::is_destroying(1), true);
+		assert_noop!(
+			TokenFungible::start_destroy(Origin::signed(ALICE), 1),
+			Error::<Test>::TokenDestroying
+		);
+		assert_noop!(
+			TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 10),
+			Error::<Test>::TokenDestroying
+		);
+		assert_noop!(
+			TokenFungible::mint(Origin::signed(ALICE), 1, ALICE, 10),
+			Error::<Test>::TokenDestroying
+		);
+
+		// two balances, one allowance and one role, removed two at a time
+		assert_ok!(TokenFungible::destroy_accounts(Origin::signed(BOB), 1));
+		assert_noop!(
+			TokenFungible::finish_destroy(Origin::signed(BOB), 1),
+			Error::<Test>::AccountsRemaining
+		);
+		assert_ok!(TokenFungible::destroy_accounts(Origin::signed(BOB), 1));
+		assert_ok!(TokenFungible::finish_destroy(Origin::signed(BOB), 1));
+
+		assert_eq!(TokenFungible::exists(1), false);
+		assert_eq!(TokenFungible::is_destroying(1), false);
+		assert_eq!(TokenFungible::balance_of(1, ALICE), 0);
+		assert_eq!(TokenFungible::allowances(1, (ALICE, CHARLIE)), 0);
+		assert_eq!(TokenFungible::max_supply(1), None);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&ALICE), 0);
+
+		assert_noop!(
+			TokenFungible::finish_destroy(Origin::signed(BOB), 1),
+			Error::<Test>::NotDestroying
+		);
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(BOB),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+	})
+}
+
+#[test]
+fn freeze_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, BOB, 100));
+		assert_noop!(
+			TokenFungible::freeze(Origin::signed(CHARLIE), 1, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenFungible::grant_role(
+			Origin::signed(ALICE),
+			1,
+			TokenRole::Freezer,
+			CHARLIE
+		));
+
+		assert_ok!(TokenFungible::freeze(Origin::signed(CHARLIE), 1, BOB));
+		assert_eq!(TokenFungible::is_frozen(1, BOB), true);
+		assert_noop!(
+			TokenFungible::transfer(Origin::signed(BOB), 1, ALICE, 10),
+			Error::<Test>::AccountFrozen
+		);
+		assert_ok!(TokenFungible::approve(Origin::signed(BOB), 1, CHARLIE, 10));
+		assert_noop!(
+			TokenFungible::transfer_from(Origin::signed(CHARLIE), 1, BOB, ALICE, 10),
+			Error::<Test>::AccountFrozen
+		);
+		// frozen accounts can still receive tokens
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, BOB, 100));
+
+		assert_ok!(TokenFungible::thaw(Origin::signed(CHARLIE), 1, BOB));
+		assert_ok!(TokenFungible::transfer(Origin::signed(BOB), 1, ALICE, 10));
+
+		assert_ok!(TokenFungible::freeze_token(Origin::signed(ALICE), 1));
+		assert_noop!(
+			TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 10),
+			Error::<Test>::TokenFrozen
+		);
+		assert_ok!(TokenFungible::thaw_token(Origin::signed(CHARLIE), 1));
+		assert_ok!(TokenFungible::transfer(Origin::signed(ALICE), 1, BOB, 10));
+	})
+}
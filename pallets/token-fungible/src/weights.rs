@@ -52,16 +52,38 @@ pub trait WeightInfo {
 	fn burn() -> Weight;
 	fn transfer() -> Weight;
 	fn transfer_from() -> Weight;
+	fn permit() -> Weight;
+	fn burn_from() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn set_emission_schedule() -> Weight;
+	fn mint_emission() -> Weight;
+	fn vested_transfer() -> Weight;
+	fn vest() -> Weight;
+	fn merge_schedules() -> Weight;
+	fn revoke_schedule() -> Weight;
+	fn enable_checkpoints() -> Weight;
+	fn start_destroy() -> Weight;
+	fn destroy_accounts(c: u32, ) -> Weight;
+	fn finish_destroy() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn freeze_token() -> Weight;
+	fn thaw_token() -> Weight;
 }
 
 /// Weights for web3games_token_fungible using the Web3Games node and recommended hardware.
 pub struct W3GWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: TokenFungible Tokens (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenFungible Deposits (r:0 w:1)
 	fn create_token() -> Weight {
-		(15_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: TokenFungible Tokens (r:1 w:1)
 	// Storage: TokenFungible Balances (r:1 w:1)
@@ -97,14 +119,217 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: TokenFungible Tokens (r:2 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: TokenFungible Nonces (r:1 w:1)
+	// Storage: TokenFungible Allowances (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn permit() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:1)
+	// Storage: TokenFungible Roles (r:1 w:0)
+	// Storage: TokenFungible Balances (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn burn_from() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible Roles (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn grant_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible Roles (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn revoke_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn transfer_ownership() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible MaxSupply (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_max_supply() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible EmissionSchedules (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_emission_schedule() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:1)
+	// Storage: TokenFungible Roles (r:1 w:0)
+	// Storage: TokenFungible EmissionSchedules (r:1 w:1)
+	// Storage: TokenFungible MaxSupply (r:1 w:0)
+	// Storage: TokenFungible Balances (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn mint_emission() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible VestingLocks (r:2 w:1)
+	// Storage: TokenFungible Balances (r:2 w:2)
+	// Storage: TokenFungible Vesting (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn vested_transfer() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: TokenFungible Vesting (r:1 w:1)
+	// Storage: TokenFungible VestingLocks (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn vest() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TokenFungible Vesting (r:1 w:1)
+	// Storage: TokenFungible VestingLocks (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn merge_schedules() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TokenFungible Vesting (r:1 w:1)
+	// Storage: TokenFungible VestingLocks (r:1 w:1)
+	// Storage: TokenFungible Balances (r:2 w:2)
+	// Estimated, not benchmarked yet.
+	fn revoke_schedule() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible CheckpointsEnabled (r:1 w:1)
+	// Storage: TokenFungible SupplyCheckpoints (r:0 w:1)
+	// Storage: TokenFungible SupplyCheckpointCount (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn enable_checkpoints() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible Destroying (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Destroying (r:1 w:0)
+	// Storage: TokenFungible Balances (r:1 w:1)
+	// Storage: TokenFungible Allowances (r:1 w:1)
+	// Storage: TokenFungible Roles (r:1 w:1)
+	// Storage: TokenFungible Nonces (r:1 w:1)
+	// Storage: TokenFungible Vesting (r:1 w:1)
+	// Storage: TokenFungible VestingLocks (r:1 w:1)
+	// Storage: TokenFungible BalanceCheckpoints (r:1 w:1)
+	// Storage: TokenFungible BalanceCheckpointCount (r:1 w:1)
+	// Storage: TokenFungible SupplyCheckpoints (r:1 w:1)
+	// Storage: TokenFungible Frozen (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn destroy_accounts(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: TokenFungible Destroying (r:1 w:1)
+	// Storage: TokenFungible Balances (r:1 w:0)
+	// Storage: TokenFungible Allowances (r:1 w:0)
+	// Storage: TokenFungible Roles (r:1 w:0)
+	// Storage: TokenFungible Nonces (r:1 w:0)
+	// Storage: TokenFungible Vesting (r:1 w:0)
+	// Storage: TokenFungible VestingLocks (r:1 w:0)
+	// Storage: TokenFungible BalanceCheckpoints (r:1 w:0)
+	// Storage: TokenFungible BalanceCheckpointCount (r:1 w:0)
+	// Storage: TokenFungible SupplyCheckpoints (r:1 w:0)
+	// Storage: TokenFungible Frozen (r:1 w:0)
+	// Storage: TokenFungible Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenFungible Tokens (r:0 w:1)
+	// Storage: TokenFungible MaxSupply (r:0 w:1)
+	// Storage: TokenFungible EmissionSchedules (r:0 w:1)
+	// Storage: TokenFungible CheckpointsEnabled (r:0 w:1)
+	// Storage: TokenFungible SupplyCheckpointCount (r:0 w:1)
+	// Storage: TokenFungible FrozenTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn finish_destroy() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible Roles (r:1 w:0)
+	// Storage: TokenFungible Frozen (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn freeze() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible Roles (r:1 w:0)
+	// Storage: TokenFungible Frozen (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn thaw() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible Roles (r:1 w:0)
+	// Storage: TokenFungible FrozenTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn freeze_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenFungible Tokens (r:1 w:0)
+	// Storage: TokenFungible Roles (r:1 w:0)
+	// Storage: TokenFungible FrozenTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn thaw_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn create_token() -> Weight {
-		(15_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	fn mint() -> Weight {
 		(20_000_000 as Weight)
@@ -131,4 +356,107 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	fn permit() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn burn_from() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn grant_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_ownership() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_max_supply() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_emission_schedule() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn mint_emission() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn vested_transfer() -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn vest() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn merge_schedules() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn revoke_schedule() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn enable_checkpoints() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn destroy_accounts(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn finish_destroy() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn freeze() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
@@ -7,28 +7,49 @@ use super::*;
 use crate::Pallet as TokenMulti;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
+use sp_core::{crypto::KeyTypeId, ecdsa};
+use sp_runtime::traits::Saturating;
 
 const SEED: u32 = 0;
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"vouc");
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+	let caller: T::AccountId = account(name, 0, SEED);
+	T::Currency::make_free_balance_be(
+		&caller,
+		T::CreateTokenDeposit::get().saturating_mul(1_000u32.into()),
+	);
+	caller
+}
+
+fn evm_address(public: &ecdsa::Public) -> H160 {
+	let message = [0u8; 32];
+	let signature =
+		sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, public, &message).expect("key exists; qed");
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message)
+		.expect("signature is valid; qed");
+	H160::from_slice(&keccak_256(&public)[12..])
+}
 
 benchmarks! {
 	create_token {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 	}: _(RawOrigin::Signed(alice), 1u32.into(), vec![0u8; 20])
 
 	mint {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 
 		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
 	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), 1u32.into(), 10u128)
 
 	mint_batch {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 
 		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
 	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), vec![1u32.into(), 2u32.into(), 3u32.into(), 4u32.into(), 5u32.into()], vec![10u128; 5])
 
 	set_approval_for_all {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
 		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
@@ -36,21 +57,21 @@ benchmarks! {
 	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, true)
 
 	burn {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 
 		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
 		let _ = TokenMulti::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into(), 10u128);
 	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), 5u128)
 
 	burn_batch {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 
 		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
 		let _ = TokenMulti::<T>::mint_batch(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())),1u32.into(), alice.clone(), vec![1u32.into(), 2u32.into(), 3u32.into(), 4u32.into(), 5u32.into()], vec![10u128; 5]);
 	}: _(RawOrigin::Signed(alice), 1u32.into(), vec![1u32.into(), 2u32.into(), 3u32.into(), 4u32.into(), 5u32.into()], vec![5u128; 5])
 
 	transfer_from {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
 		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
@@ -58,12 +79,166 @@ benchmarks! {
 	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), bob.clone(), 1u32.into(), 5u128)
 
 	batch_transfer_from {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
 		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
 		let _ = TokenMulti::<T>::mint_batch(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), vec![1u32.into(), 2u32.into(), 3u32.into(), 4u32.into(), 5u32.into()], vec![10u128; 5]);
 	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), bob.clone(), vec![1u32.into(), 2u32.into(), 3u32.into(), 4u32.into(), 5u32.into()], vec![5u128; 5])
+
+	burn_from {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into(), 10u128);
+		let _ = TokenMulti::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Burner, bob.clone());
+	}: _(RawOrigin::Signed(bob), 1u32.into(), alice, 1u32.into(), 5u128)
+
+	grant_role {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), TokenRole::Minter, bob)
+
+	revoke_role {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Minter, bob.clone());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), TokenRole::Minter, bob)
+
+	transfer_ownership {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	start_destroy {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	destroy_accounts {
+		let c in 0 .. T::RemoveItemsLimit::get();
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		for i in 0 .. c {
+			let _ = TokenMulti::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), account("holder", i, SEED), 1u32.into(), 10u128);
+		}
+		let _ = TokenMulti::<T>::start_destroy(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	finish_destroy {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::start_destroy(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	freeze {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	thaw {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::freeze(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), bob.clone());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	freeze_token {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	thaw_token {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::freeze_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	set_royalty {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), Some(1u32.into()), bob, 500)
+
+	clear_token_royalty {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::set_royalty(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), Some(1u32.into()), bob, 500);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into())
+
+	set_soulbound {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), Some(1u32.into()), true)
+
+	redeem_voucher {
+		let alice = funded_account::<T>("alice");
+		let bob = funded_account::<T>("bob");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+		let signer = evm_address(&public);
+		let _ = TokenMulti::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Minter, T::AddressMapping::convert(signer));
+		let voucher = MintVoucher {
+			token_id: 1u32.into(),
+			amount: 100u128,
+			recipient: None,
+			currency: None,
+			price: 1_000u128,
+			expiry: 1_000u32.into(),
+			nonce: 0,
+		};
+		let digest = TokenMulti::<T>::voucher_digest(1u32.into(), &voucher);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &digest).unwrap().0;
+	}: _(RawOrigin::Signed(bob), 1u32.into(), voucher, signature)
+
+	set_max_supply {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1_000u128)
+
+	register_token_id {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into(), 1u128);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), Some(1_000u128), false)
+
+	set_token_uri {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), vec![0u8; 20])
+
+	index_token_id {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenMulti::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 20]);
+		let _ = TokenMulti::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Minter, bob.clone());
+		// Bob can't pay the deposit, so the token id is left unindexed.
+		let _ = TokenMulti::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(bob)), 1u32.into(), alice.clone(), 1u32.into(), 10u128);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into())
 }
 
 impl_benchmark_test_suite!(TokenMulti, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -22,20 +22,29 @@ use codec::{alloc::string::ToString, Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	ensure,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{
+		tokens::fungibles, Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency,
+	},
 	BoundedVec, PalletId,
 };
 use primitives::Balance;
 use scale_info::TypeInfo;
-use sp_runtime::{traits::AtLeast32BitUnsigned, RuntimeDebug};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Convert, SaturatedConversion, UniqueSaturatedInto, Zero},
+	PerThing, Permill, RuntimeDebug,
+};
 use sp_std::prelude::*;
-use web3games_support::MultiMetadata;
+use web3games_support::{MultiMetadata, MultiTokenTransfer, TokenIdConversion, TokenRole};
 
 pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -48,6 +57,33 @@ mod tests;
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+pub type FungibleTokenIdOf<T> = <<T as Config>::Fungibles as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::AssetId;
+
+pub type MintVoucherOf<T> = MintVoucher<
+	<T as Config>::TokenId,
+	FungibleTokenIdOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// EIP-712 type of the domain a voucher is signed for.
+pub const EIP712_DOMAIN_TYPE: &[u8] =
+	b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+/// EIP-712 type of a mint voucher.
+pub const MINT_VOUCHER_TYPE: &[u8] =
+	b"MintVoucher(uint256 tokenId,uint256 amount,address recipient,address currency,uint256 price,uint256 expiry,uint256 nonce)";
+
+/// Name of the signing domain, multi tokens have no name of their own.
+pub const VOUCHER_DOMAIN_NAME: &[u8] = b"Web3Games Multi Token";
+
+/// Version of the signing domain.
+pub const VOUCHER_VERSION: &[u8] = b"1";
+
+/// The most token ids returned by one `inventory_of` query.
+pub const MAX_INVENTORY_LIMIT: u32 = 100;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct Token<AccountId, BoundedString> {
 	owner: AccountId,
@@ -55,6 +91,33 @@ pub struct Token<AccountId, BoundedString> {
 	total_supply: Balance,
 }
 
+/// The registry record of a single token id of a collection.
+#[derive(Encode, Decode, Clone, Default, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct TokenIdInfo<BoundedString> {
+	/// The amount of the token id in existence.
+	pub supply: Balance,
+	/// The irreversible cap on the amount ever minted, one for non-fungible token ids.
+	pub max_supply: Option<Balance>,
+	/// The URI of the token id, overriding the collection `uri`.
+	pub uri: Option<BoundedString>,
+	pub non_fungible: bool,
+}
+
+/// A minter's signed promise to mint tokens to whoever redeems it and pays its price.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct MintVoucher<TokenId, FungibleTokenId, BlockNumber> {
+	pub token_id: TokenId,
+	pub amount: Balance,
+	/// The only address that can redeem the voucher, anyone if `None`.
+	pub recipient: Option<H160>,
+	/// The fungible token the price is paid in, the native currency if `None`.
+	pub currency: Option<FungibleTokenId>,
+	pub price: Balance,
+	/// The last block the voucher can be redeemed at.
+	pub expiry: BlockNumber,
+	pub nonce: u64,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -86,12 +149,44 @@ pub mod pallet {
 
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 
+		/// Fungible tokens that voucher prices can be paid in.
+		type Fungibles: fungibles::Transfer<Self::AccountId, Balance = Balance>;
+
+		/// Mapping from a fungible token id to the address of its ERC20 precompile.
+		type FungibleTokenIdConversion: TokenIdConversion<FungibleTokenIdOf<Self>>;
+
+		/// The chain id voucher signatures are bound to.
+		type ChainId: Get<u64>;
+
+		/// Mapping from an EVM address to the account it controls.
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Mapping from a token id to the address of its ERC1155 precompile.
+		type TokenIdConversion: TokenIdConversion<Self::MultiTokenId>;
+
+		/// The maximum number of token ids indexed in an account's inventory of a single token,
+		/// further token ids are still held but not indexed.
+		#[pallet::constant]
+		type MaxInventorySize: Get<u32>;
+
+		/// The amount reserved from an account for each token id in its inventory.
+		#[pallet::constant]
+		type InventoryDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of storage items removed by a single `destroy_accounts`.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
 		/// runtime weights.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -127,6 +222,203 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Roles granted on a collection, keyed by `(role, account)`.
+	#[pallet::storage]
+	#[pallet::getter(fn has_role)]
+	pub(super) type Roles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		(TokenRole, T::AccountId),
+		bool,
+		ValueQuery,
+	>;
+
+	/// Accounts that can not transfer a token.
+	#[pallet::storage]
+	#[pallet::getter(fn is_frozen)]
+	pub(super) type Frozen<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Tokens that can not be transferred by any account.
+	#[pallet::storage]
+	#[pallet::getter(fn is_token_frozen)]
+	pub(super) type FrozenTokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiTokenId, bool, ValueQuery>;
+
+	/// The receiver and basis points of the royalty paid on secondary sales of a collection.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_royalty)]
+	pub(super) type Royalties<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiTokenId, (T::AccountId, u16)>;
+
+	/// Royalties of single tokens that override the collection royalty.
+	#[pallet::storage]
+	#[pallet::getter(fn token_royalty)]
+	pub(super) type TokenRoyalties<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		(T::AccountId, u16),
+	>;
+
+	/// Collections whose tokens can not be transferred.
+	#[pallet::storage]
+	#[pallet::getter(fn is_soulbound_collection)]
+	pub(super) type SoulboundCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiTokenId, bool, ValueQuery>;
+
+	/// Single tokens that can not be transferred.
+	#[pallet::storage]
+	#[pallet::getter(fn is_soulbound_token)]
+	pub(super) type SoulboundTokens<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Balances held in place by another pallet, keyed by `(token_id, account, lock)`.
+	#[pallet::storage]
+	pub(super) type Locks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		(T::TokenId, T::AccountId, LockIdentifier),
+		Balance,
+		ValueQuery,
+	>;
+
+	/// The sum of the locks on the balance of an account.
+	#[pallet::storage]
+	#[pallet::getter(fn locked_balance_of)]
+	pub(super) type LockedBalances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		(T::TokenId, T::AccountId),
+		Balance,
+		ValueQuery,
+	>;
+
+	/// Voucher nonces of a signer that were redeemed.
+	#[pallet::storage]
+	#[pallet::getter(fn is_voucher_redeemed)]
+	pub(super) type RedeemedVouchers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		(H160, u64),
+		bool,
+		ValueQuery,
+	>;
+
+	/// The irreversible cap on the amount of a token ever minted.
+	#[pallet::storage]
+	#[pallet::getter(fn max_supply)]
+	pub(super) type MaxSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiTokenId, Balance>;
+
+	/// The amount of a token ever minted, burned amounts included.
+	#[pallet::storage]
+	#[pallet::getter(fn minted)]
+	pub(super) type Minted<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiTokenId, Balance, ValueQuery>;
+
+	/// The supply, max supply and metadata of each token id, recorded on its first mint.
+	#[pallet::storage]
+	#[pallet::getter(fn token_id_info)]
+	pub(super) type TokenIds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		TokenIdInfo<BoundedVec<u8, T::StringLimit>>,
+	>;
+
+	/// The amount of each token id ever minted, burned amounts included.
+	#[pallet::storage]
+	#[pallet::getter(fn token_id_minted)]
+	pub(super) type TokenIdMinted<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		Balance,
+		ValueQuery,
+	>;
+
+	/// The token ids held by each account, with the account that paid the deposit and its amount.
+	#[pallet::storage]
+	pub(super) type Inventory<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::MultiTokenId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::TokenId>,
+		),
+		(T::AccountId, BalanceOf<T>),
+	>;
+
+	/// The number of token ids in each account's inventory.
+	#[pallet::storage]
+	#[pallet::getter(fn inventory_size)]
+	pub(super) type InventorySize<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The number of token ids held by each account that are missing from its inventory.
+	///
+	/// A token id is left out when the inventory is full or the payer can't cover the deposit,
+	/// the holder can add it later with `index_token_id`.
+	#[pallet::storage]
+	#[pallet::getter(fn unindexed_count)]
+	pub(super) type Unindexed<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultiTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The creator of a token and the deposit reserved from them.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit)]
+	pub(super) type Deposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiTokenId, (T::AccountId, BalanceOf<T>)>;
+
+	/// Tokens that are being destroyed and can no longer be used.
+	#[pallet::storage]
+	#[pallet::getter(fn is_destroying)]
+	pub(super) type Destroying<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultiTokenId, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -144,6 +436,26 @@ pub mod pallet {
 			Vec<Balance>,
 		),
 		ApprovalForAll(T::MultiTokenId, T::AccountId, T::AccountId, bool),
+		RoleGranted(T::MultiTokenId, TokenRole, T::AccountId),
+		RoleRevoked(T::MultiTokenId, TokenRole, T::AccountId),
+		OwnershipTransferred(T::MultiTokenId, T::AccountId, T::AccountId),
+		DestructionStarted(T::MultiTokenId),
+		AccountsDestroyed(T::MultiTokenId, u32),
+		Destroyed(T::MultiTokenId),
+		AccountFrozen(T::MultiTokenId, T::AccountId),
+		AccountThawed(T::MultiTokenId, T::AccountId),
+		TokenFrozen(T::MultiTokenId),
+		TokenThawed(T::MultiTokenId),
+		RoyaltySet(T::MultiTokenId, Option<T::TokenId>, T::AccountId, u16),
+		TokenRoyaltyCleared(T::MultiTokenId, T::TokenId),
+		SoulboundSet(T::MultiTokenId, Option<T::TokenId>, bool),
+		Locked(T::MultiTokenId, T::TokenId, T::AccountId, LockIdentifier, Balance),
+		Unlocked(T::MultiTokenId, T::TokenId, T::AccountId, LockIdentifier, Balance),
+		VoucherRedeemed(T::MultiTokenId, T::TokenId, T::AccountId, Balance, H160, u64),
+		MaxSupplySet(T::MultiTokenId, Balance),
+		TokenIdRegistered(T::MultiTokenId, T::TokenId, Option<Balance>, bool),
+		TokenUriSet(T::MultiTokenId, T::TokenId, Vec<u8>),
+		TokenIdIndexed(T::MultiTokenId, T::AccountId, T::TokenId),
 	}
 
 	#[pallet::error]
@@ -161,10 +473,36 @@ pub mod pallet {
 		ConfuseBehavior,
 		InsufficientTokens,
 		InsufficientAuthorizedTokens,
+		TokenDestroying,
+		NotDestroying,
+		AccountsRemaining,
+		AccountFrozen,
+		TokenFrozen,
+		InvalidRoyalty,
+		NoTokenRoyalty,
+		Soulbound,
+		TokenLocked,
+		NotLocked,
+		InvalidSignature,
+		VoucherExpired,
+		VoucherRedeemed,
+		NotVoucherRecipient,
+		MaxSupplyAlreadySet,
+		MaxSupplyTooLow,
+		MaxSupplyExceeded,
+		TokenIdAlreadyRegistered,
+		AlreadyIndexed,
+		InventoryFull,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+				.saturating_add(migrations::v2::migrate::<T>())
+				.saturating_add(migrations::v3::migrate::<T>())
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -175,7 +513,11 @@ pub mod pallet {
 			uri: Vec<u8>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::do_create_token(&who, id, uri)
+			Self::do_create_token(&who, id, uri)?;
+			let deposit = T::CreateTokenDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			Deposits::<T>::insert(id, (who, deposit));
+			Ok(())
 		}
 
 		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
@@ -261,6 +603,225 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			Self::do_batch_burn(&who, id, token_ids, amounts)
 		}
+
+		/// Burn tokens held by `from`, requires the `Burner` role.
+		#[pallet::weight(T::WeightInfo::burn_from())]
+		pub fn burn_from(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			from: T::AccountId,
+			token_id: T::TokenId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_owner_or_role(id, &who, TokenRole::Burner)?;
+			Self::do_burn(&from, id, token_id, amount)
+		}
+
+		#[pallet::weight(T::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			role: TokenRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_grant_role(id, &who, role, &account)
+		}
+
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			role: TokenRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_revoke_role(id, &who, role, &account)
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_transfer_ownership(id, &who, &new_owner)
+		}
+
+		/// Start destroying a token. Only the owner can do this.
+		///
+		/// Once started, the token can no longer be minted, transferred or approved.
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub fn start_destroy(origin: OriginFor<T>, id: T::MultiTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_start_destroy(id, &who)
+		}
+
+		/// Remove up to `RemoveItemsLimit` account entries of a token being destroyed.
+		///
+		/// Anyone can call this until all entries are removed.
+		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		pub fn destroy_accounts(origin: OriginFor<T>, id: T::MultiTokenId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_destroy_accounts(id)
+		}
+
+		/// Remove a token whose accounts are all destroyed and refund the creation deposit.
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub fn finish_destroy(origin: OriginFor<T>, id: T::MultiTokenId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_finish_destroy(id)
+		}
+
+		/// Stop `account` from transferring the token. Needs the `Freezer` role.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_freeze(id, &who, &account)
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_thaw(id, &who, &account)
+		}
+
+		/// Stop every account from transferring the token. Needs the `Freezer` role.
+		#[pallet::weight(T::WeightInfo::freeze_token())]
+		pub fn freeze_token(origin: OriginFor<T>, id: T::MultiTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_freeze_token(id, &who)
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw_token())]
+		pub fn thaw_token(origin: OriginFor<T>, id: T::MultiTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_thaw_token(id, &who)
+		}
+
+		/// Set the royalty paid to `receiver` on secondary sales, in basis points of the sale
+		/// price, for the whole collection or for a single token if `token_id` is given.
+		///
+		/// Zero `basis_points` removes the collection royalty, while on a single token it waives
+		/// the collection royalty for that token. Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			token_id: Option<T::TokenId>,
+			receiver: T::AccountId,
+			basis_points: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_royalty(id, &who, token_id, receiver, basis_points)
+		}
+
+		/// Remove the royalty of a single token so that it falls back to the collection
+		/// royalty. Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::clear_token_royalty())]
+		pub fn clear_token_royalty(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			token_id: T::TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_clear_token_royalty(id, &who, token_id)
+		}
+
+		/// Bind the whole collection, or a single token if `token_id` is given, to the accounts
+		/// holding it. Soulbound tokens can still be minted and burned but never transferred.
+		///
+		/// Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::set_soulbound())]
+		pub fn set_soulbound(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			token_id: Option<T::TokenId>,
+			soulbound: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_soulbound(id, &who, token_id, soulbound)
+		}
+
+		/// Mint the tokens of a voucher signed by a minter to the caller, who pays its price
+		/// to the owner of the token.
+		///
+		/// Each nonce of a signer can be redeemed once.
+		#[pallet::weight(T::WeightInfo::redeem_voucher())]
+		pub fn redeem_voucher(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			voucher: MintVoucherOf<T>,
+			signature: [u8; 65],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem_voucher(&who, id, voucher, signature)
+		}
+
+		/// Cap the total supply of a token, the cap can not be changed once set.
+		///
+		/// Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::set_max_supply())]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			max_supply: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_max_supply(id, &who, max_supply)
+		}
+
+		/// Cap the supply of a token id before or after it is first minted, the cap can not be
+		/// changed once set. Non-fungible token ids have a max supply of one, whatever
+		/// `max_supply` is.
+		///
+		/// Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::register_token_id())]
+		pub fn register_token_id(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			token_id: T::TokenId,
+			max_supply: Option<Balance>,
+			non_fungible: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_register_token_id(id, &who, token_id, max_supply, non_fungible)
+		}
+
+		/// Override the URI of a token id, an empty `uri` falls back to the collection `uri`.
+		///
+		/// Needs the `Metadata` role.
+		#[pallet::weight(T::WeightInfo::set_token_uri())]
+		pub fn set_token_uri(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			token_id: T::TokenId,
+			uri: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_token_uri(id, &who, token_id, uri)
+		}
+
+		/// Add a token id held by the caller to their inventory, reserving the deposit from them.
+		#[pallet::weight(T::WeightInfo::index_token_id())]
+		pub fn index_token_id(
+			origin: OriginFor<T>,
+			id: T::MultiTokenId,
+			token_id: T::TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_index_token_id(id, &who, token_id)
+		}
 	}
 }
 
@@ -296,6 +857,7 @@ impl<T: Config> Pallet<T> {
 		approved: bool,
 	) -> DispatchResult {
 		ensure!(Tokens::<T>::contains_key(id), Error::<T>::InvalidId,);
+		Self::ensure_live(id)?;
 
 		OperatorApprovals::<T>::insert(id, (&who, &operator), approved);
 
@@ -316,9 +878,12 @@ impl<T: Config> Pallet<T> {
 		Tokens::<T>::try_mutate(id, |maybe_token| -> DispatchResult {
 			let token = maybe_token.as_mut().ok_or(Error::<T>::Unknown)?;
 
-			Self::increase_balance(id, to, token_id, amount)?;
-
 			let new_total_supply = token.total_supply.saturating_add(amount);
+			Self::increase_minted(id, amount)?;
+
+			Self::increase_balance(id, who, to, token_id, amount)?;
+			Self::increase_token_id_supply(id, token_id, amount)?;
+
 			token.total_supply = new_total_supply;
 			Ok(())
 		})?;
@@ -347,9 +912,12 @@ impl<T: Config> Pallet<T> {
 			Tokens::<T>::try_mutate(id, |maybe_token| -> DispatchResult {
 				let token = maybe_token.as_mut().ok_or(Error::<T>::Unknown)?;
 
-				Self::increase_balance(id, to, token_id, amount)?;
-
 				let new_total_supply = token.total_supply.saturating_add(amount);
+				Self::increase_minted(id, amount)?;
+
+				Self::increase_balance(id, who, to, token_id, amount)?;
+				Self::increase_token_id_supply(id, token_id, amount)?;
+
 				token.total_supply = new_total_supply;
 				Ok(())
 			})?;
@@ -360,16 +928,143 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	pub fn do_burn(
-		who: &T::AccountId,
-		id: T::MultiTokenId,
-		token_id: T::TokenId,
+	/// Add a mint to the amount of a token ever minted, which burns don't lower.
+	fn increase_minted(id: T::MultiTokenId, amount: Balance) -> DispatchResult {
+		Minted::<T>::try_mutate(id, |minted| -> DispatchResult {
+			let new_minted = minted.checked_add(amount).ok_or(Error::<T>::NumOverflow)?;
+			if let Some(max_supply) = MaxSupply::<T>::get(id) {
+				ensure!(new_minted <= max_supply, Error::<T>::MaxSupplyExceeded);
+			}
+			*minted = new_minted;
+			Ok(())
+		})
+	}
+
+	/// Add a mint to the supply of a token id, recording the token id on its first mint.
+	fn increase_token_id_supply(
+		id: T::MultiTokenId,
+		token_id: T::TokenId,
+		amount: Balance,
+	) -> DispatchResult {
+		let minted = TokenIdMinted::<T>::get(id, token_id)
+			.checked_add(amount)
+			.ok_or(Error::<T>::NumOverflow)?;
+		TokenIds::<T>::try_mutate(id, token_id, |maybe_info| -> DispatchResult {
+			let info = maybe_info.get_or_insert_with(Default::default);
+			if let Some(max_supply) = info.max_supply {
+				ensure!(minted <= max_supply, Error::<T>::MaxSupplyExceeded);
+			}
+			info.supply = info.supply.checked_add(amount).ok_or(Error::<T>::NumOverflow)?;
+			Ok(())
+		})?;
+		TokenIdMinted::<T>::insert(id, token_id, minted);
+		Ok(())
+	}
+
+	fn decrease_token_id_supply(id: T::MultiTokenId, token_id: T::TokenId, amount: Balance) {
+		TokenIds::<T>::mutate(id, token_id, |maybe_info| {
+			if let Some(info) = maybe_info {
+				info.supply = info.supply.saturating_sub(amount);
+			}
+		});
+	}
+
+	/// The amount of a token id in existence.
+	pub fn token_id_supply(id: T::MultiTokenId, token_id: T::TokenId) -> Balance {
+		Self::token_id_info(id, token_id).map(|info| info.supply).unwrap_or_default()
+	}
+
+	/// Whether any amount of a token id exists.
+	pub fn token_id_exists(id: T::MultiTokenId, token_id: T::TokenId) -> bool {
+		!Self::token_id_supply(id, token_id).is_zero()
+	}
+
+	pub fn do_register_token_id(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+		max_supply: Option<Balance>,
+		non_fungible: bool,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+		let max_supply = if non_fungible { Some(1) } else { max_supply };
+
+		TokenIds::<T>::try_mutate(id, token_id, |maybe_info| -> DispatchResult {
+			let info = maybe_info.get_or_insert_with(Default::default);
+			ensure!(
+				info.max_supply.is_none() && !info.non_fungible,
+				Error::<T>::TokenIdAlreadyRegistered
+			);
+			if let Some(max_supply) = max_supply {
+				ensure!(
+					max_supply >= TokenIdMinted::<T>::get(id, token_id),
+					Error::<T>::MaxSupplyTooLow
+				);
+			}
+			info.max_supply = max_supply;
+			info.non_fungible = non_fungible;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::TokenIdRegistered(id, token_id, max_supply, non_fungible));
+
+		Ok(())
+	}
+
+	pub fn do_set_token_uri(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+		uri: Vec<u8>,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Metadata)?;
+		Self::ensure_live(id)?;
+		let bounded_uri: Option<BoundedVec<u8, T::StringLimit>> = if uri.is_empty() {
+			None
+		} else {
+			Some(uri.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?)
+		};
+
+		TokenIds::<T>::mutate(id, token_id, |maybe_info| {
+			maybe_info.get_or_insert_with(Default::default).uri = bounded_uri;
+		});
+
+		Self::deposit_event(Event::TokenUriSet(id, token_id, uri));
+
+		Ok(())
+	}
+
+	pub fn do_set_max_supply(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		max_supply: Balance,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+		ensure!(!MaxSupply::<T>::contains_key(id), Error::<T>::MaxSupplyAlreadySet);
+		ensure!(max_supply >= Minted::<T>::get(id), Error::<T>::MaxSupplyTooLow);
+
+		MaxSupply::<T>::insert(id, max_supply);
+
+		Self::deposit_event(Event::MaxSupplySet(id, max_supply));
+
+		Ok(())
+	}
+
+	pub fn do_burn(
+		who: &T::AccountId,
+		id: T::MultiTokenId,
+		token_id: T::TokenId,
 		amount: Balance,
 	) -> DispatchResult {
 		Tokens::<T>::try_mutate(id, |maybe_token| -> DispatchResult {
 			let token = maybe_token.as_mut().ok_or(Error::<T>::Unknown)?;
 
 			Self::decrease_balance(id, who, token_id, amount)?;
+			Self::decrease_token_id_supply(id, token_id, amount);
 
 			let new_total_supply = token.total_supply.saturating_sub(amount);
 			token.total_supply = new_total_supply;
@@ -398,6 +1093,7 @@ impl<T: Config> Pallet<T> {
 				let token = maybe_token.as_mut().ok_or(Error::<T>::Unknown)?;
 
 				Self::decrease_balance(id, who, token_id, amount)?;
+				Self::decrease_token_id_supply(id, token_id, amount);
 
 				let new_total_supply = token.total_supply.saturating_sub(amount);
 				token.total_supply = new_total_supply;
@@ -419,6 +1115,52 @@ impl<T: Config> Pallet<T> {
 		amount: Balance,
 	) -> DispatchResult {
 		ensure!(Self::owner_or_approved(id, &who, &from), Error::<T>::NotOwnerOrApproved);
+		Self::ensure_not_frozen(id, from)?;
+		ensure!(!Self::is_soulbound(id, token_id), Error::<T>::Soulbound);
+		Self::transfer_tokens(who, id, from, to, token_id, amount)
+	}
+
+	/// Return `amount` of `token_id` held in escrow by `escrow` to `depositor`.
+	///
+	/// Unlike `do_transfer_from`, this ignores soulbound flags set while the tokens were in
+	/// escrow, so they can always go back to the account they are bound to.
+	pub fn do_release_escrow(
+		id: T::MultiTokenId,
+		escrow: &T::AccountId,
+		depositor: &T::AccountId,
+		token_id: T::TokenId,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::ensure_not_frozen(id, escrow)?;
+		Self::transfer_tokens(depositor, id, escrow, depositor, token_id, amount)
+	}
+
+	/// Move `amount` of `token_id` of `depositor` into escrow by `escrow`.
+	///
+	/// Like `do_release_escrow`, this ignores soulbound flags: callers only return escrowed
+	/// tokens to `depositor` or burn them.
+	pub fn do_deposit_escrow(
+		id: T::MultiTokenId,
+		depositor: &T::AccountId,
+		escrow: &T::AccountId,
+		token_id: T::TokenId,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::ensure_not_frozen(id, depositor)?;
+		Self::transfer_tokens(depositor, id, depositor, escrow, token_id, amount)
+	}
+
+	/// Move `amount` of `token_id` from `from` to `to`, callers check soulbound and frozen tokens.
+	///
+	/// `payer` pays the inventory deposit if `to` didn't hold `token_id` yet.
+	fn transfer_tokens(
+		payer: &T::AccountId,
+		id: T::MultiTokenId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token_id: T::TokenId,
+		amount: Balance,
+	) -> DispatchResult {
 		ensure!(
 			Balances::<T>::get(id, (token_id, from.clone())) >= amount,
 			Error::<T>::InsufficientTokens
@@ -430,7 +1172,7 @@ impl<T: Config> Pallet<T> {
 
 		Self::decrease_balance(id, from, token_id, amount)?;
 
-		Self::increase_balance(id, to, token_id, amount)?;
+		Self::increase_balance(id, payer, to, token_id, amount)?;
 
 		Self::deposit_event(Event::Transferred(id, from.clone(), to.clone(), token_id, amount));
 
@@ -446,6 +1188,7 @@ impl<T: Config> Pallet<T> {
 		amounts: Vec<Balance>,
 	) -> DispatchResult {
 		ensure!(Self::owner_or_approved(id, &who, &from), Error::<T>::NotOwnerOrApproved);
+		Self::ensure_not_frozen(id, from)?;
 		ensure!(token_ids.len() == amounts.len(), Error::<T>::LengthMismatch);
 
 		if from == to {
@@ -457,6 +1200,7 @@ impl<T: Config> Pallet<T> {
 			let token_id = token_ids[i];
 			let amount = amounts[i];
 
+			ensure!(!Self::is_soulbound(id, token_id), Error::<T>::Soulbound);
 			ensure!(
 				Balances::<T>::get(id, (token_id, from.clone())) >= amount,
 				Error::<T>::InsufficientTokens
@@ -464,7 +1208,7 @@ impl<T: Config> Pallet<T> {
 
 			Self::decrease_balance(id, from, token_id, amount)?;
 
-			Self::increase_balance(id, to, token_id, amount)?;
+			Self::increase_balance(id, who, to, token_id, amount)?;
 		}
 
 		Self::deposit_event(Event::BatchTransferred(
@@ -500,12 +1244,19 @@ impl<T: Config> Pallet<T> {
 
 	fn increase_balance(
 		id: T::MultiTokenId,
+		payer: &T::AccountId,
 		to: &T::AccountId,
 		token_id: T::TokenId,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_live(id)?;
+
 		Balances::<T>::try_mutate(id, (token_id, to), |balance| -> DispatchResult {
+			let old_balance = *balance;
 			*balance = balance.checked_add(amount).ok_or(Error::<T>::NumOverflow)?;
+			if old_balance.is_zero() && !balance.is_zero() {
+				Self::add_to_inventory(id, payer, to, token_id);
+			}
 			Ok(())
 		})?;
 		Ok(())
@@ -517,21 +1268,586 @@ impl<T: Config> Pallet<T> {
 		token_id: T::TokenId,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_live(id)?;
+
+		let locked = Self::locked_balance_of(id, (token_id, from.clone()));
 		Balances::<T>::try_mutate(id, (token_id, from), |balance| -> DispatchResult {
+			let old_balance = *balance;
 			*balance = balance.checked_sub(amount).ok_or(Error::<T>::NumOverflow)?;
+			ensure!(*balance >= locked, Error::<T>::TokenLocked);
+			if !old_balance.is_zero() && balance.is_zero() {
+				Self::remove_from_inventory(id, from, token_id);
+			}
 			Ok(())
 		})?;
 
 		Ok(())
 	}
 
+	/// Add a token id to the inventory of `who`, reserving the deposit from `payer`.
+	///
+	/// If the inventory is full or `payer` can't pay the deposit the token id is counted in
+	/// `Unindexed` instead, so the inventory never silently misses a balance.
+	fn add_to_inventory(
+		id: T::MultiTokenId,
+		payer: &T::AccountId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+	) {
+		if Self::try_add_to_inventory(id, payer, who, token_id).is_err() {
+			Unindexed::<T>::mutate(id, who, |count| *count = count.saturating_add(1));
+		}
+	}
+
+	fn try_add_to_inventory(
+		id: T::MultiTokenId,
+		payer: &T::AccountId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		let size = InventorySize::<T>::get(id, who);
+		ensure!(size < T::MaxInventorySize::get(), Error::<T>::InventoryFull);
+
+		let deposit = T::InventoryDeposit::get();
+		T::Currency::reserve(payer, deposit)?;
+
+		Inventory::<T>::insert((id, who, token_id), (payer.clone(), deposit));
+		InventorySize::<T>::insert(id, who, size + 1);
+		Ok(())
+	}
+
+	fn remove_from_inventory(id: T::MultiTokenId, who: &T::AccountId, token_id: T::TokenId) {
+		if let Some((depositor, deposit)) = Inventory::<T>::take((id, who, token_id)) {
+			T::Currency::unreserve(&depositor, deposit);
+			let size = InventorySize::<T>::get(id, who).saturating_sub(1);
+			if size.is_zero() {
+				InventorySize::<T>::remove(id, who);
+			} else {
+				InventorySize::<T>::insert(id, who, size);
+			}
+		} else {
+			Self::decrease_unindexed(id, who);
+		}
+	}
+
+	fn decrease_unindexed(id: T::MultiTokenId, who: &T::AccountId) {
+		Unindexed::<T>::mutate_exists(id, who, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| !c.is_zero());
+		});
+	}
+
+	pub fn do_index_token_id(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		ensure!(
+			!Self::balance_of(id, (token_id, who.clone())).is_zero(),
+			Error::<T>::InsufficientTokens
+		);
+		ensure!(!Inventory::<T>::contains_key((id, who, token_id)), Error::<T>::AlreadyIndexed);
+
+		Self::try_add_to_inventory(id, who, who, token_id)?;
+		Self::decrease_unindexed(id, who);
+
+		Self::deposit_event(Event::TokenIdIndexed(id, who.clone(), token_id));
+
+		Ok(())
+	}
+
+	/// Up to `limit` token ids held by `who` and their balances, starting after `start_after`.
+	///
+	/// `limit` is capped at `MAX_INVENTORY_LIMIT`. The `unindexed_count` token ids left out of
+	/// the inventory are not listed.
+	pub fn inventory_of(
+		id: T::MultiTokenId,
+		who: T::AccountId,
+		start_after: Option<T::TokenId>,
+		limit: u32,
+	) -> Vec<(T::TokenId, Balance)> {
+		let iter = match start_after {
+			Some(token_id) => Inventory::<T>::iter_prefix_from(
+				(id, who.clone()),
+				Inventory::<T>::hashed_key_for((id, who.clone(), token_id)),
+			),
+			None => Inventory::<T>::iter_prefix((id, who.clone())),
+		};
+		iter.take(limit.min(MAX_INVENTORY_LIMIT) as usize)
+			.map(|(token_id, _)| (token_id, Self::balance_of(id, (token_id, who.clone()))))
+			.collect()
+	}
+
 	fn owner_or_approved(id: T::MultiTokenId, who: &T::AccountId, owner: &T::AccountId) -> bool {
 		*who == *owner || Self::is_approved_for_all(id, (owner, who))
 	}
 
-	fn has_permission(id: T::MultiTokenId, who: &T::AccountId) -> bool {
-		let token = Tokens::<T>::get(id).unwrap();
-		*who == token.owner
+	pub fn do_grant_role(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_role_admin(id, who, role)?;
+		Self::ensure_live(id)?;
+
+		Roles::<T>::insert(id, (role, account), true);
+
+		Self::deposit_event(Event::RoleGranted(id, role, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_revoke_role(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_role_admin(id, who, role)?;
+
+		Roles::<T>::remove(id, (role, account));
+
+		Self::deposit_event(Event::RoleRevoked(id, role, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_transfer_ownership(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		new_owner: &T::AccountId,
+	) -> DispatchResult {
+		Tokens::<T>::try_mutate(id, |maybe_token| -> DispatchResult {
+			let token = maybe_token.as_mut().ok_or(Error::<T>::InvalidId)?;
+			ensure!(*who == token.owner, Error::<T>::NotOwner);
+			Self::ensure_live(id)?;
+
+			token.owner = new_owner.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::OwnershipTransferred(id, who.clone(), new_owner.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_start_destroy(id: T::MultiTokenId, who: &T::AccountId) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+
+		Destroying::<T>::insert(id, true);
+
+		Self::deposit_event(Event::DestructionStarted(id));
+
+		Ok(())
+	}
+
+	pub fn do_destroy_accounts(id: T::MultiTokenId) -> DispatchResult {
+		ensure!(Self::is_destroying(id), Error::<T>::NotDestroying);
+
+		let limit = T::RemoveItemsLimit::get() as usize;
+		let mut removed = 0;
+		removed += Balances::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += OperatorApprovals::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Roles::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Frozen::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += TokenRoyalties::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += SoulboundTokens::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Locks::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += LockedBalances::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += RedeemedVouchers::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += TokenIds::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += TokenIdMinted::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += InventorySize::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Unindexed::<T>::drain_prefix(id).take(limit - removed).count();
+		for (_, (depositor, deposit)) in Inventory::<T>::drain_prefix((id,)).take(limit - removed) {
+			T::Currency::unreserve(&depositor, deposit);
+			removed += 1;
+		}
+
+		Self::deposit_event(Event::AccountsDestroyed(id, removed as u32));
+
+		Ok(())
+	}
+
+	pub fn do_finish_destroy(id: T::MultiTokenId) -> DispatchResult {
+		ensure!(Self::is_destroying(id), Error::<T>::NotDestroying);
+		ensure!(
+			Balances::<T>::iter_prefix(id).next().is_none() &&
+				OperatorApprovals::<T>::iter_prefix(id).next().is_none() &&
+				Roles::<T>::iter_prefix(id).next().is_none() &&
+				Frozen::<T>::iter_prefix(id).next().is_none() &&
+				TokenRoyalties::<T>::iter_prefix(id).next().is_none() &&
+				SoulboundTokens::<T>::iter_prefix(id).next().is_none() &&
+				Locks::<T>::iter_prefix(id).next().is_none() &&
+				LockedBalances::<T>::iter_prefix(id).next().is_none() &&
+				RedeemedVouchers::<T>::iter_prefix(id).next().is_none() &&
+				TokenIds::<T>::iter_prefix(id).next().is_none() &&
+				TokenIdMinted::<T>::iter_prefix(id).next().is_none() &&
+				Inventory::<T>::iter_prefix((id,)).next().is_none() &&
+				InventorySize::<T>::iter_prefix(id).next().is_none() &&
+				Unindexed::<T>::iter_prefix(id).next().is_none(),
+			Error::<T>::AccountsRemaining
+		);
+
+		Tokens::<T>::remove(id);
+		FrozenTokens::<T>::remove(id);
+		Royalties::<T>::remove(id);
+		SoulboundCollections::<T>::remove(id);
+		MaxSupply::<T>::remove(id);
+		Minted::<T>::remove(id);
+		Destroying::<T>::remove(id);
+		if let Some((depositor, deposit)) = Deposits::<T>::take(id) {
+			T::Currency::unreserve(&depositor, deposit);
+		}
+
+		Self::deposit_event(Event::Destroyed(id));
+
+		Ok(())
+	}
+
+	pub fn do_freeze(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+		Self::ensure_live(id)?;
+
+		Frozen::<T>::insert(id, account, true);
+
+		Self::deposit_event(Event::AccountFrozen(id, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_thaw(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+
+		Frozen::<T>::remove(id, account);
+
+		Self::deposit_event(Event::AccountThawed(id, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_freeze_token(id: T::MultiTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+		Self::ensure_live(id)?;
+
+		FrozenTokens::<T>::insert(id, true);
+
+		Self::deposit_event(Event::TokenFrozen(id));
+
+		Ok(())
+	}
+
+	pub fn do_thaw_token(id: T::MultiTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+
+		FrozenTokens::<T>::remove(id);
+
+		Self::deposit_event(Event::TokenThawed(id));
+
+		Ok(())
+	}
+
+	fn ensure_not_frozen(id: T::MultiTokenId, who: &T::AccountId) -> DispatchResult {
+		ensure!(!Self::is_token_frozen(id), Error::<T>::TokenFrozen);
+		ensure!(!Self::is_frozen(id, who), Error::<T>::AccountFrozen);
+
+		Ok(())
+	}
+
+	pub fn do_set_royalty(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		token_id: Option<T::TokenId>,
+		receiver: T::AccountId,
+		basis_points: u16,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+		ensure!(basis_points <= 10_000, Error::<T>::InvalidRoyalty);
+
+		match token_id {
+			// A zero override still takes precedence over the collection royalty.
+			Some(token_id) =>
+				TokenRoyalties::<T>::insert(id, token_id, (receiver.clone(), basis_points)),
+			None => Royalties::<T>::set(
+				id,
+				Some((receiver.clone(), basis_points)).filter(|_| basis_points > 0),
+			),
+		}
+
+		Self::deposit_event(Event::RoyaltySet(id, token_id, receiver, basis_points));
+
+		Ok(())
+	}
+
+	pub fn do_clear_token_royalty(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+		ensure!(TokenRoyalties::<T>::contains_key(id, token_id), Error::<T>::NoTokenRoyalty);
+
+		TokenRoyalties::<T>::remove(id, token_id);
+
+		Self::deposit_event(Event::TokenRoyaltyCleared(id, token_id));
+
+		Ok(())
+	}
+
+	/// The royalty receiver and basis points of a token, falling back to the collection royalty.
+	pub fn royalty(id: T::MultiTokenId, token_id: T::TokenId) -> Option<(T::AccountId, u16)> {
+		TokenRoyalties::<T>::get(id, token_id).or_else(|| Royalties::<T>::get(id))
+	}
+
+	/// The royalty receiver and the amount owed to them for a sale at `sale_price`.
+	pub fn royalty_info<B: AtLeast32BitUnsigned>(
+		id: T::MultiTokenId,
+		token_id: T::TokenId,
+		sale_price: B,
+	) -> Option<(T::AccountId, B)> {
+		let (receiver, basis_points) = Self::royalty(id, token_id)?;
+		let royalty = Permill::from_parts(u32::from(basis_points) * 100).mul_floor(sale_price);
+		Some((receiver, royalty))
+	}
+
+	pub fn do_set_soulbound(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		token_id: Option<T::TokenId>,
+		soulbound: bool,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NotOwner);
+		Self::ensure_live(id)?;
+
+		match (token_id, soulbound) {
+			(Some(token_id), true) => SoulboundTokens::<T>::insert(id, token_id, true),
+			(Some(token_id), false) => SoulboundTokens::<T>::remove(id, token_id),
+			(None, true) => SoulboundCollections::<T>::insert(id, true),
+			(None, false) => SoulboundCollections::<T>::remove(id),
+		}
+
+		Self::deposit_event(Event::SoulboundSet(id, token_id, soulbound));
+
+		Ok(())
+	}
+
+	pub fn do_redeem_voucher(
+		who: &T::AccountId,
+		id: T::MultiTokenId,
+		voucher: MintVoucherOf<T>,
+		signature: [u8; 65],
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		Self::ensure_live(id)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= voucher.expiry,
+			Error::<T>::VoucherExpired
+		);
+		if let Some(recipient) = voucher.recipient {
+			ensure!(T::AddressMapping::convert(recipient) == *who, Error::<T>::NotVoucherRecipient);
+		}
+
+		let digest = Self::voucher_digest(id, &voucher);
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+			.map(|public| H160::from_slice(&keccak_256(&public)[12..]))
+			.map_err(|_| Error::<T>::InvalidSignature)?;
+		ensure!(
+			!Self::is_voucher_redeemed(id, (signer, voucher.nonce)),
+			Error::<T>::VoucherRedeemed
+		);
+
+		Self::do_mint(
+			&T::AddressMapping::convert(signer),
+			id,
+			who,
+			voucher.token_id,
+			voucher.amount,
+		)?;
+		if !voucher.price.is_zero() {
+			match voucher.currency {
+				Some(currency) => {
+					T::Fungibles::transfer(currency, who, &token.owner, voucher.price, false)?;
+				},
+				None => T::Currency::transfer(
+					who,
+					&token.owner,
+					voucher.price.saturated_into(),
+					ExistenceRequirement::AllowDeath,
+				)?,
+			}
+		}
+		RedeemedVouchers::<T>::insert(id, (signer, voucher.nonce), true);
+
+		Self::deposit_event(Event::VoucherRedeemed(
+			id,
+			voucher.token_id,
+			who.clone(),
+			voucher.amount,
+			signer,
+			voucher.nonce,
+		));
+
+		Ok(())
+	}
+
+	/// The EIP-712 domain separator of a token.
+	pub fn domain_separator(id: T::MultiTokenId) -> H256 {
+		let mut data = Vec::with_capacity(5 * 32);
+		data.extend_from_slice(&keccak_256(EIP712_DOMAIN_TYPE));
+		data.extend_from_slice(&keccak_256(VOUCHER_DOMAIN_NAME));
+		data.extend_from_slice(&keccak_256(VOUCHER_VERSION));
+		data.extend_from_slice(&Self::abi_encode_uint(T::ChainId::get().into()));
+		data.extend_from_slice(&Self::abi_encode_address(T::TokenIdConversion::into_address(id)));
+		H256::from(keccak_256(&data))
+	}
+
+	/// The EIP-712 digest a minter signs to issue a voucher.
+	pub fn voucher_digest(id: T::MultiTokenId, voucher: &MintVoucherOf<T>) -> [u8; 32] {
+		let token_id: u128 = voucher.token_id.unique_saturated_into();
+		let currency = voucher
+			.currency
+			.map(T::FungibleTokenIdConversion::into_address)
+			.unwrap_or_default();
+		let expiry: u128 = voucher.expiry.unique_saturated_into();
+		let mut data = Vec::with_capacity(8 * 32);
+		data.extend_from_slice(&keccak_256(MINT_VOUCHER_TYPE));
+		data.extend_from_slice(&Self::abi_encode_uint(token_id.into()));
+		data.extend_from_slice(&Self::abi_encode_uint(voucher.amount.into()));
+		data.extend_from_slice(&Self::abi_encode_address(voucher.recipient.unwrap_or_default()));
+		data.extend_from_slice(&Self::abi_encode_address(currency));
+		data.extend_from_slice(&Self::abi_encode_uint(voucher.price.into()));
+		data.extend_from_slice(&Self::abi_encode_uint(expiry.into()));
+		data.extend_from_slice(&Self::abi_encode_uint(voucher.nonce.into()));
+		let struct_hash = keccak_256(&data);
+
+		let mut message = Vec::with_capacity(2 + 2 * 32);
+		message.extend_from_slice(b"\x19\x01");
+		message.extend_from_slice(Self::domain_separator(id).as_bytes());
+		message.extend_from_slice(&struct_hash);
+		keccak_256(&message)
+	}
+
+	fn abi_encode_uint(value: U256) -> [u8; 32] {
+		let mut data = [0u8; 32];
+		value.to_big_endian(&mut data);
+		data
+	}
+
+	fn abi_encode_address(address: H160) -> [u8; 32] {
+		let mut data = [0u8; 32];
+		data[12..32].copy_from_slice(address.as_bytes());
+		data
+	}
+
+	/// Whether a token is bound to its holders, by its own flag or by its collection's.
+	pub fn is_soulbound(id: T::MultiTokenId, token_id: T::TokenId) -> bool {
+		Self::is_soulbound_collection(id) || Self::is_soulbound_token(id, token_id)
+	}
+
+	/// Lock `amount` more of the balance of `who` under `locker`, so that it can not be
+	/// transferred or burned until `locker` unlocks it.
+	///
+	/// The balance stays with `who`. Callers must check that `who` agreed to the lock.
+	pub fn lock(
+		id: T::MultiTokenId,
+		token_id: T::TokenId,
+		who: &T::AccountId,
+		locker: LockIdentifier,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+
+		LockedBalances::<T>::try_mutate(id, (token_id, who), |locked| -> DispatchResult {
+			let new_locked = locked.checked_add(amount).ok_or(Error::<T>::NumOverflow)?;
+			ensure!(
+				Balances::<T>::get(id, (token_id, who.clone())) >= new_locked,
+				Error::<T>::InsufficientTokens
+			);
+			*locked = new_locked;
+			Ok(())
+		})?;
+		Locks::<T>::mutate(id, (token_id, who, locker), |balance| {
+			*balance = balance.saturating_add(amount)
+		});
+
+		Self::deposit_event(Event::Locked(id, token_id, who.clone(), locker, amount));
+
+		Ok(())
+	}
+
+	/// Remove the lock `locker` put on the balance of `who`.
+	pub fn unlock(
+		id: T::MultiTokenId,
+		token_id: T::TokenId,
+		who: &T::AccountId,
+		locker: LockIdentifier,
+	) -> DispatchResult {
+		let amount = Locks::<T>::take(id, (token_id, who, locker));
+		ensure!(amount > 0, Error::<T>::NotLocked);
+
+		LockedBalances::<T>::mutate_exists(id, (token_id, who), |locked| {
+			*locked = locked.map(|l| l.saturating_sub(amount)).filter(|l| *l > 0)
+		});
+
+		Self::deposit_event(Event::Unlocked(id, token_id, who.clone(), locker, amount));
+
+		Ok(())
+	}
+
+	fn ensure_live(id: T::MultiTokenId) -> DispatchResult {
+		ensure!(!Self::is_destroying(id), Error::<T>::TokenDestroying);
+
+		Ok(())
+	}
+
+	/// The owner manages every role, admins manage every role but `Admin`.
+	fn ensure_role_admin(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(
+			*who == token.owner ||
+				(role != TokenRole::Admin && Self::has_role(id, (TokenRole::Admin, who))),
+			Error::<T>::NoPermission
+		);
+
+		Ok(())
+	}
+
+	fn ensure_owner_or_role(
+		id: T::MultiTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner || Self::has_role(id, (role, who)), Error::<T>::NoPermission);
+
+		Ok(())
+	}
+
+	/// Whether `who` can mint `id`, as its owner or a `Minter`.
+	pub fn has_permission(id: T::MultiTokenId, who: &T::AccountId) -> bool {
+		Tokens::<T>::get(id).map_or(false, |token| {
+			*who == token.owner || Self::has_role(id, (TokenRole::Minter, who))
+		})
 	}
 }
 
@@ -543,9 +1859,31 @@ where
 	type TokenId = T::TokenId;
 
 	fn uri(id: Self::MultiTokenId, token_id: T::TokenId) -> Vec<u8> {
+		if let Some(uri) = Self::token_id_info(id, token_id).and_then(|info| info.uri) {
+			return uri.to_vec()
+		}
 		let base_uri_buf: Vec<u8> = Tokens::<T>::get(id).unwrap().uri.to_vec();
 		let token_id: u128 = token_id.into();
 		let token_id_buf: Vec<u8> = token_id.to_string().as_bytes().to_vec();
 		base_uri_buf.into_iter().chain(token_id_buf).collect::<Vec<_>>()
 	}
 }
+
+impl<T: Config> MultiTokenTransfer<T::AccountId> for Pallet<T> {
+	type MultiTokenId = T::MultiTokenId;
+	type TokenId = T::TokenId;
+
+	fn balance_of(id: Self::MultiTokenId, token_id: Self::TokenId, who: &T::AccountId) -> Balance {
+		Balances::<T>::get(id, (token_id, who))
+	}
+
+	fn transfer(
+		id: Self::MultiTokenId,
+		token_id: Self::TokenId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::do_transfer_from(from, id, from, to, token_id, amount)
+	}
+}
@@ -0,0 +1,137 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the multi token pallet.
+
+use super::*;
+use frame_support::{
+	traits::{GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+use sp_std::collections::btree_map::BTreeMap;
+
+/// Rebuilds the supply of every token id from the balances minted before `TokenIds` was tracked.
+pub mod v1 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		let mut supplies = BTreeMap::<(T::MultiTokenId, T::TokenId), Balance>::new();
+		for (id, (token_id, _), balance) in Balances::<T>::iter() {
+			reads.saturating_inc();
+			if !balance.is_zero() {
+				let supply = supplies.entry((id, token_id)).or_default();
+				*supply = supply.saturating_add(balance);
+			}
+		}
+
+		for (id, token_id, mut info) in TokenIds::<T>::iter() {
+			reads.saturating_inc();
+			if !supplies.contains_key(&(id, token_id)) {
+				info.supply = Zero::zero();
+				TokenIds::<T>::insert(id, token_id, info);
+				writes.saturating_inc();
+			}
+		}
+		for ((id, token_id), supply) in supplies {
+			TokenIds::<T>::mutate(id, token_id, |maybe_info| {
+				maybe_info.get_or_insert_with(Default::default).supply = supply;
+			});
+			reads.saturating_inc();
+			writes.saturating_inc();
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+/// Indexes the balances held before inventories were tracked, free of deposit.
+pub mod v2 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		for (id, (token_id, who), balance) in Balances::<T>::iter() {
+			reads.saturating_inc();
+			if balance.is_zero() || Inventory::<T>::contains_key((id, &who, token_id)) {
+				continue
+			}
+
+			let size = InventorySize::<T>::get(id, &who);
+			reads.saturating_accrue(2);
+			if size >= T::MaxInventorySize::get() {
+				Unindexed::<T>::mutate(id, &who, |count| *count = count.saturating_add(1));
+				reads.saturating_inc();
+				writes.saturating_inc();
+				continue
+			}
+
+			Inventory::<T>::insert((id, &who, token_id), (who.clone(), BalanceOf::<T>::zero()));
+			InventorySize::<T>::insert(id, &who, size + 1);
+			writes.saturating_accrue(2);
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+/// Counts the supply of every token and token id as minted, so burns made from now on no longer
+/// free room under the max supply.
+pub mod v3 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 3 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		for (id, token) in Tokens::<T>::iter() {
+			Minted::<T>::insert(id, token.total_supply);
+			reads.saturating_inc();
+			writes.saturating_inc();
+		}
+		for (id, token_id, info) in TokenIds::<T>::iter() {
+			reads.saturating_inc();
+			if !info.supply.is_zero() {
+				TokenIdMinted::<T>::insert(id, token_id, info.supply);
+				writes.saturating_inc();
+			}
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -19,15 +19,18 @@
 use crate as web3games_token_multi;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
 	PalletId,
 };
 use primitives::Balance;
-use sp_core::H256;
+use sp_core::{H160, H256};
+use sp_keystore::{testing::KeyStore, KeystoreExt};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Convert, IdentityLookup},
 };
+use std::sync::Arc;
+use web3games_support::TokenIdConversion;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -45,6 +48,8 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		TokenFungible: web3games_token_fungible::{Pallet, Call, Storage, Event<T>},
 		TokenMulti: web3games_token_multi::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -92,10 +97,54 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+pub struct MockAddressMapping;
+impl Convert<H160, u64> for MockAddressMapping {
+	fn convert(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+pub struct MockTokenIdConversion;
+impl TokenIdConversion<u32> for MockTokenIdConversion {
+	fn try_from_address(address: H160) -> Option<u32> {
+		Some(address.to_low_u64_be() as u32)
+	}
+
+	fn into_address(id: u32) -> H160 {
+		H160::from_low_u64_be(id.into())
+	}
+}
+
 parameter_types! {
+	pub const TokenFungiblePalletId: PalletId = PalletId(*b"w3g/tfpi");
 	pub const TokenMultiPalletId: PalletId = PalletId(*b"w3g/tmpi");
 	pub const StringLimit: u32 = 50;
 	pub const CreateTokenDeposit: Balance = 500 * MILLICENTS;
+	pub const InventoryDeposit: Balance = 1 * MILLICENTS;
+}
+
+impl web3games_token_fungible::Config for Test {
+	type Event = Event;
+	type PalletId = TokenFungiblePalletId;
+	type FungibleTokenId = u32;
+	type StringLimit = StringLimit;
+	type CreateTokenDeposit = CreateTokenDeposit;
+	type Currency = Balances;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type UnixTime = Timestamp;
+	type MaxVestingSchedules = ConstU32<8>;
+	type MinVestedTransfer = ConstU128<10>;
+	type RemoveItemsLimit = ConstU32<2>;
+	type WeightInfo = ();
 }
 
 impl web3games_token_multi::Config for Test {
@@ -106,6 +155,14 @@ impl web3games_token_multi::Config for Test {
 	type StringLimit = StringLimit;
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type Currency = Balances;
+	type Fungibles = TokenFungible;
+	type FungibleTokenIdConversion = MockTokenIdConversion;
+	type ChainId = ConstU64<42>;
+	type AddressMapping = MockAddressMapping;
+	type TokenIdConversion = MockTokenIdConversion;
+	type MaxInventorySize = ConstU32<5>;
+	type InventoryDeposit = InventoryDeposit;
+	type RemoveItemsLimit = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -116,5 +173,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
-	t.into()
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
+	ext
 }
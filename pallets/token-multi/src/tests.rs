@@ -19,9 +19,11 @@
 use super::*;
 use crate::mock::*;
 use frame_support::{assert_noop, assert_ok};
+use sp_core::{ecdsa, Pair};
 
 const ALICE: u64 = 1;
 const BOB: u64 = 2;
+const CHARLIE: u64 = 3;
 
 #[test]
 fn create_token_should_work() {
@@ -331,3 +333,629 @@ fn batch_transfer_from_should_work() {
 		assert_eq!(TokenMulti::balance_of(1, (3, BOB)), 50);
 	})
 }
+
+#[test]
+fn roles_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::grant_role(Origin::signed(ALICE), 1, TokenRole::Admin, BOB));
+		assert_ok!(TokenMulti::grant_role(Origin::signed(BOB), 1, TokenRole::Minter, CHARLIE));
+		assert_noop!(
+			TokenMulti::grant_role(Origin::signed(BOB), 1, TokenRole::Admin, CHARLIE),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(TokenMulti::mint(Origin::signed(CHARLIE), 1, BOB, 1, 100));
+		assert_eq!(TokenMulti::balance_of(1, (1, BOB)), 100);
+
+		assert_noop!(
+			TokenMulti::burn_from(Origin::signed(CHARLIE), 1, BOB, 1, 40),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenMulti::grant_role(Origin::signed(BOB), 1, TokenRole::Burner, CHARLIE));
+		assert_ok!(TokenMulti::burn_from(Origin::signed(CHARLIE), 1, BOB, 1, 40));
+		assert_eq!(TokenMulti::balance_of(1, (1, BOB)), 60);
+
+		assert_ok!(TokenMulti::revoke_role(Origin::signed(BOB), 1, TokenRole::Minter, CHARLIE));
+		assert_noop!(
+			TokenMulti::mint(Origin::signed(CHARLIE), 1, BOB, 1, 100),
+			Error::<Test>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn transfer_ownership_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_noop!(
+			TokenMulti::transfer_ownership(Origin::signed(BOB), 1, BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TokenMulti::transfer_ownership(Origin::signed(ALICE), 1, BOB));
+
+		assert_noop!(
+			TokenMulti::mint(Origin::signed(ALICE), 1, ALICE, 1, 100),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenMulti::mint(Origin::signed(BOB), 1, ALICE, 1, 100));
+	})
+}
+
+#[test]
+fn destroy_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&ALICE), CreateTokenDeposit::get());
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, ALICE, 1, 100));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 2, 100));
+		assert_ok!(TokenMulti::set_approval_for_all(Origin::signed(BOB), 1, CHARLIE, true));
+
+		assert_noop!(TokenMulti::start_destroy(Origin::signed(BOB), 1), Error::<Test>::NotOwner);
+		assert_ok!(TokenMulti::start_destroy(Origin::signed(ALICE), 1));
+		assert_noop!(
+			TokenMulti::transfer_from(Origin::signed(ALICE), 1, ALICE, BOB, 1, 10),
+			Error::<Test>::TokenDestroying
+		);
+		assert_noop!(
+			TokenMulti::mint(Origin::signed(ALICE), 1, ALICE, 1, 10),
+			Error::<Test>::TokenDestroying
+		);
+
+		// Two balances, one approval, two token ids, two inventory sizes and two inventory
+		// entries, removed two at a time.
+		for removed in [2, 2, 2, 2, 1] {
+			assert_noop!(
+				TokenMulti::finish_destroy(Origin::signed(BOB), 1),
+				Error::<Test>::AccountsRemaining
+			);
+			assert_ok!(TokenMulti::destroy_accounts(Origin::signed(BOB), 1));
+			System::assert_last_event(crate::mock::Event::TokenMulti(
+				crate::Event::AccountsDestroyed(1, removed),
+			));
+		}
+		assert_ok!(TokenMulti::finish_destroy(Origin::signed(BOB), 1));
+
+		assert_eq!(TokenMulti::exists(1), false);
+		assert_eq!(TokenMulti::balance_of(1, (2, BOB)), 0);
+		assert_eq!(TokenMulti::is_approved_for_all(1, (BOB, CHARLIE)), false);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&ALICE), 0);
+	})
+}
+
+#[test]
+fn freeze_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 100));
+		assert_noop!(
+			TokenMulti::freeze(Origin::signed(CHARLIE), 1, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenMulti::grant_role(Origin::signed(ALICE), 1, TokenRole::Freezer, CHARLIE));
+
+		assert_ok!(TokenMulti::freeze(Origin::signed(CHARLIE), 1, BOB));
+		assert_eq!(TokenMulti::is_frozen(1, BOB), true);
+		assert_noop!(
+			TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 1, 10),
+			Error::<Test>::AccountFrozen
+		);
+		assert_noop!(
+			TokenMulti::batch_transfer_from(Origin::signed(BOB), 1, BOB, ALICE, vec![1], vec![10]),
+			Error::<Test>::AccountFrozen
+		);
+		assert_ok!(TokenMulti::thaw(Origin::signed(CHARLIE), 1, BOB));
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 1, 10));
+
+		assert_ok!(TokenMulti::freeze_token(Origin::signed(ALICE), 1));
+		assert_noop!(
+			TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 1, 10),
+			Error::<Test>::TokenFrozen
+		);
+		assert_ok!(TokenMulti::thaw_token(Origin::signed(CHARLIE), 1));
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 1, 10));
+	})
+}
+
+#[test]
+fn royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_noop!(
+			TokenMulti::set_royalty(Origin::signed(BOB), 1, None, BOB, 500),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			TokenMulti::set_royalty(Origin::signed(ALICE), 1, None, ALICE, 10_001),
+			Error::<Test>::InvalidRoyalty
+		);
+
+		assert_ok!(TokenMulti::set_royalty(Origin::signed(ALICE), 1, None, ALICE, 500));
+		assert_eq!(TokenMulti::royalty_info(1, 1, 1_000u128), Some((ALICE, 50)));
+		assert_ok!(TokenMulti::set_royalty(Origin::signed(ALICE), 1, Some(1), CHARLIE, 250));
+		assert_eq!(TokenMulti::royalty_info(1, 1, 1_000u128), Some((CHARLIE, 25)));
+		assert_eq!(TokenMulti::royalty_info(1, 2, 1_000u128), Some((ALICE, 50)));
+
+		// a zero override waives the collection royalty for that token only
+		assert_ok!(TokenMulti::set_royalty(Origin::signed(ALICE), 1, Some(1), CHARLIE, 0));
+		assert_eq!(TokenMulti::royalty_info(1, 1, 1_000u128), Some((CHARLIE, 0)));
+		assert_eq!(TokenMulti::royalty_info(1, 2, 1_000u128), Some((ALICE, 50)));
+
+		assert_noop!(
+			TokenMulti::clear_token_royalty(Origin::signed(BOB), 1, 1),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(TokenMulti::clear_token_royalty(Origin::signed(ALICE), 1, 1));
+		assert_eq!(TokenMulti::royalty_info(1, 1, 1_000u128), Some((ALICE, 50)));
+		assert_noop!(
+			TokenMulti::clear_token_royalty(Origin::signed(ALICE), 1, 1),
+			Error::<Test>::NoTokenRoyalty
+		);
+	})
+}
+
+#[test]
+fn soulbound_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 100));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 2, 100));
+		assert_noop!(
+			TokenMulti::set_soulbound(Origin::signed(BOB), 1, Some(1), true),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(TokenMulti::set_soulbound(Origin::signed(ALICE), 1, Some(1), true));
+		assert_noop!(
+			TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1, 10),
+			Error::<Test>::Soulbound
+		);
+		assert_noop!(
+			TokenMulti::batch_transfer_from(
+				Origin::signed(BOB),
+				1,
+				BOB,
+				CHARLIE,
+				vec![1, 2],
+				vec![10, 10]
+			),
+			Error::<Test>::Soulbound
+		);
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 2, 10));
+
+		assert_ok!(TokenMulti::burn(Origin::signed(BOB), 1, 1, 10));
+		assert_ok!(TokenMulti::burn_from(Origin::signed(ALICE), 1, BOB, 1, 10));
+		assert_eq!(TokenMulti::balance_of(1, (1, BOB)), 80);
+
+		assert_ok!(TokenMulti::set_soulbound(Origin::signed(ALICE), 1, Some(1), false));
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1, 10));
+	})
+}
+
+#[test]
+fn lock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 100));
+		assert_noop!(
+			TokenMulti::lock(1, 1, &BOB, *b"staking ", 101),
+			Error::<Test>::InsufficientTokens
+		);
+
+		assert_ok!(TokenMulti::lock(1, 1, &BOB, *b"staking ", 60));
+		assert_ok!(TokenMulti::lock(1, 1, &BOB, *b"market  ", 20));
+		assert_eq!(TokenMulti::locked_balance_of(1, (1, BOB)), 80);
+		assert_noop!(
+			TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1, 30),
+			Error::<Test>::TokenLocked
+		);
+		assert_noop!(TokenMulti::burn(Origin::signed(BOB), 1, 1, 30), Error::<Test>::TokenLocked);
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1, 20));
+
+		assert_noop!(TokenMulti::unlock(1, 1, &CHARLIE, *b"staking "), Error::<Test>::NotLocked);
+		assert_ok!(TokenMulti::unlock(1, 1, &BOB, *b"staking "));
+		assert_eq!(TokenMulti::locked_balance_of(1, (1, BOB)), 20);
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1, 60));
+	})
+}
+
+fn evm_address(pair: &ecdsa::Pair) -> H160 {
+	let message = [0u8; 32];
+	let signature = pair.sign_prehashed(&message);
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message).unwrap();
+	H160::from_slice(&keccak_256(&public)[12..])
+}
+
+#[test]
+fn redeem_voucher_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let signer = evm_address(&pair);
+		assert_ok!(TokenMulti::grant_role(
+			Origin::signed(ALICE),
+			1,
+			TokenRole::Minter,
+			MockAddressMapping::convert(signer)
+		));
+
+		let voucher = MintVoucher {
+			token_id: 1,
+			amount: 10,
+			recipient: None,
+			currency: None,
+			price: 5 * DOLLARS,
+			expiry: 10,
+			nonce: 0,
+		};
+		let signature = pair.sign_prehashed(&TokenMulti::voucher_digest(1, &voucher)).0;
+		let alice_balance = <Test as Config>::Currency::free_balance(ALICE);
+		assert_ok!(TokenMulti::redeem_voucher(Origin::signed(BOB), 1, voucher.clone(), signature));
+		assert_eq!(TokenMulti::balance_of(1, (1, BOB)), 10);
+		assert_eq!(<Test as Config>::Currency::free_balance(ALICE), alice_balance + 5 * DOLLARS);
+		assert!(TokenMulti::is_voucher_redeemed(1, (signer, 0)));
+		assert_noop!(
+			TokenMulti::redeem_voucher(Origin::signed(BOB), 1, voucher, signature),
+			Error::<Test>::VoucherRedeemed
+		);
+
+		let voucher = MintVoucher {
+			token_id: 2,
+			amount: 10,
+			recipient: Some(H160::from_low_u64_be(CHARLIE)),
+			currency: None,
+			price: 0,
+			expiry: 10,
+			nonce: 1,
+		};
+		let signature = pair.sign_prehashed(&TokenMulti::voucher_digest(1, &voucher)).0;
+		assert_noop!(
+			TokenMulti::redeem_voucher(Origin::signed(BOB), 1, voucher.clone(), signature),
+			Error::<Test>::NotVoucherRecipient
+		);
+		assert_ok!(TokenMulti::redeem_voucher(Origin::signed(CHARLIE), 1, voucher, signature));
+		assert_eq!(TokenMulti::balance_of(1, (2, CHARLIE)), 10);
+
+		let other = ecdsa::Pair::from_seed(&[2u8; 32]);
+		let voucher = MintVoucher {
+			token_id: 3,
+			amount: 10,
+			recipient: None,
+			currency: None,
+			price: 0,
+			expiry: 10,
+			nonce: 2,
+		};
+		let signature = other.sign_prehashed(&TokenMulti::voucher_digest(1, &voucher)).0;
+		assert_noop!(
+			TokenMulti::redeem_voucher(Origin::signed(BOB), 1, voucher, signature),
+			Error::<Test>::NoPermission
+		);
+
+		System::set_block_number(11);
+		let voucher = MintVoucher {
+			token_id: 3,
+			amount: 10,
+			recipient: None,
+			currency: None,
+			price: 0,
+			expiry: 10,
+			nonce: 3,
+		};
+		let signature = pair.sign_prehashed(&TokenMulti::voucher_digest(1, &voucher)).0;
+		assert_noop!(
+			TokenMulti::redeem_voucher(Origin::signed(BOB), 1, voucher, signature),
+			Error::<Test>::VoucherExpired
+		);
+	})
+}
+
+#[test]
+fn max_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 100));
+
+		assert_noop!(
+			TokenMulti::set_max_supply(Origin::signed(BOB), 1, 200),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			TokenMulti::set_max_supply(Origin::signed(ALICE), 1, 99),
+			Error::<Test>::MaxSupplyTooLow
+		);
+		assert_ok!(TokenMulti::set_max_supply(Origin::signed(ALICE), 1, 200));
+		assert_eq!(TokenMulti::max_supply(1), Some(200));
+		assert_noop!(
+			TokenMulti::set_max_supply(Origin::signed(ALICE), 1, 300),
+			Error::<Test>::MaxSupplyAlreadySet
+		);
+
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 2, 50));
+		assert_noop!(
+			TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 2, 51),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_noop!(
+			TokenMulti::mint_batch(Origin::signed(ALICE), 1, BOB, vec![1, 2], vec![25, 26]),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_ok!(TokenMulti::mint_batch(Origin::signed(ALICE), 1, BOB, vec![1, 2], vec![25, 25]));
+
+		// Burned tokens don't free room under the cap.
+		assert_ok!(TokenMulti::burn(Origin::signed(BOB), 1, 1, 10));
+		assert_eq!(TokenMulti::minted(1), 200);
+		assert_noop!(
+			TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 1),
+			Error::<Test>::MaxSupplyExceeded
+		);
+	})
+}
+
+#[test]
+fn token_id_registry_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 100));
+		assert_ok!(TokenMulti::mint_batch(Origin::signed(ALICE), 1, BOB, vec![1, 2], vec![10, 20]));
+		assert_eq!(TokenMulti::token_id_supply(1, 1), 110);
+		assert_eq!(TokenMulti::token_id_supply(1, 2), 20);
+		assert!(!TokenMulti::token_id_exists(1, 3));
+
+		assert_ok!(TokenMulti::burn(Origin::signed(BOB), 1, 1, 10));
+		assert_ok!(TokenMulti::burn_batch(Origin::signed(BOB), 1, vec![1, 2], vec![50, 20]));
+		assert_eq!(TokenMulti::token_id_supply(1, 1), 50);
+		assert!(!TokenMulti::token_id_exists(1, 2));
+
+		assert_noop!(
+			TokenMulti::register_token_id(Origin::signed(BOB), 1, 1, Some(100), false),
+			Error::<Test>::NotOwner
+		);
+		// The cap counts the burned tokens too.
+		assert_eq!(TokenMulti::token_id_minted(1, 1), 110);
+		assert_noop!(
+			TokenMulti::register_token_id(Origin::signed(ALICE), 1, 1, Some(109), false),
+			Error::<Test>::MaxSupplyTooLow
+		);
+		assert_ok!(TokenMulti::register_token_id(Origin::signed(ALICE), 1, 1, Some(120), false));
+		assert_noop!(
+			TokenMulti::register_token_id(Origin::signed(ALICE), 1, 1, Some(130), false),
+			Error::<Test>::TokenIdAlreadyRegistered
+		);
+		assert_noop!(
+			TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 11),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 10));
+		assert_eq!(TokenMulti::token_id_supply(1, 1), 60);
+
+		assert_ok!(TokenMulti::register_token_id(Origin::signed(ALICE), 1, 3, None, true));
+		assert_eq!(TokenMulti::token_id_info(1, 3).unwrap().max_supply, Some(1));
+		assert_noop!(
+			TokenMulti::mint_batch(Origin::signed(ALICE), 1, BOB, vec![3, 3], vec![1, 1]),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 3, 1));
+
+		assert_eq!(TokenMulti::uri(1, 3), b"https://web3games.com/3".to_vec());
+		assert_noop!(
+			TokenMulti::set_token_uri(Origin::signed(BOB), 1, 3, b"ipfs://item".to_vec()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenMulti::set_token_uri(Origin::signed(ALICE), 1, 3, b"ipfs://item".to_vec()));
+		assert_eq!(TokenMulti::uri(1, 3), b"ipfs://item".to_vec());
+		assert_ok!(TokenMulti::set_token_uri(Origin::signed(ALICE), 1, 3, vec![]));
+		assert_eq!(TokenMulti::uri(1, 3), b"https://web3games.com/3".to_vec());
+	})
+}
+
+#[test]
+fn token_id_supply_migration_should_work() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, ALICE, 1, 100));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 30));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 2, 20));
+		assert_ok!(TokenMulti::register_token_id(Origin::signed(ALICE), 1, 3, Some(10), false));
+
+		// Balances minted before token ids were recorded.
+		TokenIds::<Test>::remove(1, 1);
+		TokenIds::<Test>::remove(1, 2);
+		StorageVersion::new(0).put::<TokenMulti>();
+
+		migrations::v1::migrate::<Test>();
+		assert_eq!(TokenMulti::token_id_supply(1, 1), 130);
+		assert_eq!(TokenMulti::token_id_supply(1, 2), 20);
+		assert_eq!(TokenMulti::token_id_info(1, 3).unwrap().max_supply, Some(10));
+		assert!(!TokenMulti::token_id_exists(1, 3));
+		assert_eq!(TokenMulti::on_chain_storage_version(), 1);
+	})
+}
+
+#[test]
+fn inventory_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint_batch(
+			Origin::signed(ALICE),
+			1,
+			BOB,
+			vec![1, 2, 3, 4, 5],
+			vec![10u128; 5]
+		));
+		assert_eq!(TokenMulti::inventory_size(1, BOB), 5);
+		// The minter pays the deposits, not the holder.
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&ALICE),
+			CreateTokenDeposit::get() + 5 * InventoryDeposit::get()
+		);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&BOB), 0);
+
+		// A full inventory counts the token id as unindexed without failing the mint.
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 6, 10));
+		assert_eq!(TokenMulti::balance_of(1, (6, BOB)), 10);
+		assert_eq!(TokenMulti::unindexed_count(1, BOB), 1);
+		assert_ok!(TokenMulti::burn(Origin::signed(BOB), 1, 6, 10));
+		assert_eq!(TokenMulti::unindexed_count(1, BOB), 0);
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 6, 10));
+		assert_ok!(TokenMulti::mint(Origin::signed(ALICE), 1, BOB, 1, 10));
+		assert_eq!(TokenMulti::inventory_size(1, BOB), 5);
+		assert_eq!(TokenMulti::unindexed_count(1, BOB), 1);
+
+		let first_page = TokenMulti::inventory_of(1, BOB, None, 2);
+		let second_page = TokenMulti::inventory_of(1, BOB, Some(first_page[1].0), 10);
+		assert_eq!(first_page.len(), 2);
+		assert_eq!(second_page.len(), 3);
+		let mut inventory = [first_page, second_page].concat();
+		inventory.sort();
+		assert_eq!(inventory, vec![(1, 20), (2, 10), (3, 10), (4, 10), (5, 10)]);
+
+		// The sender pays for the entries it creates and the depositor gets the deposit back.
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 2, 10));
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 1, 5));
+		assert_ok!(TokenMulti::burn(Origin::signed(BOB), 1, 3, 10));
+		assert_eq!(TokenMulti::inventory_size(1, BOB), 3);
+		assert_eq!(TokenMulti::inventory_size(1, ALICE), 2);
+		assert_eq!(TokenMulti::inventory_of(1, ALICE, None, 1).len(), 1);
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&ALICE),
+			CreateTokenDeposit::get() + 3 * InventoryDeposit::get()
+		);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&BOB), 2 * InventoryDeposit::get());
+
+		// The holder can index a token id themselves once there is room.
+		assert_noop!(
+			TokenMulti::index_token_id(Origin::signed(BOB), 1, 4),
+			Error::<Test>::AlreadyIndexed
+		);
+		assert_noop!(
+			TokenMulti::index_token_id(Origin::signed(BOB), 1, 3),
+			Error::<Test>::InsufficientTokens
+		);
+		assert_ok!(TokenMulti::index_token_id(Origin::signed(BOB), 1, 6));
+		assert_eq!(TokenMulti::inventory_size(1, BOB), 4);
+		assert_eq!(TokenMulti::unindexed_count(1, BOB), 0);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&BOB), 3 * InventoryDeposit::get());
+
+		// Charlie's funds are never touched, and a sender without funds leaves it unindexed.
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 4, 10));
+		assert_eq!(TokenMulti::inventory_size(1, CHARLIE), 1);
+		assert_ok!(TokenMulti::transfer_from(Origin::signed(CHARLIE), 1, CHARLIE, ALICE, 4, 10));
+		assert_eq!(TokenMulti::balance_of(1, (4, ALICE)), 10);
+		assert_eq!(TokenMulti::inventory_size(1, ALICE), 2);
+		assert_eq!(TokenMulti::unindexed_count(1, ALICE), 1);
+		assert_ok!(TokenMulti::index_token_id(Origin::signed(ALICE), 1, 4));
+		assert_eq!(TokenMulti::unindexed_count(1, ALICE), 0);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&BOB), 3 * InventoryDeposit::get());
+	})
+}
+
+#[test]
+fn inventory_migration_should_work() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint_batch(
+			Origin::signed(ALICE),
+			1,
+			BOB,
+			vec![1, 2, 3, 4, 5, 6, 7],
+			vec![10u128; 7]
+		));
+		assert_ok!(TokenMulti::burn(Origin::signed(BOB), 1, 2, 10));
+
+		// Balances from before inventories were indexed.
+		let _ = Inventory::<Test>::clear_prefix((1,), u32::MAX, None);
+		let _ = InventorySize::<Test>::clear_prefix(1, u32::MAX, None);
+		let _ = Unindexed::<Test>::clear_prefix(1, u32::MAX, None);
+		StorageVersion::new(1).put::<TokenMulti>();
+
+		migrations::v2::migrate::<Test>();
+		assert_eq!(TokenMulti::inventory_size(1, BOB), 5);
+		assert_eq!(TokenMulti::inventory_of(1, BOB, None, 10).len(), 5);
+		assert_eq!(TokenMulti::unindexed_count(1, BOB), 1);
+		assert_eq!(TokenMulti::on_chain_storage_version(), 2);
+	})
+}
+
+#[test]
+fn minted_migration_should_work() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint_batch(Origin::signed(ALICE), 1, BOB, vec![1, 2], vec![30, 20]));
+		assert_ok!(TokenMulti::burn(Origin::signed(BOB), 1, 1, 10));
+
+		// Supplies from before mints were counted.
+		Minted::<Test>::remove(1);
+		let _ = TokenIdMinted::<Test>::clear_prefix(1, u32::MAX, None);
+		StorageVersion::new(2).put::<TokenMulti>();
+
+		migrations::v3::migrate::<Test>();
+		assert_eq!(TokenMulti::minted(1), 40);
+		assert_eq!(TokenMulti::token_id_minted(1, 1), 20);
+		assert_eq!(TokenMulti::token_id_minted(1, 2), 20);
+		assert_eq!(TokenMulti::on_chain_storage_version(), 3);
+	})
+}
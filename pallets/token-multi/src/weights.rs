@@ -54,30 +54,65 @@ pub trait WeightInfo {
 	fn burn_batch() -> Weight;
 	fn transfer_from() -> Weight;
 	fn batch_transfer_from() -> Weight;
+	fn burn_from() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn start_destroy() -> Weight;
+	fn destroy_accounts(c: u32, ) -> Weight;
+	fn finish_destroy() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn freeze_token() -> Weight;
+	fn thaw_token() -> Weight;
+	fn set_royalty() -> Weight;
+	fn clear_token_royalty() -> Weight;
+	fn set_soulbound() -> Weight;
+	fn redeem_voucher() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn register_token_id() -> Weight;
+	fn set_token_uri() -> Weight;
+	fn index_token_id() -> Weight;
 }
 
 /// Weights for web3games_token_multi using the Web3Games node and recommended hardware.
 pub struct W3GWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: TokenMulti Tokens (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti Deposits (r:0 w:1)
 	fn create_token() -> Weight {
-		(15_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: TokenMulti Tokens (r:1 w:1)
 	// Storage: TokenMulti Balances (r:1 w:1)
+	// Storage: TokenMulti MaxSupply (r:1 w:0)
+	// Storage: TokenMulti Minted (r:1 w:1)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti TokenIdMinted (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:0 w:1)
 	fn mint() -> Weight {
 		(20_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	// Storage: TokenMulti Tokens (r:1 w:1)
 	// Storage: TokenMulti Balances (r:5 w:5)
+	// Storage: TokenMulti MaxSupply (r:1 w:0)
+	// Storage: TokenMulti Minted (r:1 w:1)
+	// Storage: TokenMulti TokenIds (r:5 w:5)
+	// Storage: TokenMulti TokenIdMinted (r:5 w:5)
+	// Storage: TokenMulti InventorySize (r:5 w:5)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:0 w:5)
 	fn mint_batch() -> Weight {
 		(38_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(24 as Weight))
+			.saturating_add(T::DbWeight::get().writes(28 as Weight))
 	}
 	// Storage: TokenMulti Tokens (r:1 w:0)
 	// Storage: TokenMulti OperatorApprovals (r:0 w:1)
@@ -88,48 +123,291 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	}
 	// Storage: TokenMulti Tokens (r:1 w:1)
 	// Storage: TokenMulti Balances (r:1 w:1)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
 	fn burn() -> Weight {
 		(19_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
 	// Storage: TokenMulti Tokens (r:1 w:1)
 	// Storage: TokenMulti Balances (r:5 w:5)
+	// Storage: TokenMulti LockedBalances (r:5 w:0)
+	// Storage: TokenMulti TokenIds (r:5 w:5)
+	// Storage: TokenMulti Inventory (r:5 w:5)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:5 w:5)
 	fn burn_batch() -> Weight {
 		(41_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(27 as Weight))
+			.saturating_add(T::DbWeight::get().writes(22 as Weight))
 	}
 	// Storage: TokenMulti Balances (r:2 w:2)
+	// Storage: TokenMulti SoulboundCollections (r:1 w:0)
+	// Storage: TokenMulti SoulboundTokens (r:1 w:0)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenMulti Inventory (r:1 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:2 w:2)
+	// Storage: TokenMulti Tokens (r:1 w:0)
 	fn transfer_from() -> Weight {
 		(21_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
 	// Storage: TokenMulti Balances (r:10 w:10)
+	// Storage: TokenMulti SoulboundCollections (r:1 w:0)
+	// Storage: TokenMulti SoulboundTokens (r:5 w:0)
+	// Storage: TokenMulti LockedBalances (r:5 w:0)
+	// Storage: TokenMulti Inventory (r:5 w:10)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:2 w:2)
+	// Storage: TokenMulti Tokens (r:1 w:0)
 	fn batch_transfer_from() -> Weight {
 		(56_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads(30 as Weight))
+			.saturating_add(T::DbWeight::get().writes(23 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:1)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti Balances (r:1 w:1)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn burn_from() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn grant_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn revoke_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn transfer_ownership() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti Balances (r:1 w:1)
+	// Storage: TokenMulti OperatorApprovals (r:1 w:1)
+	// Storage: TokenMulti Roles (r:1 w:1)
+	// Storage: TokenMulti Frozen (r:1 w:1)
+	// Storage: TokenMulti TokenRoyalties (r:1 w:1)
+	// Storage: TokenMulti SoulboundTokens (r:1 w:1)
+	// Storage: TokenMulti Locks (r:1 w:1)
+	// Storage: TokenMulti LockedBalances (r:1 w:1)
+	// Storage: TokenMulti RedeemedVouchers (r:1 w:1)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn destroy_accounts(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: TokenMulti Destroying (r:1 w:1)
+	// Storage: TokenMulti Balances (r:1 w:0)
+	// Storage: TokenMulti OperatorApprovals (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti Frozen (r:1 w:0)
+	// Storage: TokenMulti Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti Tokens (r:0 w:1)
+	// Storage: TokenMulti FrozenTokens (r:0 w:1)
+	// Storage: TokenMulti TokenRoyalties (r:1 w:0)
+	// Storage: TokenMulti Royalties (r:0 w:1)
+	// Storage: TokenMulti SoulboundTokens (r:1 w:0)
+	// Storage: TokenMulti SoulboundCollections (r:0 w:1)
+	// Storage: TokenMulti Locks (r:1 w:0)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenMulti RedeemedVouchers (r:1 w:0)
+	// Storage: TokenMulti MaxSupply (r:0 w:1)
+	// Storage: TokenMulti Minted (r:0 w:1)
+	// Storage: TokenMulti TokenIds (r:1 w:0)
+	// Storage: TokenMulti TokenIdMinted (r:1 w:0)
+	// Storage: TokenMulti Inventory (r:1 w:0)
+	// Storage: TokenMulti InventorySize (r:1 w:0)
+	// Estimated, not benchmarked yet.
+	fn finish_destroy() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti Frozen (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn freeze() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti Frozen (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn thaw() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti FrozenTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn freeze_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti FrozenTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn thaw_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti TokenRoyalties (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_royalty() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti TokenRoyalties (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn clear_token_royalty() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti SoulboundTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_soulbound() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:1)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti RedeemedVouchers (r:1 w:1)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti Balances (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: TokenMulti MaxSupply (r:1 w:0)
+	// Storage: TokenMulti Minted (r:1 w:1)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti TokenIdMinted (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Storage: TokenMulti Inventory (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn redeem_voucher() -> Weight {
+		(92_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().writes(10 as Weight))
 	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti MaxSupply (r:1 w:1)
+	// Storage: TokenMulti Minted (r:1 w:0)
+	// Estimated, not benchmarked yet.
+	fn set_max_supply() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Storage: TokenMulti TokenIdMinted (r:1 w:0)
+	// Estimated, not benchmarked yet.
+	fn register_token_id() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Tokens (r:1 w:0)
+	// Storage: TokenMulti Roles (r:1 w:0)
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti TokenIds (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_token_uri() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenMulti Destroying (r:1 w:0)
+	// Storage: TokenMulti Balances (r:1 w:0)
+	// Storage: TokenMulti Inventory (r:1 w:1)
+	// Storage: TokenMulti InventorySize (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenMulti Unindexed (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn index_token_id() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn create_token() -> Weight {
-		(15_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	fn mint() -> Weight {
 		(20_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	fn mint_batch() -> Weight {
 		(38_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(24 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(28 as Weight))
 	}
 	fn set_approval_for_all() -> Weight {
 		(16_000_000 as Weight)
@@ -138,22 +416,120 @@ impl WeightInfo for () {
 	}
 	fn burn() -> Weight {
 		(19_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
 	fn burn_batch() -> Weight {
 		(41_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(27 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(22 as Weight))
 	}
 	fn transfer_from() -> Weight {
 		(21_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
 	fn batch_transfer_from() -> Weight {
 		(56_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(30 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(23 as Weight))
+	}
+	fn burn_from() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn grant_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_ownership() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn destroy_accounts(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+	fn finish_destroy() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn freeze() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_royalty() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn clear_token_royalty() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_soulbound() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn redeem_voucher() -> Weight {
+		(92_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
 	}
+	fn set_max_supply() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn register_token_id() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_token_uri() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn index_token_id() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
 }
@@ -0,0 +1,31 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
+use web3games_support::NestedToken;
+
+decl_runtime_apis! {
+	pub trait TokenNonFungibleRuntimeApi {
+		fn attribute(id: u128, token_id: u128, key: Vec<u8>) -> Option<Vec<u8>>;
+		fn attributes(id: u128, token_id: u128) -> Vec<(Vec<u8>, Vec<u8>)>;
+		fn nested_tokens(id: u128, token_id: u128, depth: u32) -> Vec<(u32, NestedToken<u128, u128, u128>, u128)>;
+	}
+}
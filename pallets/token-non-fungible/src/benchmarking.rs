@@ -7,30 +7,73 @@ use super::*;
 use crate::Pallet as TokenNonFungible;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
+use sp_core::{crypto::KeyTypeId, ecdsa};
+use sp_runtime::traits::Saturating;
 
 const SEED: u32 = 0;
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"vouc");
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+	let caller: T::AccountId = account(name, 0, SEED);
+	T::Currency::make_free_balance_be(
+		&caller,
+		T::CreateTokenDeposit::get().saturating_mul(1_000u32.into()),
+	);
+	caller
+}
+
+fn evm_address(public: &ecdsa::Public) -> H160 {
+	let message = [0u8; 32];
+	let signature =
+		sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, public, &message).expect("key exists; qed");
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message)
+		.expect("signature is valid; qed");
+	H160::from_slice(&keccak_256(&public)[12..])
+}
+
+fn mint_phase<T: Config>(allowlist: Option<H256>) -> MintPhaseOf<T> {
+	MintPhase {
+		start: Zero::zero(),
+		end: 1_000u32.into(),
+		currency: None,
+		price: 1_000u128,
+		wallet_limit: 1,
+		allowlist,
+	}
+}
+
+/// A root with `who` as a leaf, and the proof of that leaf through `depth` levels.
+fn merkle_root<T: Config>(who: &T::AccountId, depth: u32) -> (H256, Vec<H256>) {
+	let proof: Vec<H256> = (0..depth).map(|i| H256::repeat_byte(i as u8)).collect();
+	let leaf = H256::from(keccak_256(&who.encode()));
+	let root = proof.iter().fold(leaf, |hash, node| {
+		let (first, second) = if hash <= *node { (hash, *node) } else { (*node, hash) };
+		H256::from(keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+	});
+	(root, proof)
+}
 
 benchmarks! {
 	create_token {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 	}: _(RawOrigin::Signed(alice), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20])
 
 	mint {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		// let bob: T::AccountId = account("bob", 0, SEED);
 
 		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
 	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), 1u32.into())
 
 	burn {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 
 		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
 		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
 	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into())
 
 	approve {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
 		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
@@ -38,7 +81,7 @@ benchmarks! {
 	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, 1u32.into())
 
 	set_approve_for_all {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
 		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
@@ -46,12 +89,220 @@ benchmarks! {
 	}: _(RawOrigin::Signed(alice), 1u32.into(), bob, true)
 
 	transfer_from {
-		let alice: T::AccountId = account("alice", 0, SEED);
+		let alice = funded_account::<T>("alice");
 		let bob: T::AccountId = account("bob", 0, SEED);
 
 		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
 		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
 	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), bob, 1u32.into())
+
+	grant_role {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), TokenRole::Minter, bob)
+
+	revoke_role {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Minter, bob.clone());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), TokenRole::Minter, bob)
+
+	transfer_ownership {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	start_destroy {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	destroy_accounts {
+		let c in 0 .. T::RemoveItemsLimit::get();
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		for i in 0 .. c {
+			let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), account("holder", i, SEED), i.into());
+		}
+		let _ = TokenNonFungible::<T>::start_destroy(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	finish_destroy {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::start_destroy(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	freeze {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	thaw {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::freeze(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), bob.clone());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), bob)
+
+	freeze_token {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	thaw_token {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::freeze_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into())
+
+	set_attribute {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), vec![0u8; T::KeyLimit::get() as usize], vec![0u8; T::ValueLimit::get() as usize])
+
+	clear_attribute {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
+		let _ = TokenNonFungible::<T>::set_attribute(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), 1u32.into(), vec![0u8; T::KeyLimit::get() as usize], vec![0u8; T::ValueLimit::get() as usize]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), vec![0u8; T::KeyLimit::get() as usize])
+
+	set_token_uri {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), vec![0u8; T::StringLimit::get() as usize])
+
+	freeze_metadata {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), Some(1u32.into()))
+
+	set_royalty {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), Some(1u32.into()), bob, 500)
+
+	clear_token_royalty {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::set_royalty(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), Some(1u32.into()), bob, 500);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into())
+
+	set_soulbound {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), Some(1u32.into()), true)
+
+	set_user {
+		let alice = funded_account::<T>("alice");
+		let bob: T::AccountId = account("bob", 0, SEED);
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), Some(bob), 100u32.into())
+
+	nest {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 2u32.into());
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 1u32.into(), 1u32.into(), 2u32.into())
+
+	unnest {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 1u32.into());
+		let _ = TokenNonFungible::<T>::mint(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), alice.clone(), 2u32.into());
+		let _ = TokenNonFungible::<T>::nest(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), 1u32.into(), 1u32.into(), 2u32.into());
+	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), 1u32.into(), 1u32.into(), 2u32.into(), alice.clone())
+
+	redeem_voucher {
+		let alice = funded_account::<T>("alice");
+		let bob = funded_account::<T>("bob");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+		let signer = evm_address(&public);
+		let _ = TokenNonFungible::<T>::grant_role(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), TokenRole::Minter, T::AddressMapping::convert(signer));
+		let voucher = MintVoucher {
+			token_id: 1u32.into(),
+			recipient: None,
+			currency: None,
+			price: 1_000u128,
+			expiry: 1_000u32.into(),
+			nonce: 0,
+		};
+		let digest = TokenNonFungible::<T>::voucher_digest(1u32.into(), &voucher);
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &digest).unwrap().0;
+	}: _(RawOrigin::Signed(bob), 1u32.into(), voucher, signature)
+
+	set_max_supply {
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice), 1u32.into(), 100)
+
+	set_mint_phases {
+		let p in 1 .. T::MaxMintPhases::get();
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let phases = (0..p).map(|_| mint_phase::<T>(None)).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(alice), 1u32.into(), phases)
+
+	public_mint {
+		let p in 1 .. 32;
+		let alice = funded_account::<T>("alice");
+		let bob = funded_account::<T>("bob");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let (root, proof) = merkle_root::<T>(&bob, p);
+		let _ = TokenNonFungible::<T>::set_mint_phases(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![mint_phase::<T>(Some(root))]);
+	}: _(RawOrigin::Signed(bob), 1u32.into(), 1u32.into(), proof)
+
+	mint_batch {
+		let n in 1 .. T::MaxBatchMint::get();
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+		let token_ids = (0..n).map(|i| i.into()).collect::<Vec<T::TokenId>>();
+	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), token_ids)
+
+	mint_range {
+		let n in 1 .. T::MaxBatchMint::get();
+		let alice = funded_account::<T>("alice");
+
+		let _ = TokenNonFungible::<T>::create_token(<T as frame_system::Config>::Origin::from(RawOrigin::Signed(alice.clone())), 1u32.into(), vec![0u8; 10], vec![0u8; 10], vec![0u8; 20]);
+	}: _(RawOrigin::Signed(alice.clone()), 1u32.into(), alice.clone(), 0u32.into(), n)
 }
 
 impl_benchmark_test_suite!(TokenNonFungible, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -22,23 +22,35 @@ use codec::{alloc::string::ToString, Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	ensure,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{
+		tokens::fungibles, Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency,
+	},
 	BoundedVec, PalletId,
 };
-use primitives::TokenIndex;
+use primitives::{Balance, TokenIndex};
 use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::{blake2_256, keccak_256};
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, One, TrailingZeroInput},
-	RuntimeDebug,
+	traits::{
+		AtLeast32BitUnsigned, Convert, One, SaturatedConversion, Saturating, TrailingZeroInput,
+		UniqueSaturatedInto, Zero,
+	},
+	PerThing, Permill, RuntimeDebug,
 };
 use sp_std::prelude::*;
-use web3games_support::{NonFungibleEnumerable, NonFungibleMetadata};
+use web3games_support::{
+	MultiTokenTransfer, NestedToken, NonFungibleEnumerable, NonFungibleMetadata, TokenIdConversion,
+	TokenRole,
+};
 
 pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -51,6 +63,36 @@ mod tests;
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+pub type MultiTokenIdOf<T> = <<T as Config>::MultiToken as MultiTokenTransfer<
+	<T as frame_system::Config>::AccountId,
+>>::MultiTokenId;
+
+pub type NestedTokenOf<T> =
+	NestedToken<<T as Config>::NonFungibleTokenId, MultiTokenIdOf<T>, <T as Config>::TokenId>;
+
+pub type FungibleTokenIdOf<T> = <<T as Config>::Fungibles as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::AssetId;
+
+pub type MintVoucherOf<T> = MintVoucher<
+	<T as Config>::TokenId,
+	FungibleTokenIdOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+pub type MintPhaseOf<T> = MintPhase<FungibleTokenIdOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// EIP-712 type of the domain a voucher is signed for.
+pub const EIP712_DOMAIN_TYPE: &[u8] =
+	b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+/// EIP-712 type of a mint voucher.
+pub const MINT_VOUCHER_TYPE: &[u8] =
+	b"MintVoucher(uint256 tokenId,address recipient,address currency,uint256 price,uint256 expiry,uint256 nonce)";
+
+/// Version of the signing domain.
+pub const VOUCHER_VERSION: &[u8] = b"1";
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct Token<AccountId, BoundedString> {
 	owner: AccountId,
@@ -59,6 +101,36 @@ pub struct Token<AccountId, BoundedString> {
 	base_uri: BoundedString,
 }
 
+/// A minter's signed promise to mint a token to whoever redeems it and pays its price.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct MintVoucher<TokenId, FungibleTokenId, BlockNumber> {
+	pub token_id: TokenId,
+	/// The only address that can redeem the voucher, anyone if `None`.
+	pub recipient: Option<H160>,
+	/// The fungible token the price is paid in, the native currency if `None`.
+	pub currency: Option<FungibleTokenId>,
+	pub price: Balance,
+	/// The last block the voucher can be redeemed at.
+	pub expiry: BlockNumber,
+	pub nonce: u64,
+}
+
+/// A window in which anyone, or only the accounts of an allowlist, can mint tokens for a price.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct MintPhase<FungibleTokenId, BlockNumber> {
+	/// The first block of the phase.
+	pub start: BlockNumber,
+	/// The last block of the phase.
+	pub end: BlockNumber,
+	/// The fungible token the price is paid in, the native currency if `None`.
+	pub currency: Option<FungibleTokenId>,
+	pub price: Balance,
+	/// The number of tokens each account can mint in the phase.
+	pub wallet_limit: u32,
+	/// Merkle root of the accounts allowed to mint, anyone if `None`.
+	pub allowlist: Option<H256>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -91,12 +163,70 @@ pub mod pallet {
 
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 
+		/// The maximum length of an attribute key.
+		#[pallet::constant]
+		type KeyLimit: Get<u32>;
+
+		/// The maximum length of an attribute value.
+		#[pallet::constant]
+		type ValueLimit: Get<u32>;
+
+		/// The basic amount reserved for setting an attribute.
+		#[pallet::constant]
+		type AttributeDepositBase: Get<BalanceOf<Self>>;
+
+		/// The additional amount reserved per byte of attribute key and value.
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+
+		/// Multi tokens that can be nested in non-fungible tokens.
+		type MultiToken: MultiTokenTransfer<Self::AccountId, TokenId = Self::TokenId>;
+
+		/// The maximum depth of tokens nested in each other.
+		#[pallet::constant]
+		type MaxNestingDepth: Get<u32>;
+
+		/// The maximum number of tokens nested directly in a token.
+		#[pallet::constant]
+		type MaxChildren: Get<u32>;
+
+		/// Fungible tokens that voucher prices can be paid in.
+		type Fungibles: fungibles::Transfer<Self::AccountId, Balance = Balance>;
+
+		/// Mapping from a fungible token id to the address of its ERC20 precompile.
+		type FungibleTokenIdConversion: TokenIdConversion<FungibleTokenIdOf<Self>>;
+
+		/// The chain id voucher signatures are bound to.
+		type ChainId: Get<u64>;
+
+		/// Mapping from an EVM address to the account it controls.
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Mapping from a token id to the address of its ERC721 precompile.
+		type TokenIdConversion: TokenIdConversion<Self::NonFungibleTokenId>;
+
+		/// The maximum number of public mint phases of a collection.
+		#[pallet::constant]
+		type MaxMintPhases: Get<u32>;
+
+		/// The maximum number of tokens minted by one `mint_batch` or `mint_range`.
+		#[pallet::constant]
+		type MaxBatchMint: Get<u32>;
+
+		/// The maximum number of storage items removed by a single `destroy_accounts`.
+		#[pallet::constant]
+		type RemoveItemsLimit: Get<u32>;
+
 		/// runtime weights.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -208,6 +338,252 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Roles granted on a collection, keyed by `(role, account)`.
+	#[pallet::storage]
+	#[pallet::getter(fn has_role)]
+	pub(super) type Roles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		(TokenRole, T::AccountId),
+		bool,
+		ValueQuery,
+	>;
+
+	/// Accounts that can not transfer a token.
+	#[pallet::storage]
+	#[pallet::getter(fn is_frozen)]
+	pub(super) type Frozen<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Tokens that can not be transferred by any account.
+	#[pallet::storage]
+	#[pallet::getter(fn is_token_frozen)]
+	pub(super) type FrozenTokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, bool, ValueQuery>;
+
+	/// Attributes of each token and the account and deposit reserved for them.
+	#[pallet::storage]
+	pub(super) type Attributes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::NonFungibleTokenId>,
+			NMapKey<Blake2_128Concat, T::TokenId>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+		),
+		(BoundedVec<u8, T::ValueLimit>, (T::AccountId, BalanceOf<T>)),
+	>;
+
+	/// URIs of single tokens that override the collection `base_uri`.
+	#[pallet::storage]
+	pub(super) type TokenUris<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		BoundedVec<u8, T::StringLimit>,
+	>;
+
+	/// Tokens whose metadata can never be changed again.
+	#[pallet::storage]
+	#[pallet::getter(fn is_metadata_frozen)]
+	pub(super) type MetadataFrozen<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Collections whose metadata can never be changed again.
+	#[pallet::storage]
+	#[pallet::getter(fn is_collection_metadata_frozen)]
+	pub(super) type CollectionMetadataFrozen<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, bool, ValueQuery>;
+
+	/// The receiver and basis points of the royalty paid on secondary sales of a collection.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_royalty)]
+	pub(super) type Royalties<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, (T::AccountId, u16)>;
+
+	/// Royalties of single tokens that override the collection royalty.
+	#[pallet::storage]
+	#[pallet::getter(fn token_royalty)]
+	pub(super) type TokenRoyalties<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		(T::AccountId, u16),
+	>;
+
+	/// Collections whose tokens can not be transferred.
+	#[pallet::storage]
+	#[pallet::getter(fn is_soulbound_collection)]
+	pub(super) type SoulboundCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, bool, ValueQuery>;
+
+	/// Single tokens that can not be transferred.
+	#[pallet::storage]
+	#[pallet::getter(fn is_soulbound_token)]
+	pub(super) type SoulboundTokens<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Tokens held in place by another pallet, and the identifier of the lock.
+	#[pallet::storage]
+	#[pallet::getter(fn locker_of)]
+	pub(super) type Locks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		LockIdentifier,
+	>;
+
+	/// The account allowed to use a token and the block its right expires at.
+	#[pallet::storage]
+	#[pallet::getter(fn user)]
+	pub(super) type Users<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		(T::AccountId, T::BlockNumber),
+	>;
+
+	/// The non-fungible token each nested token is held by.
+	#[pallet::storage]
+	#[pallet::getter(fn parent_of)]
+	pub(super) type Parents<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		(T::NonFungibleTokenId, T::TokenId),
+	>;
+
+	/// Tokens nested in each non-fungible token.
+	#[pallet::storage]
+	pub(super) type Children<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::NonFungibleTokenId>,
+			NMapKey<Blake2_128Concat, T::TokenId>,
+			NMapKey<Blake2_128Concat, NestedTokenOf<T>>,
+		),
+		(),
+	>;
+
+	/// The number of tokens nested directly in each non-fungible token.
+	#[pallet::storage]
+	#[pallet::getter(fn child_count)]
+	pub(super) type ChildCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The number of levels of non-fungible tokens nested below each non-fungible token.
+	#[pallet::storage]
+	#[pallet::getter(fn height_of)]
+	pub(super) type Heights<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		T::TokenId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Voucher nonces of a signer that were redeemed.
+	#[pallet::storage]
+	#[pallet::getter(fn is_voucher_redeemed)]
+	pub(super) type RedeemedVouchers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		(H160, u64),
+		bool,
+		ValueQuery,
+	>;
+
+	/// The irreversible cap on the number of tokens ever minted in a collection.
+	#[pallet::storage]
+	#[pallet::getter(fn max_supply)]
+	pub(super) type MaxSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, u32>;
+
+	/// The number of tokens ever minted in a collection, burned tokens included.
+	#[pallet::storage]
+	#[pallet::getter(fn minted)]
+	pub(super) type Minted<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, u32, ValueQuery>;
+
+	/// The public mint phases of a collection.
+	#[pallet::storage]
+	#[pallet::getter(fn mint_phases)]
+	pub(super) type MintPhases<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		BoundedVec<MintPhaseOf<T>, T::MaxMintPhases>,
+		ValueQuery,
+	>;
+
+	/// The number of tokens each account minted in a phase, keyed by `(phase, account)`.
+	#[pallet::storage]
+	#[pallet::getter(fn phase_minted)]
+	pub(super) type PhaseMinted<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NonFungibleTokenId,
+		Blake2_128Concat,
+		(u32, T::AccountId),
+		u32,
+		ValueQuery,
+	>;
+
+	/// The creator of a token and the deposit reserved from them.
+	#[pallet::storage]
+	#[pallet::getter(fn deposit)]
+	pub(super) type Deposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, (T::AccountId, BalanceOf<T>)>;
+
+	/// Tokens that are being destroyed and can no longer be used.
+	#[pallet::storage]
+	#[pallet::getter(fn is_destroying)]
+	pub(super) type Destroying<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::NonFungibleTokenId, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -215,6 +591,34 @@ pub mod pallet {
 		Transfer(T::NonFungibleTokenId, T::AccountId, T::AccountId, T::TokenId),
 		Approval(T::NonFungibleTokenId, T::AccountId, T::AccountId, T::TokenId),
 		ApprovalForAll(T::NonFungibleTokenId, T::AccountId, T::AccountId, bool),
+		RoleGranted(T::NonFungibleTokenId, TokenRole, T::AccountId),
+		RoleRevoked(T::NonFungibleTokenId, TokenRole, T::AccountId),
+		OwnershipTransferred(T::NonFungibleTokenId, T::AccountId, T::AccountId),
+		DestructionStarted(T::NonFungibleTokenId),
+		AccountsDestroyed(T::NonFungibleTokenId, u32),
+		Destroyed(T::NonFungibleTokenId),
+		AccountFrozen(T::NonFungibleTokenId, T::AccountId),
+		AccountThawed(T::NonFungibleTokenId, T::AccountId),
+		TokenFrozen(T::NonFungibleTokenId),
+		TokenThawed(T::NonFungibleTokenId),
+		AttributeSet(T::NonFungibleTokenId, T::TokenId, Vec<u8>, Vec<u8>),
+		AttributeCleared(T::NonFungibleTokenId, T::TokenId, Vec<u8>),
+		TokenUriSet(T::NonFungibleTokenId, T::TokenId, Vec<u8>),
+		MetadataFrozen(T::NonFungibleTokenId, Option<T::TokenId>),
+		RoyaltySet(T::NonFungibleTokenId, Option<T::TokenId>, T::AccountId, u16),
+		TokenRoyaltyCleared(T::NonFungibleTokenId, T::TokenId),
+		SoulboundSet(T::NonFungibleTokenId, Option<T::TokenId>, bool),
+		Locked(T::NonFungibleTokenId, T::TokenId, LockIdentifier),
+		Unlocked(T::NonFungibleTokenId, T::TokenId, LockIdentifier),
+		UserSet(T::NonFungibleTokenId, T::TokenId, Option<T::AccountId>, T::BlockNumber),
+		TokenNested(T::NonFungibleTokenId, T::TokenId, NestedTokenOf<T>, Balance),
+		TokenUnnested(T::NonFungibleTokenId, T::TokenId, NestedTokenOf<T>, T::AccountId, Balance),
+		VoucherRedeemed(T::NonFungibleTokenId, T::TokenId, T::AccountId, H160, u64),
+		MaxSupplySet(T::NonFungibleTokenId, u32),
+		MintPhasesSet(T::NonFungibleTokenId, u32),
+		PublicMinted(T::NonFungibleTokenId, u32, T::AccountId, T::TokenId),
+		BatchMint(T::NonFungibleTokenId, T::AccountId, Vec<T::TokenId>),
+		RangeMint(T::NonFungibleTokenId, T::AccountId, T::TokenId, u32),
 	}
 
 	#[pallet::error]
@@ -234,10 +638,43 @@ pub mod pallet {
 		ConfuseBehavior,
 		TransferTokenNotOwn,
 		NotFound,
+		TokenDestroying,
+		NotDestroying,
+		AccountsRemaining,
+		AccountFrozen,
+		TokenFrozen,
+		MetadataFrozen,
+		InvalidRoyalty,
+		NoTokenRoyalty,
+		Soulbound,
+		TokenLocked,
+		NotLocked,
+		HasChildren,
+		NotNested,
+		NestingLoop,
+		NestingTooDeep,
+		TooManyChildren,
+		InvalidSignature,
+		VoucherExpired,
+		VoucherRedeemed,
+		NotVoucherRecipient,
+		MaxSupplyAlreadySet,
+		MaxSupplyTooLow,
+		MaxSupplyExceeded,
+		InvalidMintPhase,
+		TooManyMintPhases,
+		TooManyTokens,
+		NoActiveMintPhase,
+		NotAllowlisted,
+		WalletLimitReached,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -250,7 +687,11 @@ pub mod pallet {
 			base_uri: Vec<u8>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::do_create_token(&who, id, name, symbol, base_uri)
+			Self::do_create_token(&who, id, name, symbol, base_uri)?;
+			let deposit = T::CreateTokenDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			Deposits::<T>::insert(id, (who, deposit));
+			Ok(())
 		}
 
 		#[pallet::weight(T::WeightInfo::approve())]
@@ -298,6 +739,31 @@ pub mod pallet {
 			Self::do_mint(&who, id, &to, token_id)
 		}
 
+		/// Mint `token_ids` to `to`, updating the enumerations of the collection once.
+		#[pallet::weight(T::WeightInfo::mint_batch(token_ids.len() as u32))]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			to: T::AccountId,
+			token_ids: Vec<T::TokenId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_batch_mint(&who, id, &to, token_ids)
+		}
+
+		/// Mint the `count` consecutive tokens from `start` to `to`.
+		#[pallet::weight(T::WeightInfo::mint_range(*count))]
+		pub fn mint_range(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			to: T::AccountId,
+			start: T::TokenId,
+			count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_mint_range(&who, id, &to, start, count)
+		}
+
 		#[pallet::weight(T::WeightInfo::burn())]
 		pub fn burn(
 			origin: OriginFor<T>,
@@ -307,222 +773,1681 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			Self::do_burn(&who, id, token_id)
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	fn zero_account_id() -> T::AccountId {
-		T::AccountId::decode(&mut TrailingZeroInput::zeroes()).expect("infinite input; qed")
-	}
+		#[pallet::weight(T::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			role: TokenRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_grant_role(id, &who, role, &account)
+		}
 
-	pub fn exists(id: T::NonFungibleTokenId) -> bool {
-		Tokens::<T>::contains_key(id)
-	}
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			role: TokenRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_revoke_role(id, &who, role, &account)
+		}
 
-	pub fn token_exists(id: T::NonFungibleTokenId, token_id: T::TokenId) -> bool {
-		Owners::<T>::contains_key(id, token_id)
-	}
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_transfer_ownership(id, &who, &new_owner)
+		}
 
-	pub fn do_create_token(
-		who: &T::AccountId,
-		id: T::NonFungibleTokenId,
-		name: Vec<u8>,
-		symbol: Vec<u8>,
-		base_uri: Vec<u8>,
-	) -> DispatchResult {
-		ensure!(!Self::exists(id.clone()), Error::<T>::InvalidId);
+		/// Start destroying a token. Only the owner can do this.
+		///
+		/// Once started, the token can no longer be minted, transferred or approved.
+		#[pallet::weight(T::WeightInfo::start_destroy())]
+		pub fn start_destroy(origin: OriginFor<T>, id: T::NonFungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_start_destroy(id, &who)
+		}
 
-		let bounded_name: BoundedVec<u8, T::StringLimit> =
-			name.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
-		let bounded_symbol: BoundedVec<u8, T::StringLimit> =
-			symbol.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
-		let bounded_base_uri: BoundedVec<u8, T::StringLimit> =
-			base_uri.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+		/// Remove up to `RemoveItemsLimit` account entries of a token being destroyed.
+		///
+		/// Anyone can call this until all entries are removed.
+		#[pallet::weight(T::WeightInfo::destroy_accounts(T::RemoveItemsLimit::get()))]
+		pub fn destroy_accounts(origin: OriginFor<T>, id: T::NonFungibleTokenId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_destroy_accounts(id)
+		}
 
-		let token = Token {
-			owner: who.clone(),
-			name: bounded_name,
-			symbol: bounded_symbol,
-			base_uri: bounded_base_uri,
+		/// Remove a token whose accounts are all destroyed and refund the creation deposit.
+		#[pallet::weight(T::WeightInfo::finish_destroy())]
+		pub fn finish_destroy(origin: OriginFor<T>, id: T::NonFungibleTokenId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_finish_destroy(id)
+		}
+
+		/// Stop `account` from transferring the token. Needs the `Freezer` role.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_freeze(id, &who, &account)
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_thaw(id, &who, &account)
+		}
+
+		/// Stop every account from transferring the token. Needs the `Freezer` role.
+		#[pallet::weight(T::WeightInfo::freeze_token())]
+		pub fn freeze_token(origin: OriginFor<T>, id: T::NonFungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_freeze_token(id, &who)
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw_token())]
+		pub fn thaw_token(origin: OriginFor<T>, id: T::NonFungibleTokenId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_thaw_token(id, &who)
+		}
+
+		/// Set an attribute of a token, reserving a deposit from the caller.
+		///
+		/// Needs the `Metadata` role, token holders can not change attributes.
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_attribute(id, &who, token_id, key, value)
+		}
+
+		/// Clear an attribute of a token and refund its deposit. Needs the `Metadata` role.
+		///
+		/// Attributes of burned tokens can still be cleared.
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+			key: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_clear_attribute(id, &who, token_id, key)
+		}
+
+		/// Override the URI of a token, an empty `uri` falls back to the `base_uri`.
+		///
+		/// Needs the `Metadata` role.
+		#[pallet::weight(T::WeightInfo::set_token_uri())]
+		pub fn set_token_uri(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+			uri: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_token_uri(id, &who, token_id, uri)
+		}
+
+		/// Permanently freeze the metadata of a token, or of the whole collection if `token_id`
+		/// is `None`. Needs the `Metadata` role.
+		#[pallet::weight(T::WeightInfo::freeze_metadata())]
+		pub fn freeze_metadata(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: Option<T::TokenId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_freeze_metadata(id, &who, token_id)
+		}
+
+		/// Set the royalty paid to `receiver` on secondary sales, in basis points of the sale
+		/// price, for the whole collection or for a single token if `token_id` is given.
+		///
+		/// Zero `basis_points` removes the collection royalty, while on a single token it waives
+		/// the collection royalty for that token. Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: Option<T::TokenId>,
+			receiver: T::AccountId,
+			basis_points: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_royalty(id, &who, token_id, receiver, basis_points)
+		}
+
+		/// Remove the royalty of a single token so that it falls back to the collection
+		/// royalty. Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::clear_token_royalty())]
+		pub fn clear_token_royalty(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_clear_token_royalty(id, &who, token_id)
+		}
+
+		/// Bind the whole collection, or a single token if `token_id` is given, to the accounts
+		/// holding it. Soulbound tokens can still be minted and burned but never transferred.
+		///
+		/// Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::set_soulbound())]
+		pub fn set_soulbound(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: Option<T::TokenId>,
+			soulbound: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_soulbound(id, &who, token_id, soulbound)
+		}
+
+		/// Let `user` use a token until block `expires` while its owner keeps it, or remove the
+		/// user if `None`. Transferring the token also removes the user.
+		///
+		/// Needs the owner or an approved account.
+		#[pallet::weight(T::WeightInfo::set_user())]
+		pub fn set_user(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+			user: Option<T::AccountId>,
+			expires: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_user(id, &who, token_id, user, expires)
+		}
+
+		/// Nest a token of the caller in the token `parent_token_id` of `parent_id`, so that it
+		/// moves with its parent. Needs the owner of the parent, or of the tree it is nested in.
+		#[pallet::weight(T::WeightInfo::nest())]
+		pub fn nest(
+			origin: OriginFor<T>,
+			parent_id: T::NonFungibleTokenId,
+			parent_token_id: T::TokenId,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_nest(&who, parent_id, parent_token_id, id, token_id)
+		}
+
+		/// Nest `amount` of a multi token of the caller in a non-fungible token.
+		#[pallet::weight(T::WeightInfo::nest_multi())]
+		pub fn nest_multi(
+			origin: OriginFor<T>,
+			parent_id: T::NonFungibleTokenId,
+			parent_token_id: T::TokenId,
+			multi_token_id: MultiTokenIdOf<T>,
+			token_id: T::TokenId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_nest_multi(&who, parent_id, parent_token_id, multi_token_id, token_id, amount)
+		}
+
+		/// Move a token nested in a non-fungible token out to `to`. Needs the owner of the
+		/// parent, or of the tree it is nested in.
+		#[pallet::weight(T::WeightInfo::unnest())]
+		pub fn unnest(
+			origin: OriginFor<T>,
+			parent_id: T::NonFungibleTokenId,
+			parent_token_id: T::TokenId,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_unnest(&who, parent_id, parent_token_id, id, token_id, &to)
+		}
+
+		/// Move `amount` of a multi token nested in a non-fungible token out to `to`.
+		#[pallet::weight(T::WeightInfo::unnest_multi())]
+		pub fn unnest_multi(
+			origin: OriginFor<T>,
+			parent_id: T::NonFungibleTokenId,
+			parent_token_id: T::TokenId,
+			multi_token_id: MultiTokenIdOf<T>,
+			token_id: T::TokenId,
+			amount: Balance,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_unnest_multi(
+				&who,
+				parent_id,
+				parent_token_id,
+				multi_token_id,
+				token_id,
+				amount,
+				&to,
+			)
+		}
+
+		/// Mint the token of a voucher signed by a minter to the caller, who pays its price
+		/// to the owner of the token.
+		///
+		/// Each nonce of a signer can be redeemed once.
+		#[pallet::weight(T::WeightInfo::redeem_voucher())]
+		pub fn redeem_voucher(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			voucher: MintVoucherOf<T>,
+			signature: [u8; 65],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem_voucher(&who, id, voucher, signature)
+		}
+
+		/// Cap the total supply of a collection, the cap can not be changed once set.
+		///
+		/// Only the owner can do this.
+		#[pallet::weight(T::WeightInfo::set_max_supply())]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			max_supply: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_max_supply(id, &who, max_supply)
+		}
+
+		/// Replace the public mint phases of a collection.
+		///
+		/// Only the owner can do this. Mint counts are kept per phase index, so replacing a phase
+		/// does not reset how many tokens each account minted in it.
+		#[pallet::weight(T::WeightInfo::set_mint_phases(phases.len() as u32))]
+		pub fn set_mint_phases(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			phases: Vec<MintPhaseOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_mint_phases(id, &who, phases)
+		}
+
+		/// Mint `token_id` to the sender in the active mint phase and pay its price.
+		///
+		/// `proof` shows the sender is in the allowlist of the phase, if it has one.
+		#[pallet::weight(T::WeightInfo::public_mint(proof.len() as u32))]
+		pub fn public_mint(
+			origin: OriginFor<T>,
+			id: T::NonFungibleTokenId,
+			token_id: T::TokenId,
+			proof: Vec<H256>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_public_mint(&who, id, token_id, &proof)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn zero_account_id() -> T::AccountId {
+		T::AccountId::decode(&mut TrailingZeroInput::zeroes()).expect("infinite input; qed")
+	}
+
+	pub fn exists(id: T::NonFungibleTokenId) -> bool {
+		Tokens::<T>::contains_key(id)
+	}
+
+	pub fn token_exists(id: T::NonFungibleTokenId, token_id: T::TokenId) -> bool {
+		Owners::<T>::contains_key(id, token_id)
+	}
+
+	pub fn do_create_token(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		base_uri: Vec<u8>,
+	) -> DispatchResult {
+		ensure!(!Self::exists(id.clone()), Error::<T>::InvalidId);
+
+		let bounded_name: BoundedVec<u8, T::StringLimit> =
+			name.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+		let bounded_symbol: BoundedVec<u8, T::StringLimit> =
+			symbol.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+		let bounded_base_uri: BoundedVec<u8, T::StringLimit> =
+			base_uri.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+
+		let token = Token {
+			owner: who.clone(),
+			name: bounded_name,
+			symbol: bounded_symbol,
+			base_uri: bounded_base_uri,
 		};
 
 		Tokens::<T>::insert(id, token);
 
-		Self::deposit_event(Event::TokenCreated(id, who.clone(), name, symbol, base_uri));
+		Self::deposit_event(Event::TokenCreated(id, who.clone(), name, symbol, base_uri));
+
+		Ok(())
+	}
+
+	pub fn do_approve(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		to: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		let owner = Self::owner_of(id, token_id).ok_or(Error::<T>::NotFound)?;
+		ensure!(to != &owner, Error::<T>::ApproveToCurrentOwner);
+
+		ensure!(
+			who == &owner || Self::is_approved_for_all(id, (&owner, who)),
+			Error::<T>::NotOwnerOrApproved
+		);
+
+		TokenApprovals::<T>::insert(id, token_id, to);
+
+		Self::deposit_event(Event::Approval(id, owner, to.clone(), token_id));
+
+		Ok(())
+	}
+
+	pub fn do_set_approve_for_all(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		operator: &T::AccountId,
+		approved: bool,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		ensure!(operator != who, Error::<T>::ApproveToCaller);
+
+		OperatorApprovals::<T>::insert(id, (who, operator), approved);
+
+		Self::deposit_event(Event::ApprovalForAll(id, who.clone(), operator.clone(), approved));
+
+		Ok(())
+	}
+
+	pub fn do_transfer_from(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		ensure!(Self::token_exists(id, token_id), Error::<T>::TokenNonExistent);
+		ensure!(
+			Self::is_approved_or_owner(id, &who, token_id).unwrap(),
+			Error::<T>::NotOwnerOrApproved
+		);
+		Self::do_transfer(id, from, to, token_id)?;
+		Ok(())
+	}
+
+	pub fn do_transfer(
+		id: T::NonFungibleTokenId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		Self::ensure_not_frozen(id, from)?;
+		ensure!(!Self::is_soulbound(id, token_id), Error::<T>::Soulbound);
+		Self::transfer_token(id, from, to, token_id)
+	}
+
+	/// Return a token held in escrow by `escrow` to `depositor`.
+	///
+	/// Unlike `do_transfer`, this ignores soulbound flags set while the token was in escrow, so
+	/// it can always go back to the account it is bound to.
+	pub fn do_release_escrow(
+		id: T::NonFungibleTokenId,
+		escrow: &T::AccountId,
+		depositor: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		Self::ensure_not_frozen(id, escrow)?;
+		Self::transfer_token(id, escrow, depositor, token_id)
+	}
+
+	/// Move a token of `depositor` into escrow by `escrow`.
+	///
+	/// Like `do_release_escrow`, this ignores soulbound flags: callers only return escrowed
+	/// tokens to `depositor` or burn them.
+	pub fn do_deposit_escrow(
+		id: T::NonFungibleTokenId,
+		depositor: &T::AccountId,
+		escrow: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		Self::ensure_not_frozen(id, depositor)?;
+		Self::transfer_token(id, depositor, escrow, token_id)
+	}
+
+	/// Move `token_id` from `from` to `to`, callers check soulbound and frozen tokens.
+	fn transfer_token(
+		id: T::NonFungibleTokenId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		ensure!(!Locks::<T>::contains_key(id, token_id), Error::<T>::TokenLocked);
+		ensure!(
+			Owners::<T>::get(id, token_id) == Some(from.clone()),
+			Error::<T>::TransferTokenNotOwn
+		);
+
+		if from == to {
+			return Ok(())
+		}
+
+		let balance_from = Self::balance_of(id, from);
+		let balance_to = Self::balance_of(id, to);
+
+		let new_balance_from = match balance_from.checked_sub(1) {
+			Some(c) => c,
+			None => return Err(Error::<T>::Underflow.into()),
+		};
+
+		let new_balance_to = match balance_to.checked_add(1) {
+			Some(c) => c,
+			None => return Err(Error::<T>::Overflow.into()),
+		};
+
+		Self::remove_token_from_owner_enumeration(id, from, token_id)?;
+		Self::add_token_to_owner_enumeration(id, to, token_id)?;
+
+		Self::clear_approval(id, token_id)?;
+		if Users::<T>::take(id, token_id).is_some() {
+			Self::deposit_event(Event::UserSet(id, token_id, None, Zero::zero()));
+		}
+
+		Balances::<T>::insert(id, from, new_balance_from);
+		Balances::<T>::insert(id, to, new_balance_to);
+		Owners::<T>::insert(id, token_id, to);
+
+		Self::deposit_event(Event::Transfer(id.clone(), from.clone(), to.clone(), token_id));
+
+		Ok(())
+	}
+
+	pub fn do_mint(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		to: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		ensure!(Self::exists(id), Error::<T>::InvalidId);
+		Self::ensure_live(id)?;
+		ensure!(Self::has_permission(id, who), Error::<T>::NoPermission);
+		Self::mint_token(id, to, token_id)
+	}
+
+	/// Mint `token_id` to `to`, callers check who is allowed to.
+	fn mint_token(
+		id: T::NonFungibleTokenId,
+		to: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		ensure!(!Self::token_exists(id, token_id), Error::<T>::TokenAlreadyMinted);
+		let minted = Minted::<T>::get(id).checked_add(1).ok_or(Error::<T>::Overflow)?;
+		if let Some(max_supply) = MaxSupply::<T>::get(id) {
+			ensure!(minted <= max_supply, Error::<T>::MaxSupplyExceeded);
+		}
+
+		let balance = Self::balance_of(id, to);
+
+		let new_balance = match balance.checked_add(One::one()) {
+			Some(c) => c,
+			None => return Err(Error::<T>::Overflow.into()),
+		};
+
+		Self::add_token_to_all_tokens_enumeration(id, token_id)?;
+		Self::add_token_to_owner_enumeration(id, to, token_id)?;
+
+		Balances::<T>::insert(id, to, new_balance);
+		Owners::<T>::insert(id, token_id, to);
+		Minted::<T>::insert(id, minted);
+
+		Self::deposit_event(Event::Transfer(
+			id.clone(),
+			Self::zero_account_id(),
+			to.clone(),
+			token_id,
+		));
+
+		Ok(())
+	}
+
+	pub fn do_batch_mint(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		to: &T::AccountId,
+		token_ids: Vec<T::TokenId>,
+	) -> DispatchResult {
+		ensure!(Self::exists(id), Error::<T>::InvalidId);
+		Self::ensure_live(id)?;
+		ensure!(Self::has_permission(id, who), Error::<T>::NoPermission);
+
+		Self::mint_tokens(id, to, &token_ids)?;
+
+		Self::deposit_event(Event::BatchMint(id, to.clone(), token_ids));
+
+		Ok(())
+	}
+
+	pub fn do_mint_range(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		to: &T::AccountId,
+		start: T::TokenId,
+		count: u32,
+	) -> DispatchResult {
+		ensure!(Self::exists(id), Error::<T>::InvalidId);
+		Self::ensure_live(id)?;
+		ensure!(Self::has_permission(id, who), Error::<T>::NoPermission);
+		ensure!(count <= T::MaxBatchMint::get(), Error::<T>::TooManyTokens);
+		ensure!(start.checked_add(&count.into()).is_some(), Error::<T>::Overflow);
+
+		let token_ids: Vec<T::TokenId> = (0..count).map(|i| start + i.into()).collect();
+		Self::mint_tokens(id, to, &token_ids)?;
+
+		Self::deposit_event(Event::RangeMint(id, to.clone(), start, count));
+
+		Ok(())
+	}
+
+	/// Mint `token_ids` to `to`, reading and writing the supply and balance once for all of
+	/// them. Callers check who is allowed to.
+	fn mint_tokens(
+		id: T::NonFungibleTokenId,
+		to: &T::AccountId,
+		token_ids: &[T::TokenId],
+	) -> DispatchResult {
+		let count = u32::try_from(token_ids.len()).map_err(|_| Error::<T>::Overflow)?;
+		ensure!(count <= T::MaxBatchMint::get(), Error::<T>::TooManyTokens);
+		let total_supply = TotalSupply::<T>::get(id);
+		let new_total_supply = total_supply.checked_add(count).ok_or(Error::<T>::Overflow)?;
+		let minted = Minted::<T>::get(id).checked_add(count).ok_or(Error::<T>::Overflow)?;
+		if let Some(max_supply) = MaxSupply::<T>::get(id) {
+			ensure!(minted <= max_supply, Error::<T>::MaxSupplyExceeded);
+		}
+		let balance = Self::balance_of(id, to);
+		let new_balance = balance.checked_add(count).ok_or(Error::<T>::Overflow)?;
+
+		for (offset, &token_id) in (0..count).zip(token_ids) {
+			// Tokens minted earlier in the batch are already owned, so duplicates fail here.
+			ensure!(!Self::token_exists(id, token_id), Error::<T>::TokenAlreadyMinted);
+
+			AllTokensIndex::<T>::insert(id, token_id, total_supply + offset);
+			AllTokens::<T>::insert(id, total_supply + offset, token_id);
+			OwnedTokensIndex::<T>::insert(id, (to, token_id), balance + offset);
+			OwnedTokens::<T>::insert(id, (to, balance + offset), token_id);
+			Owners::<T>::insert(id, token_id, to);
+
+			Self::deposit_event(Event::Transfer(id, Self::zero_account_id(), to.clone(), token_id));
+		}
+
+		TotalSupply::<T>::insert(id, new_total_supply);
+		Minted::<T>::insert(id, minted);
+		Balances::<T>::insert(id, to, new_balance);
+
+		Ok(())
+	}
+
+	pub fn do_burn(
+		who: &T::AccountId,
+		id: T::NonFungibleTokenId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		let owner = Self::owner_of(id, token_id).ok_or(Error::<T>::NotFound)?;
+		ensure!(
+			who == &owner || Self::has_role(id, (TokenRole::Burner, who)),
+			Error::<T>::NotTokenOwner
+		);
+		ensure!(!Locks::<T>::contains_key(id, token_id), Error::<T>::TokenLocked);
+		ensure!(
+			Children::<T>::iter_key_prefix((id, token_id)).next().is_none(),
+			Error::<T>::HasChildren
+		);
+
+		let balance = Self::balance_of(id, &owner);
+
+		let new_balance = match balance.checked_sub(One::one()) {
+			Some(c) => c,
+			None => return Err(Error::<T>::Underflow.into()),
+		};
+
+		Self::remove_token_from_all_tokens_enumeration(id, token_id)?;
+		Self::remove_token_from_owner_enumeration(id, &owner, token_id)?;
+
+		Self::clear_approval(id, token_id)?;
+
+		Balances::<T>::insert(id, &owner, new_balance);
+		Owners::<T>::remove(id, token_id);
+		TokenUris::<T>::remove(id, token_id);
+		MetadataFrozen::<T>::remove(id, token_id);
+		TokenRoyalties::<T>::remove(id, token_id);
+		SoulboundTokens::<T>::remove(id, token_id);
+		Users::<T>::remove(id, token_id);
+		if let Some((parent_id, parent_token_id)) = Parents::<T>::take(id, token_id) {
+			Self::remove_child(parent_id, parent_token_id, NestedToken::NonFungible(id, token_id));
+		}
+
+		Self::deposit_event(Event::Transfer(id.clone(), owner, Self::zero_account_id(), token_id));
+
+		Ok(())
+	}
+
+	fn is_approved_or_owner(
+		id: T::NonFungibleTokenId,
+		spender: &T::AccountId,
+		token_id: T::TokenId,
+	) -> Result<bool, DispatchError> {
+		let owner = Self::owner_of(id, token_id).ok_or(Error::<T>::NotFound)?;
+
+		Ok(*spender == owner ||
+			Self::get_approved(id, token_id) == Some(spender.clone()) ||
+			Self::is_approved_for_all(id, (&owner, spender)))
+	}
+
+	pub fn do_grant_role(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_role_admin(id, who, role)?;
+		Self::ensure_live(id)?;
+
+		Roles::<T>::insert(id, (role, account), true);
+
+		Self::deposit_event(Event::RoleGranted(id, role, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_revoke_role(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_role_admin(id, who, role)?;
+
+		Roles::<T>::remove(id, (role, account));
+
+		Self::deposit_event(Event::RoleRevoked(id, role, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_transfer_ownership(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		new_owner: &T::AccountId,
+	) -> DispatchResult {
+		Tokens::<T>::try_mutate(id, |maybe_token| -> DispatchResult {
+			let token = maybe_token.as_mut().ok_or(Error::<T>::InvalidId)?;
+			ensure!(*who == token.owner, Error::<T>::NoPermission);
+			Self::ensure_live(id)?;
+
+			token.owner = new_owner.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::OwnershipTransferred(id, who.clone(), new_owner.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_start_destroy(id: T::NonFungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NoPermission);
+		Self::ensure_live(id)?;
+		ensure!(Children::<T>::iter_key_prefix((id,)).next().is_none(), Error::<T>::HasChildren);
+
+		Destroying::<T>::insert(id, true);
+
+		Self::deposit_event(Event::DestructionStarted(id));
+
+		Ok(())
+	}
+
+	pub fn do_destroy_accounts(id: T::NonFungibleTokenId) -> DispatchResult {
+		ensure!(Self::is_destroying(id), Error::<T>::NotDestroying);
+
+		let limit = T::RemoveItemsLimit::get() as usize;
+		let mut removed = 0;
+		removed += Owners::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Balances::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += TokenApprovals::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += OperatorApprovals::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += AllTokens::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += AllTokensIndex::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += OwnedTokens::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += OwnedTokensIndex::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Roles::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Frozen::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += TokenRoyalties::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += SoulboundTokens::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Locks::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += Users::<T>::drain_prefix(id).take(limit - removed).count();
+		for (token_id, (parent_id, parent_token_id)) in
+			Parents::<T>::drain_prefix(id).take(limit - removed)
+		{
+			Self::remove_child(parent_id, parent_token_id, NestedToken::NonFungible(id, token_id));
+			removed += 1;
+		}
+		removed += TokenUris::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += MetadataFrozen::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += RedeemedVouchers::<T>::drain_prefix(id).take(limit - removed).count();
+		removed += PhaseMinted::<T>::drain_prefix(id).take(limit - removed).count();
+		for (_, (_, (depositor, deposit))) in
+			Attributes::<T>::drain_prefix((id,)).take(limit - removed)
+		{
+			T::Currency::unreserve(&depositor, deposit);
+			removed += 1;
+		}
+
+		Self::deposit_event(Event::AccountsDestroyed(id, removed as u32));
+
+		Ok(())
+	}
+
+	pub fn do_finish_destroy(id: T::NonFungibleTokenId) -> DispatchResult {
+		ensure!(Self::is_destroying(id), Error::<T>::NotDestroying);
+		ensure!(
+			Owners::<T>::iter_prefix(id).next().is_none() &&
+				Balances::<T>::iter_prefix(id).next().is_none() &&
+				TokenApprovals::<T>::iter_prefix(id).next().is_none() &&
+				OperatorApprovals::<T>::iter_prefix(id).next().is_none() &&
+				AllTokens::<T>::iter_prefix(id).next().is_none() &&
+				AllTokensIndex::<T>::iter_prefix(id).next().is_none() &&
+				OwnedTokens::<T>::iter_prefix(id).next().is_none() &&
+				OwnedTokensIndex::<T>::iter_prefix(id).next().is_none() &&
+				Roles::<T>::iter_prefix(id).next().is_none() &&
+				Frozen::<T>::iter_prefix(id).next().is_none() &&
+				TokenRoyalties::<T>::iter_prefix(id).next().is_none() &&
+				SoulboundTokens::<T>::iter_prefix(id).next().is_none() &&
+				Locks::<T>::iter_prefix(id).next().is_none() &&
+				Users::<T>::iter_prefix(id).next().is_none() &&
+				Parents::<T>::iter_prefix(id).next().is_none() &&
+				TokenUris::<T>::iter_prefix(id).next().is_none() &&
+				MetadataFrozen::<T>::iter_prefix(id).next().is_none() &&
+				RedeemedVouchers::<T>::iter_prefix(id).next().is_none() &&
+				PhaseMinted::<T>::iter_prefix(id).next().is_none() &&
+				Attributes::<T>::iter_prefix((id,)).next().is_none(),
+			Error::<T>::AccountsRemaining
+		);
+
+		Tokens::<T>::remove(id);
+		TotalSupply::<T>::remove(id);
+		FrozenTokens::<T>::remove(id);
+		Royalties::<T>::remove(id);
+		SoulboundCollections::<T>::remove(id);
+		CollectionMetadataFrozen::<T>::remove(id);
+		MaxSupply::<T>::remove(id);
+		Minted::<T>::remove(id);
+		MintPhases::<T>::remove(id);
+		Destroying::<T>::remove(id);
+		if let Some((depositor, deposit)) = Deposits::<T>::take(id) {
+			T::Currency::unreserve(&depositor, deposit);
+		}
+
+		Self::deposit_event(Event::Destroyed(id));
+
+		Ok(())
+	}
+
+	pub fn do_freeze(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+		Self::ensure_live(id)?;
+
+		Frozen::<T>::insert(id, account, true);
+
+		Self::deposit_event(Event::AccountFrozen(id, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_thaw(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		account: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+
+		Frozen::<T>::remove(id, account);
+
+		Self::deposit_event(Event::AccountThawed(id, account.clone()));
+
+		Ok(())
+	}
+
+	pub fn do_freeze_token(id: T::NonFungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+		Self::ensure_live(id)?;
+
+		FrozenTokens::<T>::insert(id, true);
+
+		Self::deposit_event(Event::TokenFrozen(id));
+
+		Ok(())
+	}
+
+	pub fn do_thaw_token(id: T::NonFungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Freezer)?;
+
+		FrozenTokens::<T>::remove(id);
+
+		Self::deposit_event(Event::TokenThawed(id));
+
+		Ok(())
+	}
+
+	pub fn do_set_attribute(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+		key: Vec<u8>,
+		value: Vec<u8>,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Metadata)?;
+		Self::ensure_live(id)?;
+		ensure!(Self::token_exists(id, token_id), Error::<T>::TokenNonExistent);
+		Self::ensure_metadata_mutable(id, token_id)?;
+
+		let bounded_key: BoundedVec<u8, T::KeyLimit> =
+			key.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+		let bounded_value: BoundedVec<u8, T::ValueLimit> =
+			value.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+
+		if let Some((_, (depositor, deposit))) = Attributes::<T>::get((id, token_id, &bounded_key))
+		{
+			T::Currency::unreserve(&depositor, deposit);
+		}
+		let deposit = T::DepositPerByte::get()
+			.saturating_mul(((bounded_key.len() + bounded_value.len()) as u32).into())
+			.saturating_add(T::AttributeDepositBase::get());
+		T::Currency::reserve(who, deposit)?;
+
+		Attributes::<T>::insert(
+			(id, token_id, bounded_key),
+			(bounded_value, (who.clone(), deposit)),
+		);
+
+		Self::deposit_event(Event::AttributeSet(id, token_id, key, value));
+
+		Ok(())
+	}
+
+	pub fn do_clear_attribute(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+		key: Vec<u8>,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Metadata)?;
+		Self::ensure_metadata_mutable(id, token_id)?;
+
+		let bounded_key: BoundedVec<u8, T::KeyLimit> =
+			key.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+		let (_, (depositor, deposit)) =
+			Attributes::<T>::take((id, token_id, bounded_key)).ok_or(Error::<T>::NotFound)?;
+		T::Currency::unreserve(&depositor, deposit);
+
+		Self::deposit_event(Event::AttributeCleared(id, token_id, key));
+
+		Ok(())
+	}
+
+	pub fn do_set_token_uri(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		token_id: T::TokenId,
+		uri: Vec<u8>,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Metadata)?;
+		Self::ensure_live(id)?;
+		ensure!(Self::token_exists(id, token_id), Error::<T>::TokenNonExistent);
+		Self::ensure_metadata_mutable(id, token_id)?;
+
+		if uri.is_empty() {
+			TokenUris::<T>::remove(id, token_id);
+		} else {
+			let bounded_uri: BoundedVec<u8, T::StringLimit> =
+				uri.clone().try_into().map_err(|_| Error::<T>::BadMetadata)?;
+			TokenUris::<T>::insert(id, token_id, bounded_uri);
+		}
+
+		Self::deposit_event(Event::TokenUriSet(id, token_id, uri));
+
+		Ok(())
+	}
+
+	pub fn do_freeze_metadata(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		token_id: Option<T::TokenId>,
+	) -> DispatchResult {
+		Self::ensure_owner_or_role(id, who, TokenRole::Metadata)?;
+		Self::ensure_live(id)?;
+
+		match token_id {
+			Some(token_id) => {
+				ensure!(Self::token_exists(id, token_id), Error::<T>::TokenNonExistent);
+				Self::ensure_metadata_mutable(id, token_id)?;
+				MetadataFrozen::<T>::insert(id, token_id, true);
+			},
+			None => {
+				ensure!(!Self::is_collection_metadata_frozen(id), Error::<T>::MetadataFrozen);
+				CollectionMetadataFrozen::<T>::insert(id, true);
+			},
+		}
+
+		Self::deposit_event(Event::MetadataFrozen(id, token_id));
+
+		Ok(())
+	}
+
+	/// All attributes of a token as `(key, value)` pairs.
+	pub fn attributes(id: T::NonFungibleTokenId, token_id: T::TokenId) -> Vec<(Vec<u8>, Vec<u8>)> {
+		Attributes::<T>::iter_prefix((id, token_id))
+			.map(|(key, (value, _))| (key.to_vec(), value.to_vec()))
+			.collect()
+	}
+
+	fn ensure_not_frozen(id: T::NonFungibleTokenId, who: &T::AccountId) -> DispatchResult {
+		ensure!(!Self::is_token_frozen(id), Error::<T>::TokenFrozen);
+		ensure!(!Self::is_frozen(id, who), Error::<T>::AccountFrozen);
+
+		Ok(())
+	}
+
+	fn ensure_metadata_mutable(id: T::NonFungibleTokenId, token_id: T::TokenId) -> DispatchResult {
+		ensure!(
+			!Self::is_collection_metadata_frozen(id) && !Self::is_metadata_frozen(id, token_id),
+			Error::<T>::MetadataFrozen
+		);
+
+		Ok(())
+	}
+
+	pub fn do_set_royalty(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		token_id: Option<T::TokenId>,
+		receiver: T::AccountId,
+		basis_points: u16,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NoPermission);
+		Self::ensure_live(id)?;
+		ensure!(basis_points <= 10_000, Error::<T>::InvalidRoyalty);
+
+		match token_id {
+			// A zero override still takes precedence over the collection royalty.
+			Some(token_id) =>
+				TokenRoyalties::<T>::insert(id, token_id, (receiver.clone(), basis_points)),
+			None => Royalties::<T>::set(
+				id,
+				Some((receiver.clone(), basis_points)).filter(|_| basis_points > 0),
+			),
+		}
+
+		Self::deposit_event(Event::RoyaltySet(id, token_id, receiver, basis_points));
 
 		Ok(())
 	}
 
-	pub fn do_approve(
+	pub fn do_clear_token_royalty(
+		id: T::NonFungibleTokenId,
 		who: &T::AccountId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NoPermission);
+		Self::ensure_live(id)?;
+		ensure!(TokenRoyalties::<T>::contains_key(id, token_id), Error::<T>::NoTokenRoyalty);
+
+		TokenRoyalties::<T>::remove(id, token_id);
+
+		Self::deposit_event(Event::TokenRoyaltyCleared(id, token_id));
+
+		Ok(())
+	}
+
+	/// The royalty receiver and basis points of a token, falling back to the collection royalty.
+	pub fn royalty(id: T::NonFungibleTokenId, token_id: T::TokenId) -> Option<(T::AccountId, u16)> {
+		TokenRoyalties::<T>::get(id, token_id).or_else(|| Royalties::<T>::get(id))
+	}
+
+	/// The royalty receiver and the amount owed to them for a sale at `sale_price`.
+	pub fn royalty_info<B: AtLeast32BitUnsigned>(
 		id: T::NonFungibleTokenId,
-		to: &T::AccountId,
 		token_id: T::TokenId,
+		sale_price: B,
+	) -> Option<(T::AccountId, B)> {
+		let (receiver, basis_points) = Self::royalty(id, token_id)?;
+		let royalty = Permill::from_parts(u32::from(basis_points) * 100).mul_floor(sale_price);
+		Some((receiver, royalty))
+	}
+
+	pub fn do_set_soulbound(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		token_id: Option<T::TokenId>,
+		soulbound: bool,
 	) -> DispatchResult {
-		let owner = Self::owner_of(id, token_id).ok_or(Error::<T>::NotFound)?;
-		ensure!(to != &owner, Error::<T>::ApproveToCurrentOwner);
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NoPermission);
+		Self::ensure_live(id)?;
+
+		match (token_id, soulbound) {
+			(Some(token_id), true) => SoulboundTokens::<T>::insert(id, token_id, true),
+			(Some(token_id), false) => SoulboundTokens::<T>::remove(id, token_id),
+			(None, true) => SoulboundCollections::<T>::insert(id, true),
+			(None, false) => SoulboundCollections::<T>::remove(id),
+		}
 
-		ensure!(
-			who == &owner || Self::is_approved_for_all(id, (&owner, who)),
-			Error::<T>::NotOwnerOrApproved
-		);
+		Self::deposit_event(Event::SoulboundSet(id, token_id, soulbound));
 
-		TokenApprovals::<T>::insert(id, token_id, to);
+		Ok(())
+	}
 
-		Self::deposit_event(Event::Approval(id, owner, to.clone(), token_id));
+	/// Whether a token is bound to its holders, by its own flag or by its collection's.
+	pub fn is_soulbound(id: T::NonFungibleTokenId, token_id: T::TokenId) -> bool {
+		Self::is_soulbound_collection(id) || Self::is_soulbound_token(id, token_id)
+	}
+
+	/// Lock a token so that it can not be transferred or burned until `locker` unlocks it.
+	///
+	/// The token stays with its owner. Callers must check that the owner agreed to the lock.
+	pub fn lock(
+		id: T::NonFungibleTokenId,
+		token_id: T::TokenId,
+		locker: LockIdentifier,
+	) -> DispatchResult {
+		Self::ensure_live(id)?;
+		ensure!(Self::token_exists(id, token_id), Error::<T>::TokenNonExistent);
+		ensure!(!Locks::<T>::contains_key(id, token_id), Error::<T>::TokenLocked);
+
+		Locks::<T>::insert(id, token_id, locker);
+
+		Self::deposit_event(Event::Locked(id, token_id, locker));
 
 		Ok(())
 	}
 
-	pub fn do_set_approve_for_all(
-		who: &T::AccountId,
+	/// Remove the lock `locker` put on a token.
+	pub fn unlock(
 		id: T::NonFungibleTokenId,
-		operator: &T::AccountId,
-		approved: bool,
+		token_id: T::TokenId,
+		locker: LockIdentifier,
 	) -> DispatchResult {
-		ensure!(operator != who, Error::<T>::ApproveToCaller);
+		ensure!(Self::locker_of(id, token_id) == Some(locker), Error::<T>::NotLocked);
 
-		OperatorApprovals::<T>::insert(id, (who, operator), approved);
+		Locks::<T>::remove(id, token_id);
 
-		Self::deposit_event(Event::ApprovalForAll(id, who.clone(), operator.clone(), approved));
+		Self::deposit_event(Event::Unlocked(id, token_id, locker));
 
 		Ok(())
 	}
 
-	pub fn do_transfer_from(
-		who: &T::AccountId,
+	pub fn do_set_user(
 		id: T::NonFungibleTokenId,
-		from: &T::AccountId,
-		to: &T::AccountId,
+		who: &T::AccountId,
 		token_id: T::TokenId,
+		user: Option<T::AccountId>,
+		expires: T::BlockNumber,
 	) -> DispatchResult {
+		Self::ensure_live(id)?;
 		ensure!(Self::token_exists(id, token_id), Error::<T>::TokenNonExistent);
-		ensure!(
-			Self::is_approved_or_owner(id, &who, token_id).unwrap(),
-			Error::<T>::NotOwnerOrApproved
-		);
-		Self::do_transfer(id, from, to, token_id)?;
+		ensure!(Self::is_approved_or_owner(id, who, token_id)?, Error::<T>::NotOwnerOrApproved);
+
+		Users::<T>::set(id, token_id, user.clone().map(|user| (user, expires)));
+
+		Self::deposit_event(Event::UserSet(id, token_id, user, expires));
+
 		Ok(())
 	}
 
-	pub fn do_transfer(
+	/// The account allowed to use a token, if its right has not expired.
+	pub fn user_of(id: T::NonFungibleTokenId, token_id: T::TokenId) -> Option<T::AccountId> {
+		let now = frame_system::Pallet::<T>::block_number();
+		Users::<T>::get(id, token_id)
+			.filter(|(_, expires)| *expires >= now)
+			.map(|(user, _)| user)
+	}
+
+	/// The block the right of the user of a token expires at, zero if it has no user.
+	pub fn user_expires(id: T::NonFungibleTokenId, token_id: T::TokenId) -> T::BlockNumber {
+		Users::<T>::get(id, token_id)
+			.map(|(_, expires)| expires)
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// The account holding the tokens nested in a non-fungible token.
+	pub fn token_account_id(id: T::NonFungibleTokenId, token_id: T::TokenId) -> T::AccountId {
+		let entropy = (T::PalletId::get(), id, token_id).using_encoded(blake2_256);
+		T::AccountId::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite input; qed")
+	}
+
+	/// The tokens a token is nested in, from its parent up to the root of its tree.
+	pub fn ancestors_of(
 		id: T::NonFungibleTokenId,
-		from: &T::AccountId,
-		to: &T::AccountId,
 		token_id: T::TokenId,
-	) -> DispatchResult {
-		ensure!(
-			Owners::<T>::get(id, token_id) == Some(from.clone()),
-			Error::<T>::TransferTokenNotOwn
+	) -> Vec<(T::NonFungibleTokenId, T::TokenId)> {
+		let mut ancestors = Vec::new();
+		let mut current = (id, token_id);
+		while let Some(parent) = Self::parent_of(current.0, current.1) {
+			if ancestors.len() as u32 >= T::MaxNestingDepth::get() {
+				break
+			}
+			ancestors.push(parent);
+			current = parent;
+		}
+		ancestors
+	}
+
+	/// The owner of a token, or of the root of the tree it is nested in.
+	pub fn root_owner_of(id: T::NonFungibleTokenId, token_id: T::TokenId) -> Option<T::AccountId> {
+		let (root_id, root_token_id) =
+			Self::ancestors_of(id, token_id).last().copied().unwrap_or((id, token_id));
+		Self::owner_of(root_id, root_token_id)
+	}
+
+	/// The tokens nested in a token down to `depth` levels, each with its level and balance.
+	pub fn nested_tokens(
+		id: T::NonFungibleTokenId,
+		token_id: T::TokenId,
+		depth: u32,
+	) -> Vec<(u32, NestedTokenOf<T>, Balance)> {
+		let mut tokens = Vec::new();
+		Self::collect_nested_tokens(
+			id,
+			token_id,
+			1,
+			depth.min(T::MaxNestingDepth::get()),
+			&mut tokens,
 		);
+		tokens
+	}
 
-		if from == to {
+	fn collect_nested_tokens(
+		id: T::NonFungibleTokenId,
+		token_id: T::TokenId,
+		level: u32,
+		depth: u32,
+		tokens: &mut Vec<(u32, NestedTokenOf<T>, Balance)>,
+	) {
+		if level > depth {
+			return
+		}
+		let account = Self::token_account_id(id, token_id);
+		for child in Children::<T>::iter_key_prefix((id, token_id)) {
+			match child {
+				NestedToken::NonFungible(child_id, child_token_id) => {
+					tokens.push((level, child, 1));
+					Self::collect_nested_tokens(child_id, child_token_id, level + 1, depth, tokens);
+				},
+				NestedToken::Multi(multi_token_id, child_token_id) => {
+					let balance =
+						T::MultiToken::balance_of(multi_token_id, child_token_id, &account);
+					if balance > 0 {
+						tokens.push((level, child, balance));
+					}
+				},
+			}
+		}
+	}
+
+	/// Record `child` as nested in a token, and raise the heights of the token and its ancestors.
+	fn add_child(
+		parent_id: T::NonFungibleTokenId,
+		parent_token_id: T::TokenId,
+		child: NestedTokenOf<T>,
+	) -> DispatchResult {
+		if Children::<T>::contains_key((parent_id, parent_token_id, child)) {
 			return Ok(())
 		}
+		ChildCount::<T>::try_mutate(parent_id, parent_token_id, |count| -> DispatchResult {
+			ensure!(*count < T::MaxChildren::get(), Error::<T>::TooManyChildren);
+			*count += 1;
+			Ok(())
+		})?;
+		Children::<T>::insert((parent_id, parent_token_id, child), ());
+
+		if let NestedToken::NonFungible(id, token_id) = child {
+			let mut height = Self::height_of(id, token_id) + 1;
+			let mut current = (parent_id, parent_token_id);
+			for _ in 0..=T::MaxNestingDepth::get() {
+				if Self::height_of(current.0, current.1) >= height {
+					break
+				}
+				Heights::<T>::insert(current.0, current.1, height);
+				match Self::parent_of(current.0, current.1) {
+					Some(parent) => current = parent,
+					None => break,
+				}
+				height += 1;
+			}
+		}
+		Ok(())
+	}
 
-		let balance_from = Self::balance_of(id, from);
-		let balance_to = Self::balance_of(id, to);
+	/// Forget `child` as nested in a token, and lower the heights of the token and its ancestors.
+	fn remove_child(
+		parent_id: T::NonFungibleTokenId,
+		parent_token_id: T::TokenId,
+		child: NestedTokenOf<T>,
+	) {
+		if Children::<T>::take((parent_id, parent_token_id, child)).is_none() {
+			return
+		}
+		ChildCount::<T>::mutate_exists(parent_id, parent_token_id, |count| {
+			*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+		});
+
+		if let NestedToken::NonFungible(..) = child {
+			let mut current = (parent_id, parent_token_id);
+			for _ in 0..=T::MaxNestingDepth::get() {
+				let height = Children::<T>::iter_key_prefix(current)
+					.filter_map(|child| match child {
+						NestedToken::NonFungible(id, token_id) =>
+							Some(Self::height_of(id, token_id) + 1),
+						NestedToken::Multi(..) => None,
+					})
+					.max()
+					.unwrap_or(0);
+				if height == Self::height_of(current.0, current.1) {
+					break
+				}
+				if height == 0 {
+					Heights::<T>::remove(current.0, current.1);
+				} else {
+					Heights::<T>::insert(current.0, current.1, height);
+				}
+				match Self::parent_of(current.0, current.1) {
+					Some(parent) => current = parent,
+					None => break,
+				}
+			}
+		}
+	}
 
-		let new_balance_from = match balance_from.checked_sub(1) {
-			Some(c) => c,
-			None => return Err(Error::<T>::Underflow.into()),
-		};
+	/// Check that `who` owns the tree a token is in, and return the ancestors of the token.
+	fn ensure_root_owner(
+		id: T::NonFungibleTokenId,
+		token_id: T::TokenId,
+		who: &T::AccountId,
+	) -> Result<Vec<(T::NonFungibleTokenId, T::TokenId)>, DispatchError> {
+		let ancestors = Self::ancestors_of(id, token_id);
+		let (root_id, root_token_id) = ancestors.last().copied().unwrap_or((id, token_id));
+		let owner = Self::owner_of(root_id, root_token_id).ok_or(Error::<T>::TokenNonExistent)?;
+		ensure!(owner == *who, Error::<T>::NoPermission);
 
-		let new_balance_to = match balance_to.checked_add(1) {
-			Some(c) => c,
-			None => return Err(Error::<T>::Overflow.into()),
-		};
+		Ok(ancestors)
+	}
 
-		Self::remove_token_from_owner_enumeration(id, from, token_id)?;
-		Self::add_token_to_owner_enumeration(id, to, token_id)?;
+	pub fn do_nest(
+		who: &T::AccountId,
+		parent_id: T::NonFungibleTokenId,
+		parent_token_id: T::TokenId,
+		id: T::NonFungibleTokenId,
+		token_id: T::TokenId,
+	) -> DispatchResult {
+		Self::ensure_live(parent_id)?;
+		let ancestors = Self::ensure_root_owner(parent_id, parent_token_id, who)?;
+		ensure!(
+			(parent_id, parent_token_id) != (id, token_id) && !ancestors.contains(&(id, token_id)),
+			Error::<T>::NestingLoop
+		);
+		let max_depth = T::MaxNestingDepth::get();
+		ensure!(
+			ancestors.len() as u32 + 1 + Self::height_of(id, token_id) <= max_depth,
+			Error::<T>::NestingTooDeep
+		);
 
-		Self::clear_approval(id, token_id)?;
+		Self::do_transfer(id, who, &Self::token_account_id(parent_id, parent_token_id), token_id)?;
+		Parents::<T>::insert(id, token_id, (parent_id, parent_token_id));
+		let child = NestedToken::NonFungible(id, token_id);
+		Self::add_child(parent_id, parent_token_id, child)?;
 
-		Balances::<T>::insert(id, from, new_balance_from);
-		Balances::<T>::insert(id, to, new_balance_to);
-		Owners::<T>::insert(id, token_id, to);
+		Self::deposit_event(Event::TokenNested(parent_id, parent_token_id, child, 1));
 
-		Self::deposit_event(Event::Transfer(id.clone(), from.clone(), to.clone(), token_id));
+		Ok(())
+	}
+
+	pub fn do_nest_multi(
+		who: &T::AccountId,
+		parent_id: T::NonFungibleTokenId,
+		parent_token_id: T::TokenId,
+		multi_token_id: MultiTokenIdOf<T>,
+		token_id: T::TokenId,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::ensure_live(parent_id)?;
+		let ancestors = Self::ensure_root_owner(parent_id, parent_token_id, who)?;
+		ensure!(ancestors.len() as u32 < T::MaxNestingDepth::get(), Error::<T>::NestingTooDeep);
+
+		let account = Self::token_account_id(parent_id, parent_token_id);
+		T::MultiToken::transfer(multi_token_id, token_id, who, &account, amount)?;
+		let child = NestedToken::Multi(multi_token_id, token_id);
+		Self::add_child(parent_id, parent_token_id, child)?;
+
+		Self::deposit_event(Event::TokenNested(parent_id, parent_token_id, child, amount));
 
 		Ok(())
 	}
 
-	pub fn do_mint(
+	pub fn do_unnest(
 		who: &T::AccountId,
+		parent_id: T::NonFungibleTokenId,
+		parent_token_id: T::TokenId,
 		id: T::NonFungibleTokenId,
-		to: &T::AccountId,
 		token_id: T::TokenId,
+		to: &T::AccountId,
 	) -> DispatchResult {
-		ensure!(Self::exists(id), Error::<T>::InvalidId);
-		ensure!(Self::has_permission(id, who), Error::<T>::NoPermission);
-		ensure!(!Self::token_exists(id, token_id), Error::<T>::TokenAlreadyMinted);
+		let account = Self::token_account_id(parent_id, parent_token_id);
+		ensure!(Self::owner_of(id, token_id) == Some(account.clone()), Error::<T>::NotNested);
+		Self::ensure_root_owner(parent_id, parent_token_id, who)?;
 
-		let balance = Self::balance_of(id, to);
+		Self::do_transfer(id, &account, to, token_id)?;
+		Parents::<T>::remove(id, token_id);
+		let child = NestedToken::NonFungible(id, token_id);
+		Self::remove_child(parent_id, parent_token_id, child);
 
-		let new_balance = match balance.checked_add(One::one()) {
-			Some(c) => c,
-			None => return Err(Error::<T>::Overflow.into()),
-		};
+		Self::deposit_event(Event::TokenUnnested(parent_id, parent_token_id, child, to.clone(), 1));
 
-		Self::add_token_to_all_tokens_enumeration(id, token_id)?;
-		Self::add_token_to_owner_enumeration(id, to, token_id)?;
+		Ok(())
+	}
 
-		Balances::<T>::insert(id, to, new_balance);
-		Owners::<T>::insert(id, token_id, to);
+	pub fn do_unnest_multi(
+		who: &T::AccountId,
+		parent_id: T::NonFungibleTokenId,
+		parent_token_id: T::TokenId,
+		multi_token_id: MultiTokenIdOf<T>,
+		token_id: T::TokenId,
+		amount: Balance,
+		to: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_root_owner(parent_id, parent_token_id, who)?;
 
-		Self::deposit_event(Event::Transfer(
-			id.clone(),
-			Self::zero_account_id(),
+		let account = Self::token_account_id(parent_id, parent_token_id);
+		T::MultiToken::transfer(multi_token_id, token_id, &account, to, amount)?;
+		let child = NestedToken::Multi(multi_token_id, token_id);
+		if T::MultiToken::balance_of(multi_token_id, token_id, &account) == 0 {
+			Self::remove_child(parent_id, parent_token_id, child);
+		}
+
+		Self::deposit_event(Event::TokenUnnested(
+			parent_id,
+			parent_token_id,
+			child,
 			to.clone(),
-			token_id,
+			amount,
 		));
 
 		Ok(())
 	}
 
-	pub fn do_burn(
+	pub fn do_redeem_voucher(
 		who: &T::AccountId,
 		id: T::NonFungibleTokenId,
-		token_id: T::TokenId,
+		voucher: MintVoucherOf<T>,
+		signature: [u8; 65],
 	) -> DispatchResult {
-		let owner = Self::owner_of(id, token_id).ok_or(Error::<T>::NotFound)?;
-		ensure!(who == &owner, Error::<T>::NotTokenOwner);
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= voucher.expiry,
+			Error::<T>::VoucherExpired
+		);
+		if let Some(recipient) = voucher.recipient {
+			ensure!(T::AddressMapping::convert(recipient) == *who, Error::<T>::NotVoucherRecipient);
+		}
 
-		let balance = Self::balance_of(id, &owner);
+		let digest = Self::voucher_digest(id, &voucher);
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+			.map(|public| H160::from_slice(&keccak_256(&public)[12..]))
+			.map_err(|_| Error::<T>::InvalidSignature)?;
+		ensure!(
+			!Self::is_voucher_redeemed(id, (signer, voucher.nonce)),
+			Error::<T>::VoucherRedeemed
+		);
 
-		let new_balance = match balance.checked_sub(One::one()) {
-			Some(c) => c,
-			None => return Err(Error::<T>::Underflow.into()),
-		};
+		Self::do_mint(&T::AddressMapping::convert(signer), id, who, voucher.token_id)?;
+		Self::pay(who, &token.owner, voucher.currency, voucher.price)?;
+		RedeemedVouchers::<T>::insert(id, (signer, voucher.nonce), true);
 
-		Self::remove_token_from_all_tokens_enumeration(id, token_id)?;
-		Self::remove_token_from_owner_enumeration(id, &owner, token_id)?;
+		Self::deposit_event(Event::VoucherRedeemed(
+			id,
+			voucher.token_id,
+			who.clone(),
+			signer,
+			voucher.nonce,
+		));
 
-		Self::clear_approval(id, token_id)?;
+		Ok(())
+	}
 
-		Balances::<T>::insert(id, &owner, new_balance);
-		Owners::<T>::remove(id, token_id);
+	/// Pay `price` from `who` to `to` in `currency`, or in the native currency if `None`.
+	fn pay(
+		who: &T::AccountId,
+		to: &T::AccountId,
+		currency: Option<FungibleTokenIdOf<T>>,
+		price: Balance,
+	) -> DispatchResult {
+		if price.is_zero() {
+			return Ok(())
+		}
+		match currency {
+			Some(currency) => T::Fungibles::transfer(currency, who, to, price, false).map(|_| ()),
+			None => T::Currency::transfer(
+				who,
+				to,
+				price.saturated_into(),
+				ExistenceRequirement::AllowDeath,
+			),
+		}
+	}
 
-		Self::deposit_event(Event::Transfer(id.clone(), owner, Self::zero_account_id(), token_id));
+	pub fn do_set_max_supply(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		max_supply: u32,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NoPermission);
+		Self::ensure_live(id)?;
+		ensure!(!MaxSupply::<T>::contains_key(id), Error::<T>::MaxSupplyAlreadySet);
+		ensure!(max_supply >= Minted::<T>::get(id), Error::<T>::MaxSupplyTooLow);
+
+		MaxSupply::<T>::insert(id, max_supply);
+
+		Self::deposit_event(Event::MaxSupplySet(id, max_supply));
 
 		Ok(())
 	}
 
-	fn is_approved_or_owner(
+	pub fn do_set_mint_phases(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		phases: Vec<MintPhaseOf<T>>,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner, Error::<T>::NoPermission);
+		Self::ensure_live(id)?;
+		ensure!(phases.iter().all(|phase| phase.start <= phase.end), Error::<T>::InvalidMintPhase);
+		let phases: BoundedVec<_, T::MaxMintPhases> =
+			phases.try_into().map_err(|_| Error::<T>::TooManyMintPhases)?;
+		let count = phases.len() as u32;
+
+		MintPhases::<T>::insert(id, phases);
+
+		Self::deposit_event(Event::MintPhasesSet(id, count));
+
+		Ok(())
+	}
+
+	/// The index and details of the mint phase open at the current block, the first one if
+	/// several phases overlap.
+	pub fn active_mint_phase(id: T::NonFungibleTokenId) -> Option<(u32, MintPhaseOf<T>)> {
+		let now = frame_system::Pallet::<T>::block_number();
+		Self::mint_phases(id)
+			.into_iter()
+			.enumerate()
+			.find(|(_, phase)| phase.start <= now && now <= phase.end)
+			.map(|(index, phase)| (index as u32, phase))
+	}
+
+	pub fn do_public_mint(
+		who: &T::AccountId,
 		id: T::NonFungibleTokenId,
-		spender: &T::AccountId,
 		token_id: T::TokenId,
-	) -> Result<bool, DispatchError> {
-		let owner = Self::owner_of(id, token_id).ok_or(Error::<T>::NotFound)?;
+		proof: &[H256],
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		Self::ensure_live(id)?;
+		let (index, phase) = Self::active_mint_phase(id).ok_or(Error::<T>::NoActiveMintPhase)?;
+		if let Some(root) = phase.allowlist {
+			let leaf = H256::from(keccak_256(&who.encode()));
+			ensure!(Self::verify_proof(root, leaf, proof), Error::<T>::NotAllowlisted);
+		}
+		let minted = Self::phase_minted(id, (index, who));
+		ensure!(minted < phase.wallet_limit, Error::<T>::WalletLimitReached);
 
-		Ok(*spender == owner ||
-			Self::get_approved(id, token_id) == Some(spender.clone()) ||
-			Self::is_approved_for_all(id, (&owner, spender)))
+		Self::mint_token(id, who, token_id)?;
+		Self::pay(who, &token.owner, phase.currency, phase.price)?;
+		PhaseMinted::<T>::insert(id, (index, who.clone()), minted + 1);
+
+		Self::deposit_event(Event::PublicMinted(id, index, who.clone(), token_id));
+
+		Ok(())
+	}
+
+	/// Check `proof` leads from `leaf` to `root`, hashing each pair in sorted order.
+	pub fn verify_proof(root: H256, leaf: H256, proof: &[H256]) -> bool {
+		let computed = proof.iter().fold(leaf, |hash, node| {
+			let (first, second) = if hash <= *node { (hash, *node) } else { (*node, hash) };
+			H256::from(keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+		});
+		computed == root
 	}
 
-	fn has_permission(id: T::NonFungibleTokenId, who: &T::AccountId) -> bool {
-		let token = Tokens::<T>::get(id).unwrap();
-		*who == token.owner
+	/// The EIP-712 domain separator of a token.
+	pub fn domain_separator(id: T::NonFungibleTokenId) -> H256 {
+		let name = Tokens::<T>::get(id).map(|token| token.name.to_vec()).unwrap_or_default();
+		let mut data = Vec::with_capacity(5 * 32);
+		data.extend_from_slice(&keccak_256(EIP712_DOMAIN_TYPE));
+		data.extend_from_slice(&keccak_256(&name));
+		data.extend_from_slice(&keccak_256(VOUCHER_VERSION));
+		data.extend_from_slice(&Self::abi_encode_uint(T::ChainId::get().into()));
+		data.extend_from_slice(&Self::abi_encode_address(T::TokenIdConversion::into_address(id)));
+		H256::from(keccak_256(&data))
+	}
+
+	/// The EIP-712 digest a minter signs to issue a voucher.
+	pub fn voucher_digest(id: T::NonFungibleTokenId, voucher: &MintVoucherOf<T>) -> [u8; 32] {
+		let token_id: u128 = voucher.token_id.unique_saturated_into();
+		let currency = voucher
+			.currency
+			.map(T::FungibleTokenIdConversion::into_address)
+			.unwrap_or_default();
+		let expiry: u128 = voucher.expiry.unique_saturated_into();
+		let mut data = Vec::with_capacity(7 * 32);
+		data.extend_from_slice(&keccak_256(MINT_VOUCHER_TYPE));
+		data.extend_from_slice(&Self::abi_encode_uint(token_id.into()));
+		data.extend_from_slice(&Self::abi_encode_address(voucher.recipient.unwrap_or_default()));
+		data.extend_from_slice(&Self::abi_encode_address(currency));
+		data.extend_from_slice(&Self::abi_encode_uint(voucher.price.into()));
+		data.extend_from_slice(&Self::abi_encode_uint(expiry.into()));
+		data.extend_from_slice(&Self::abi_encode_uint(voucher.nonce.into()));
+		let struct_hash = keccak_256(&data);
+
+		let mut message = Vec::with_capacity(2 + 2 * 32);
+		message.extend_from_slice(b"\x19\x01");
+		message.extend_from_slice(Self::domain_separator(id).as_bytes());
+		message.extend_from_slice(&struct_hash);
+		keccak_256(&message)
+	}
+
+	fn abi_encode_uint(value: U256) -> [u8; 32] {
+		let mut data = [0u8; 32];
+		value.to_big_endian(&mut data);
+		data
+	}
+
+	fn abi_encode_address(address: H160) -> [u8; 32] {
+		let mut data = [0u8; 32];
+		data[12..32].copy_from_slice(address.as_bytes());
+		data
+	}
+
+	fn ensure_live(id: T::NonFungibleTokenId) -> DispatchResult {
+		ensure!(!Self::is_destroying(id), Error::<T>::TokenDestroying);
+
+		Ok(())
+	}
+
+	/// The owner manages every role, admins manage every role but `Admin`.
+	fn ensure_role_admin(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(
+			*who == token.owner ||
+				(role != TokenRole::Admin && Self::has_role(id, (TokenRole::Admin, who))),
+			Error::<T>::NoPermission
+		);
+
+		Ok(())
+	}
+
+	fn ensure_owner_or_role(
+		id: T::NonFungibleTokenId,
+		who: &T::AccountId,
+		role: TokenRole,
+	) -> DispatchResult {
+		let token = Tokens::<T>::get(id).ok_or(Error::<T>::InvalidId)?;
+		ensure!(*who == token.owner || Self::has_role(id, (role, who)), Error::<T>::NoPermission);
+
+		Ok(())
+	}
+
+	/// Whether `who` can mint `id`, as its owner or a `Minter`.
+	pub fn has_permission(id: T::NonFungibleTokenId, who: &T::AccountId) -> bool {
+		Tokens::<T>::get(id).map_or(false, |token| {
+			*who == token.owner || Self::has_role(id, (TokenRole::Minter, who))
+		})
 	}
 
 	fn clear_approval(id: T::NonFungibleTokenId, token_id: T::TokenId) -> DispatchResult {
@@ -631,11 +2556,23 @@ where
 	}
 
 	fn token_uri(id: Self::NonFungibleTokenId, token_id: Self::TokenId) -> Vec<u8> {
+		if let Some(uri) = TokenUris::<T>::get(id, token_id) {
+			return uri.to_vec()
+		}
 		let base_uri_buf: Vec<u8> = Tokens::<T>::get(id).unwrap().base_uri.to_vec();
 		let token_id: u128 = token_id.into();
 		let token_id_buf: Vec<u8> = token_id.to_string().as_bytes().to_vec();
 		base_uri_buf.into_iter().chain(token_id_buf).collect::<Vec<_>>()
 	}
+
+	fn attribute(
+		id: Self::NonFungibleTokenId,
+		token_id: Self::TokenId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		let key: BoundedVec<u8, T::KeyLimit> = key.to_vec().try_into().ok()?;
+		Attributes::<T>::get((id, token_id, key)).map(|(value, _)| value.to_vec())
+	}
 }
 
 impl<T: Config> NonFungibleEnumerable<T::AccountId> for Pallet<T> {
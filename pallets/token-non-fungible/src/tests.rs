@@ -19,6 +19,7 @@
 use super::*;
 use crate::mock::*;
 use frame_support::{assert_noop, assert_ok};
+use sp_core::{ecdsa, Pair};
 
 const ALICE: u64 = 1;
 const BOB: u64 = 2;
@@ -283,3 +284,930 @@ fn transfer_from_should_not_work() {
 		assert_eq!(TokenNonFungible::total_supply(1), 2);
 	})
 }
+
+#[test]
+fn roles_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::grant_role(Origin::signed(ALICE), 1, TokenRole::Admin, BOB));
+		assert_ok!(TokenNonFungible::grant_role(
+			Origin::signed(BOB),
+			1,
+			TokenRole::Minter,
+			CHARLIE
+		));
+		assert_noop!(
+			TokenNonFungible::grant_role(Origin::signed(BOB), 1, TokenRole::Admin, CHARLIE),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(TokenNonFungible::mint(Origin::signed(CHARLIE), 1, ALICE, 1));
+		assert_eq!(TokenNonFungible::owner_of(1, 1), Some(ALICE));
+
+		assert_noop!(
+			TokenNonFungible::burn(Origin::signed(CHARLIE), 1, 1),
+			Error::<Test>::NotTokenOwner
+		);
+		assert_ok!(TokenNonFungible::grant_role(
+			Origin::signed(ALICE),
+			1,
+			TokenRole::Burner,
+			CHARLIE
+		));
+		assert_ok!(TokenNonFungible::burn(Origin::signed(CHARLIE), 1, 1));
+		assert_eq!(TokenNonFungible::owner_of(1, 1), None);
+		assert_eq!(TokenNonFungible::balance_of(1, ALICE), 0);
+
+		assert_ok!(TokenNonFungible::revoke_role(
+			Origin::signed(BOB),
+			1,
+			TokenRole::Minter,
+			CHARLIE
+		));
+		assert_noop!(
+			TokenNonFungible::mint(Origin::signed(CHARLIE), 1, ALICE, 2),
+			Error::<Test>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn transfer_ownership_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_noop!(
+			TokenNonFungible::transfer_ownership(Origin::signed(BOB), 1, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenNonFungible::transfer_ownership(Origin::signed(ALICE), 1, BOB));
+
+		assert_noop!(
+			TokenNonFungible::mint(Origin::signed(ALICE), 1, ALICE, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenNonFungible::mint(Origin::signed(BOB), 1, ALICE, 1));
+	})
+}
+
+#[test]
+fn destroy_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&ALICE), CreateTokenDeposit::get());
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, ALICE, 1));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 2));
+		assert_ok!(TokenNonFungible::set_approve_for_all(Origin::signed(BOB), 1, CHARLIE, true));
+
+		assert_noop!(
+			TokenNonFungible::start_destroy(Origin::signed(BOB), 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenNonFungible::start_destroy(Origin::signed(ALICE), 1));
+		assert_noop!(
+			TokenNonFungible::transfer_from(Origin::signed(ALICE), 1, ALICE, BOB, 1),
+			Error::<Test>::TokenDestroying
+		);
+		assert_noop!(
+			TokenNonFungible::mint(Origin::signed(ALICE), 1, ALICE, 3),
+			Error::<Test>::TokenDestroying
+		);
+		assert_noop!(
+			TokenNonFungible::burn(Origin::signed(ALICE), 1, 1),
+			Error::<Test>::TokenDestroying
+		);
+
+		while TokenNonFungible::finish_destroy(Origin::signed(BOB), 1).is_err() {
+			assert_ok!(TokenNonFungible::destroy_accounts(Origin::signed(BOB), 1));
+		}
+
+		assert_eq!(TokenNonFungible::exists(1), false);
+		assert_eq!(TokenNonFungible::owner_of(1, 1), None);
+		assert_eq!(TokenNonFungible::balance_of(1, BOB), 0);
+		assert_eq!(TokenNonFungible::is_approved_for_all(1, (BOB, CHARLIE)), false);
+		assert_eq!(TokenNonFungible::total_supply(1), 0);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&ALICE), 0);
+		assert_noop!(
+			TokenNonFungible::destroy_accounts(Origin::signed(BOB), 1),
+			Error::<Test>::NotDestroying
+		);
+	})
+}
+
+#[test]
+fn freeze_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 2));
+		assert_noop!(
+			TokenNonFungible::freeze(Origin::signed(CHARLIE), 1, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenNonFungible::grant_role(
+			Origin::signed(ALICE),
+			1,
+			TokenRole::Freezer,
+			CHARLIE
+		));
+
+		assert_ok!(TokenNonFungible::freeze(Origin::signed(CHARLIE), 1, BOB));
+		assert_eq!(TokenNonFungible::is_frozen(1, BOB), true);
+		assert_noop!(
+			TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 1),
+			Error::<Test>::AccountFrozen
+		);
+		assert_ok!(TokenNonFungible::thaw(Origin::signed(CHARLIE), 1, BOB));
+		assert_ok!(TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 1));
+
+		assert_ok!(TokenNonFungible::freeze_token(Origin::signed(ALICE), 1));
+		assert_noop!(
+			TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 2),
+			Error::<Test>::TokenFrozen
+		);
+		assert_ok!(TokenNonFungible::thaw_token(Origin::signed(CHARLIE), 1));
+		assert_ok!(TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, ALICE, 2));
+	})
+}
+
+#[test]
+fn attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+		assert_noop!(
+			TokenNonFungible::set_attribute(
+				Origin::signed(BOB),
+				1,
+				1,
+				b"level".to_vec(),
+				b"99".to_vec()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			TokenNonFungible::set_attribute(
+				Origin::signed(ALICE),
+				1,
+				2,
+				b"level".to_vec(),
+				b"1".to_vec()
+			),
+			Error::<Test>::TokenNonExistent
+		);
+		assert_noop!(
+			TokenNonFungible::set_attribute(
+				Origin::signed(ALICE),
+				1,
+				1,
+				vec![0u8; 33],
+				b"1".to_vec()
+			),
+			Error::<Test>::BadMetadata
+		);
+
+		let reserved = <Test as Config>::Currency::reserved_balance(&ALICE);
+		assert_ok!(TokenNonFungible::set_attribute(
+			Origin::signed(ALICE),
+			1,
+			1,
+			b"level".to_vec(),
+			b"1".to_vec()
+		));
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&ALICE),
+			reserved + AttributeDepositBase::get() + 6 * DepositPerByte::get()
+		);
+		assert_ok!(TokenNonFungible::set_attribute(
+			Origin::signed(ALICE),
+			1,
+			1,
+			b"level".to_vec(),
+			b"10".to_vec()
+		));
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&ALICE),
+			reserved + AttributeDepositBase::get() + 7 * DepositPerByte::get()
+		);
+		assert_eq!(TokenNonFungible::attribute(1, 1, b"level"), Some(b"10".to_vec()));
+		assert_eq!(TokenNonFungible::attributes(1, 1), vec![(b"level".to_vec(), b"10".to_vec())]);
+
+		assert_noop!(
+			TokenNonFungible::clear_attribute(Origin::signed(BOB), 1, 1, b"level".to_vec()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenNonFungible::clear_attribute(
+			Origin::signed(ALICE),
+			1,
+			1,
+			b"level".to_vec()
+		));
+		assert_eq!(<Test as Config>::Currency::reserved_balance(&ALICE), reserved);
+		assert_eq!(TokenNonFungible::attribute(1, 1, b"level"), None);
+		assert_noop!(
+			TokenNonFungible::clear_attribute(Origin::signed(ALICE), 1, 1, b"level".to_vec()),
+			Error::<Test>::NotFound
+		);
+	})
+}
+
+#[test]
+fn token_uri_and_metadata_freezing_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 2));
+		assert_noop!(
+			TokenNonFungible::set_token_uri(Origin::signed(BOB), 1, 1, b"ipfs://trophy".to_vec()),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(TokenNonFungible::set_token_uri(
+			Origin::signed(ALICE),
+			1,
+			1,
+			b"ipfs://trophy".to_vec()
+		));
+		assert_eq!(TokenNonFungible::token_uri(1, 1), b"ipfs://trophy".to_vec());
+		assert_eq!(TokenNonFungible::token_uri(1, 2), b"https://web3games.com/2".to_vec());
+		assert_ok!(TokenNonFungible::set_token_uri(Origin::signed(ALICE), 1, 1, vec![]));
+		assert_eq!(TokenNonFungible::token_uri(1, 1), b"https://web3games.com/1".to_vec());
+
+		assert_ok!(TokenNonFungible::freeze_metadata(Origin::signed(ALICE), 1, Some(1)));
+		assert_noop!(
+			TokenNonFungible::set_token_uri(Origin::signed(ALICE), 1, 1, b"ipfs://trophy".to_vec()),
+			Error::<Test>::MetadataFrozen
+		);
+		assert_noop!(
+			TokenNonFungible::set_attribute(
+				Origin::signed(ALICE),
+				1,
+				1,
+				b"level".to_vec(),
+				b"1".to_vec()
+			),
+			Error::<Test>::MetadataFrozen
+		);
+		assert_ok!(TokenNonFungible::set_token_uri(
+			Origin::signed(ALICE),
+			1,
+			2,
+			b"ipfs://second".to_vec()
+		));
+
+		assert_ok!(TokenNonFungible::freeze_metadata(Origin::signed(ALICE), 1, None));
+		assert_noop!(
+			TokenNonFungible::freeze_metadata(Origin::signed(ALICE), 1, None),
+			Error::<Test>::MetadataFrozen
+		);
+		assert_noop!(
+			TokenNonFungible::set_token_uri(Origin::signed(ALICE), 1, 2, vec![]),
+			Error::<Test>::MetadataFrozen
+		);
+		assert_eq!(TokenNonFungible::token_uri(1, 2), b"ipfs://second".to_vec());
+	})
+}
+
+#[test]
+fn royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+		assert_noop!(
+			TokenNonFungible::set_royalty(Origin::signed(BOB), 1, None, BOB, 500),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			TokenNonFungible::set_royalty(Origin::signed(ALICE), 1, None, ALICE, 10_001),
+			Error::<Test>::InvalidRoyalty
+		);
+
+		assert_ok!(TokenNonFungible::set_royalty(Origin::signed(ALICE), 1, None, ALICE, 500));
+		assert_eq!(TokenNonFungible::royalty_info(1, 1, 1_000u128), Some((ALICE, 50)));
+		assert_ok!(TokenNonFungible::set_royalty(Origin::signed(ALICE), 1, Some(1), CHARLIE, 250));
+		assert_eq!(TokenNonFungible::royalty_info(1, 1, 1_000u128), Some((CHARLIE, 25)));
+		assert_eq!(TokenNonFungible::royalty_info(1, 2, 1_000u128), Some((ALICE, 50)));
+
+		// a zero override waives the collection royalty for that token only
+		assert_ok!(TokenNonFungible::set_royalty(Origin::signed(ALICE), 1, Some(1), CHARLIE, 0));
+		assert_eq!(TokenNonFungible::royalty_info(1, 1, 1_000u128), Some((CHARLIE, 0)));
+		assert_eq!(TokenNonFungible::royalty_info(1, 2, 1_000u128), Some((ALICE, 50)));
+
+		assert_noop!(
+			TokenNonFungible::clear_token_royalty(Origin::signed(BOB), 1, 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TokenNonFungible::clear_token_royalty(Origin::signed(ALICE), 1, 1));
+		assert_eq!(TokenNonFungible::royalty_info(1, 1, 1_000u128), Some((ALICE, 50)));
+		assert_noop!(
+			TokenNonFungible::clear_token_royalty(Origin::signed(ALICE), 1, 1),
+			Error::<Test>::NoTokenRoyalty
+		);
+
+		assert_ok!(TokenNonFungible::set_royalty(Origin::signed(ALICE), 1, None, ALICE, 0));
+		assert_eq!(TokenNonFungible::royalty_info(1, 1, 1_000u128), None);
+	})
+}
+
+#[test]
+fn soulbound_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 2));
+		assert_noop!(
+			TokenNonFungible::set_soulbound(Origin::signed(BOB), 1, Some(1), true),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(TokenNonFungible::set_soulbound(Origin::signed(ALICE), 1, Some(1), true));
+		assert_eq!(TokenNonFungible::is_soulbound(1, 1), true);
+		assert_noop!(
+			TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1),
+			Error::<Test>::Soulbound
+		);
+		assert_ok!(TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 2));
+
+		assert_ok!(TokenNonFungible::set_soulbound(Origin::signed(ALICE), 1, None, true));
+		assert_noop!(
+			TokenNonFungible::transfer_from(Origin::signed(CHARLIE), 1, CHARLIE, BOB, 2),
+			Error::<Test>::Soulbound
+		);
+
+		assert_ok!(TokenNonFungible::burn(Origin::signed(CHARLIE), 1, 2));
+		assert_ok!(TokenNonFungible::grant_role(
+			Origin::signed(ALICE),
+			1,
+			TokenRole::Burner,
+			ALICE
+		));
+		assert_ok!(TokenNonFungible::burn(Origin::signed(ALICE), 1, 1));
+		assert_eq!(TokenNonFungible::is_soulbound_token(1, 1), false);
+	})
+}
+
+#[test]
+fn lock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+		assert_noop!(TokenNonFungible::lock(1, 2, *b"staking "), Error::<Test>::TokenNonExistent);
+
+		assert_ok!(TokenNonFungible::lock(1, 1, *b"staking "));
+		assert_eq!(TokenNonFungible::locker_of(1, 1), Some(*b"staking "));
+		assert_eq!(TokenNonFungible::owner_of(1, 1), Some(BOB));
+		assert_noop!(TokenNonFungible::lock(1, 1, *b"market  "), Error::<Test>::TokenLocked);
+		assert_noop!(
+			TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1),
+			Error::<Test>::TokenLocked
+		);
+		assert_noop!(TokenNonFungible::burn(Origin::signed(BOB), 1, 1), Error::<Test>::TokenLocked);
+
+		assert_noop!(TokenNonFungible::unlock(1, 1, *b"market  "), Error::<Test>::NotLocked);
+		assert_ok!(TokenNonFungible::unlock(1, 1, *b"staking "));
+		assert_ok!(TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 1));
+	})
+}
+
+#[test]
+fn set_user_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, ALICE, 1));
+		assert_noop!(
+			TokenNonFungible::set_user(Origin::signed(BOB), 1, 1, Some(BOB), 10),
+			Error::<Test>::NotOwnerOrApproved
+		);
+
+		assert_ok!(TokenNonFungible::set_user(Origin::signed(ALICE), 1, 1, Some(BOB), 10));
+		assert_eq!(TokenNonFungible::user_of(1, 1), Some(BOB));
+		assert_eq!(TokenNonFungible::user_expires(1, 1), 10);
+		assert_eq!(TokenNonFungible::owner_of(1, 1), Some(ALICE));
+
+		System::set_block_number(11);
+		assert_eq!(TokenNonFungible::user_of(1, 1), None);
+
+		assert_ok!(TokenNonFungible::set_user(Origin::signed(ALICE), 1, 1, Some(CHARLIE), 20));
+		assert_eq!(TokenNonFungible::user_of(1, 1), Some(CHARLIE));
+		assert_ok!(TokenNonFungible::transfer_from(Origin::signed(ALICE), 1, ALICE, BOB, 1));
+		assert_eq!(TokenNonFungible::user_of(1, 1), None);
+		assert_eq!(TokenNonFungible::user_expires(1, 1), 0);
+	})
+}
+
+#[test]
+fn nesting_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		for token_id in 1..=5 {
+			assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, ALICE, token_id));
+		}
+		assert_noop!(
+			TokenNonFungible::nest(Origin::signed(BOB), 1, 1, 1, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			TokenNonFungible::nest(Origin::signed(ALICE), 1, 1, 1, 1),
+			Error::<Test>::NestingLoop
+		);
+
+		assert_ok!(TokenNonFungible::nest(Origin::signed(ALICE), 1, 1, 1, 2));
+		assert_ok!(TokenNonFungible::nest(Origin::signed(ALICE), 1, 2, 1, 3));
+		assert_ok!(TokenNonFungible::nest(Origin::signed(ALICE), 1, 3, 1, 4));
+		assert_eq!(
+			TokenNonFungible::owner_of(1, 2),
+			Some(TokenNonFungible::token_account_id(1, 1))
+		);
+		assert_eq!(TokenNonFungible::parent_of(1, 4), Some((1, 3)));
+		assert_eq!(TokenNonFungible::root_owner_of(1, 4), Some(ALICE));
+		assert_noop!(
+			TokenNonFungible::nest(Origin::signed(ALICE), 1, 4, 1, 5),
+			Error::<Test>::NestingTooDeep
+		);
+		assert_noop!(
+			TokenNonFungible::burn(Origin::signed(ALICE), 1, 1),
+			Error::<Test>::HasChildren
+		);
+
+		assert_ok!(TokenMulti::create_token(
+			Origin::signed(BOB),
+			1,
+			b"https://web3games.com/".to_vec()
+		));
+		assert_ok!(TokenMulti::mint(Origin::signed(BOB), 1, ALICE, 1, 10));
+		assert_ok!(TokenNonFungible::nest_multi(Origin::signed(ALICE), 1, 2, 1, 1, 6));
+		let nested = TokenNonFungible::nested_tokens(1, 1, 3);
+		assert_eq!(nested.len(), 4);
+		assert!(nested.contains(&(3, NestedToken::NonFungible(1, 4), 1)));
+		assert!(nested.contains(&(2, NestedToken::Multi(1, 1), 6)));
+		assert_eq!(TokenNonFungible::nested_tokens(1, 1, 2).len(), 3);
+
+		assert_ok!(TokenNonFungible::transfer_from(Origin::signed(ALICE), 1, ALICE, BOB, 1));
+		assert_eq!(TokenNonFungible::root_owner_of(1, 4), Some(BOB));
+		assert_noop!(
+			TokenNonFungible::unnest(Origin::signed(ALICE), 1, 3, 1, 4, ALICE),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			TokenNonFungible::unnest(Origin::signed(BOB), 1, 2, 1, 4, BOB),
+			Error::<Test>::NotNested
+		);
+		assert_ok!(TokenNonFungible::unnest(Origin::signed(BOB), 1, 3, 1, 4, CHARLIE));
+		assert_eq!(TokenNonFungible::owner_of(1, 4), Some(CHARLIE));
+		assert_eq!(TokenNonFungible::parent_of(1, 4), None);
+
+		assert_ok!(TokenNonFungible::unnest_multi(Origin::signed(BOB), 1, 2, 1, 1, 6, BOB));
+		assert_eq!(TokenMulti::balance_of(1, (1, BOB)), 6);
+		assert_eq!(TokenNonFungible::nested_tokens(1, 1, 3).len(), 2);
+	})
+}
+
+#[test]
+fn nesting_limits_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		for token_id in 1..=4 {
+			assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, ALICE, token_id));
+		}
+
+		assert_ok!(TokenNonFungible::nest(Origin::signed(ALICE), 1, 1, 1, 2));
+		assert_ok!(TokenNonFungible::nest(Origin::signed(ALICE), 1, 1, 1, 3));
+		assert_eq!(TokenNonFungible::child_count(1, 1), 2);
+		assert_eq!(TokenNonFungible::height_of(1, 1), 1);
+		assert_noop!(
+			TokenNonFungible::nest(Origin::signed(ALICE), 1, 1, 1, 4),
+			Error::<Test>::TooManyChildren
+		);
+
+		assert_ok!(TokenNonFungible::nest(Origin::signed(ALICE), 1, 2, 1, 4));
+		assert_eq!(TokenNonFungible::height_of(1, 2), 1);
+		assert_eq!(TokenNonFungible::height_of(1, 1), 2);
+
+		assert_ok!(TokenNonFungible::unnest(Origin::signed(ALICE), 1, 2, 1, 4, ALICE));
+		assert_eq!(TokenNonFungible::height_of(1, 2), 0);
+		assert_eq!(TokenNonFungible::height_of(1, 1), 1);
+
+		assert_ok!(TokenNonFungible::unnest(Origin::signed(ALICE), 1, 1, 1, 2, ALICE));
+		assert_ok!(TokenNonFungible::unnest(Origin::signed(ALICE), 1, 1, 1, 3, ALICE));
+		assert_eq!(TokenNonFungible::child_count(1, 1), 0);
+		assert_eq!(TokenNonFungible::height_of(1, 1), 0);
+	})
+}
+
+fn evm_address(pair: &ecdsa::Pair) -> H160 {
+	let message = [0u8; 32];
+	let signature = pair.sign_prehashed(&message);
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message).unwrap();
+	H160::from_slice(&keccak_256(&public)[12..])
+}
+
+#[test]
+fn redeem_voucher_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let signer = evm_address(&pair);
+		assert_ok!(TokenNonFungible::grant_role(
+			Origin::signed(ALICE),
+			1,
+			TokenRole::Minter,
+			MockAddressMapping::convert(signer)
+		));
+
+		let voucher = MintVoucher {
+			token_id: 1,
+			recipient: None,
+			currency: None,
+			price: 5 * DOLLARS,
+			expiry: 10,
+			nonce: 0,
+		};
+		let signature = pair.sign_prehashed(&TokenNonFungible::voucher_digest(1, &voucher)).0;
+		let alice_balance = <Test as Config>::Currency::free_balance(ALICE);
+		assert_ok!(TokenNonFungible::redeem_voucher(
+			Origin::signed(BOB),
+			1,
+			voucher.clone(),
+			signature
+		));
+		assert_eq!(TokenNonFungible::owner_of(1, 1), Some(BOB));
+		assert_eq!(<Test as Config>::Currency::free_balance(ALICE), alice_balance + 5 * DOLLARS);
+		assert!(TokenNonFungible::is_voucher_redeemed(1, (signer, 0)));
+		assert_noop!(
+			TokenNonFungible::redeem_voucher(Origin::signed(BOB), 1, voucher, signature),
+			Error::<Test>::VoucherRedeemed
+		);
+
+		assert_ok!(TokenFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			18,
+			None
+		));
+		assert_ok!(TokenFungible::mint(Origin::signed(ALICE), 1, BOB, 100));
+		let voucher = MintVoucher {
+			token_id: 2,
+			recipient: Some(H160::from_low_u64_be(CHARLIE)),
+			currency: Some(1),
+			price: 40,
+			expiry: 10,
+			nonce: 1,
+		};
+		let signature = pair.sign_prehashed(&TokenNonFungible::voucher_digest(1, &voucher)).0;
+		assert_noop!(
+			TokenNonFungible::redeem_voucher(Origin::signed(BOB), 1, voucher.clone(), signature),
+			Error::<Test>::NotVoucherRecipient
+		);
+		assert_ok!(TokenFungible::transfer(Origin::signed(BOB), 1, CHARLIE, 50));
+		assert_ok!(TokenNonFungible::redeem_voucher(
+			Origin::signed(CHARLIE),
+			1,
+			voucher,
+			signature
+		));
+		assert_eq!(TokenNonFungible::owner_of(1, 2), Some(CHARLIE));
+		assert_eq!(TokenFungible::balance_of(1, CHARLIE), 10);
+		assert_eq!(TokenFungible::balance_of(1, ALICE), 40);
+
+		let other = ecdsa::Pair::from_seed(&[2u8; 32]);
+		let voucher = MintVoucher {
+			token_id: 3,
+			recipient: None,
+			currency: None,
+			price: 0,
+			expiry: 10,
+			nonce: 2,
+		};
+		let signature = other.sign_prehashed(&TokenNonFungible::voucher_digest(1, &voucher)).0;
+		assert_noop!(
+			TokenNonFungible::redeem_voucher(Origin::signed(BOB), 1, voucher, signature),
+			Error::<Test>::NoPermission
+		);
+
+		System::set_block_number(11);
+		let voucher = MintVoucher {
+			token_id: 3,
+			recipient: None,
+			currency: None,
+			price: 0,
+			expiry: 10,
+			nonce: 3,
+		};
+		let signature = pair.sign_prehashed(&TokenNonFungible::voucher_digest(1, &voucher)).0;
+		assert_noop!(
+			TokenNonFungible::redeem_voucher(Origin::signed(BOB), 1, voucher, signature),
+			Error::<Test>::VoucherExpired
+		);
+	})
+}
+
+#[test]
+fn max_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+
+		assert_noop!(
+			TokenNonFungible::set_max_supply(Origin::signed(BOB), 1, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			TokenNonFungible::set_max_supply(Origin::signed(ALICE), 1, 0),
+			Error::<Test>::MaxSupplyTooLow
+		);
+		assert_ok!(TokenNonFungible::set_max_supply(Origin::signed(ALICE), 1, 2));
+		assert_eq!(TokenNonFungible::max_supply(1), Some(2));
+		assert_noop!(
+			TokenNonFungible::set_max_supply(Origin::signed(ALICE), 1, 3),
+			Error::<Test>::MaxSupplyAlreadySet
+		);
+
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 2));
+		assert_noop!(
+			TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 3),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		// Burned tokens don't free room under the cap.
+		assert_ok!(TokenNonFungible::burn(Origin::signed(BOB), 1, 2));
+		assert_eq!(TokenNonFungible::minted(1), 2);
+		assert_noop!(
+			TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 3),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		assert_noop!(
+			TokenNonFungible::mint_range(Origin::signed(ALICE), 1, BOB, 3, 1),
+			Error::<Test>::MaxSupplyExceeded
+		);
+	})
+}
+
+#[test]
+fn minted_migration_should_work() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint_range(Origin::signed(ALICE), 1, BOB, 1, 3));
+		assert_ok!(TokenNonFungible::burn(Origin::signed(BOB), 1, 2));
+
+		// Collections minted before mints were counted.
+		Minted::<Test>::remove(1);
+		StorageVersion::new(0).put::<TokenNonFungible>();
+
+		migrations::v1::migrate::<Test>();
+		assert_eq!(TokenNonFungible::minted(1), 2);
+		assert_eq!(TokenNonFungible::on_chain_storage_version(), 1);
+	})
+}
+
+fn hash_pair(a: H256, b: H256) -> H256 {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+	H256::from(keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+}
+
+#[test]
+fn public_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_noop!(
+			TokenNonFungible::public_mint(Origin::signed(BOB), 1, 1, vec![]),
+			Error::<Test>::NoActiveMintPhase
+		);
+
+		let bob_leaf = H256::from(keccak_256(&BOB.encode()));
+		let charlie_leaf = H256::from(keccak_256(&CHARLIE.encode()));
+		let allowlist = MintPhase {
+			start: 1,
+			end: 10,
+			currency: None,
+			price: DOLLARS,
+			wallet_limit: 1,
+			allowlist: Some(hash_pair(bob_leaf, charlie_leaf)),
+		};
+		let public = MintPhase {
+			start: 11,
+			end: 20,
+			currency: None,
+			price: 0,
+			wallet_limit: 2,
+			allowlist: None,
+		};
+		assert_noop!(
+			TokenNonFungible::set_mint_phases(Origin::signed(BOB), 1, vec![allowlist.clone()]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			TokenNonFungible::set_mint_phases(
+				Origin::signed(ALICE),
+				1,
+				vec![MintPhase { start: 21, ..public.clone() }]
+			),
+			Error::<Test>::InvalidMintPhase
+		);
+		assert_noop!(
+			TokenNonFungible::set_mint_phases(Origin::signed(ALICE), 1, vec![public.clone(); 4]),
+			Error::<Test>::TooManyMintPhases
+		);
+		assert_ok!(TokenNonFungible::set_mint_phases(
+			Origin::signed(ALICE),
+			1,
+			vec![allowlist, public]
+		));
+
+		let alice_balance = <Test as Config>::Currency::free_balance(ALICE);
+		assert_ok!(TokenNonFungible::public_mint(Origin::signed(BOB), 1, 1, vec![charlie_leaf]));
+		assert_eq!(TokenNonFungible::owner_of(1, 1), Some(BOB));
+		assert_eq!(<Test as Config>::Currency::free_balance(ALICE), alice_balance + DOLLARS);
+		assert_eq!(TokenNonFungible::phase_minted(1, (0, BOB)), 1);
+		assert_noop!(
+			TokenNonFungible::public_mint(Origin::signed(BOB), 1, 2, vec![charlie_leaf]),
+			Error::<Test>::WalletLimitReached
+		);
+		assert_noop!(
+			TokenNonFungible::public_mint(Origin::signed(ALICE), 1, 2, vec![charlie_leaf]),
+			Error::<Test>::NotAllowlisted
+		);
+
+		System::set_block_number(11);
+		assert_ok!(TokenNonFungible::public_mint(Origin::signed(CHARLIE), 1, 2, vec![]));
+		assert_ok!(TokenNonFungible::public_mint(Origin::signed(CHARLIE), 1, 3, vec![]));
+		assert_noop!(
+			TokenNonFungible::public_mint(Origin::signed(CHARLIE), 1, 4, vec![]),
+			Error::<Test>::WalletLimitReached
+		);
+		assert_noop!(
+			TokenNonFungible::public_mint(Origin::signed(BOB), 1, 3, vec![]),
+			Error::<Test>::TokenAlreadyMinted
+		);
+
+		System::set_block_number(21);
+		assert_noop!(
+			TokenNonFungible::public_mint(Origin::signed(BOB), 1, 4, vec![]),
+			Error::<Test>::NoActiveMintPhase
+		);
+	})
+}
+
+#[test]
+fn mint_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenNonFungible::create_token(
+			Origin::signed(ALICE),
+			1,
+			b"W3G".to_vec(),
+			b"W3G".to_vec(),
+			b"https://web3games.com/".to_vec(),
+		));
+		assert_ok!(TokenNonFungible::mint(Origin::signed(ALICE), 1, BOB, 1));
+
+		assert_noop!(
+			TokenNonFungible::mint_batch(Origin::signed(BOB), 1, BOB, vec![2, 3]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			TokenNonFungible::mint_batch(Origin::signed(ALICE), 1, BOB, vec![2, 1]),
+			Error::<Test>::TokenAlreadyMinted
+		);
+		assert_noop!(
+			TokenNonFungible::mint_batch(Origin::signed(ALICE), 1, BOB, vec![2, 2]),
+			Error::<Test>::TokenAlreadyMinted
+		);
+		assert_ok!(TokenNonFungible::mint_batch(Origin::signed(ALICE), 1, BOB, vec![5, 3]));
+		assert_eq!(TokenNonFungible::owner_of(1, 5), Some(BOB));
+		assert_eq!(TokenNonFungible::balance_of(1, BOB), 3);
+		assert_eq!(TokenNonFungible::total_supply(1), 3);
+		assert_eq!(TokenNonFungible::token_by_index(1, 1), 5);
+		assert_eq!(TokenNonFungible::token_by_index(1, 2), 3);
+		assert_eq!(TokenNonFungible::token_of_owner_by_index(1, BOB, 2), 3);
+
+		assert_noop!(
+			TokenNonFungible::mint_batch(Origin::signed(ALICE), 1, BOB, (20..26).collect()),
+			Error::<Test>::TooManyTokens
+		);
+		assert_noop!(
+			TokenNonFungible::mint_range(Origin::signed(ALICE), 1, CHARLIE, 20, u32::MAX),
+			Error::<Test>::TooManyTokens
+		);
+
+		assert_ok!(TokenNonFungible::mint_range(Origin::signed(ALICE), 1, CHARLIE, 10, 3));
+		System::assert_has_event(crate::mock::Event::TokenNonFungible(
+			TokenFungibleEvent::Transfer(1, 0, CHARLIE, 11),
+		));
+		assert_eq!(TokenNonFungible::balance_of(1, CHARLIE), 3);
+		assert_eq!(TokenNonFungible::total_supply(1), 6);
+		assert_eq!(TokenNonFungible::token_by_index(1, 5), 12);
+		assert_eq!(TokenNonFungible::token_of_owner_by_index(1, CHARLIE, 0), 10);
+		assert_noop!(
+			TokenNonFungible::mint_range(Origin::signed(ALICE), 1, CHARLIE, 12, 2),
+			Error::<Test>::TokenAlreadyMinted
+		);
+
+		assert_ok!(TokenNonFungible::set_max_supply(Origin::signed(ALICE), 1, 8));
+		assert_noop!(
+			TokenNonFungible::mint_range(Origin::signed(ALICE), 1, CHARLIE, 20, 3),
+			Error::<Test>::MaxSupplyExceeded
+		);
+
+		assert_ok!(TokenNonFungible::transfer_from(Origin::signed(BOB), 1, BOB, CHARLIE, 5));
+		assert_ok!(TokenNonFungible::burn(Origin::signed(CHARLIE), 1, 11));
+		assert_eq!(TokenNonFungible::balance_of(1, CHARLIE), 3);
+		assert_eq!(TokenNonFungible::total_supply(1), 5);
+	})
+}
@@ -52,16 +52,46 @@ pub trait WeightInfo {
 	fn approve() -> Weight;
 	fn set_approve_for_all() -> Weight;
 	fn transfer_from() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn start_destroy() -> Weight;
+	fn destroy_accounts(c: u32, ) -> Weight;
+	fn finish_destroy() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn freeze_token() -> Weight;
+	fn thaw_token() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn set_token_uri() -> Weight;
+	fn freeze_metadata() -> Weight;
+	fn set_royalty() -> Weight;
+	fn clear_token_royalty() -> Weight;
+	fn set_soulbound() -> Weight;
+	fn set_user() -> Weight;
+	fn nest() -> Weight;
+	fn nest_multi() -> Weight;
+	fn unnest() -> Weight;
+	fn unnest_multi() -> Weight;
+	fn redeem_voucher() -> Weight;
+	fn set_max_supply() -> Weight;
+	fn set_mint_phases(p: u32, ) -> Weight;
+	fn public_mint(p: u32, ) -> Weight;
+	fn mint_batch(n: u32, ) -> Weight;
+	fn mint_range(n: u32, ) -> Weight;
 }
 
 /// Weights for web3games_token_non_fungible using the Web3Games node and recommended hardware.
 pub struct W3GWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: TokenNonFungible Tokens (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenNonFungible Deposits (r:0 w:1)
 	fn create_token() -> Weight {
-		(15_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: TokenNonFungible Tokens (r:1 w:0)
 	// Storage: TokenNonFungible Owners (r:1 w:1)
@@ -71,10 +101,12 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: TokenNonFungible OwnedTokens (r:0 w:1)
 	// Storage: TokenNonFungible AllTokens (r:0 w:1)
 	// Storage: TokenNonFungible OwnedTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible MaxSupply (r:1 w:0)
+	// Storage: TokenNonFungible Minted (r:1 w:1)
 	fn mint() -> Weight {
 		(33_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	// Storage: TokenNonFungible Owners (r:1 w:1)
 	// Storage: TokenNonFungible Balances (r:1 w:1)
@@ -84,10 +116,18 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:1)
 	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
 	// Storage: TokenNonFungible OwnedTokens (r:0 w:1)
+	// Storage: TokenNonFungible TokenUris (r:0 w:1)
+	// Storage: TokenNonFungible MetadataFrozen (r:0 w:1)
+	// Storage: TokenNonFungible TokenRoyalties (r:0 w:1)
+	// Storage: TokenNonFungible SoulboundTokens (r:0 w:1)
+	// Storage: TokenNonFungible Locks (r:1 w:0)
+	// Storage: TokenNonFungible Users (r:0 w:1)
+	// Storage: TokenNonFungible Children (r:1 w:0)
+	// Storage: TokenNonFungible Parents (r:1 w:1)
 	fn burn() -> Weight {
 		(45_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
 	}
 	// Storage: TokenNonFungible Owners (r:1 w:0)
 	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
@@ -106,29 +146,424 @@ impl<T: frame_system::Config> WeightInfo for W3GWeight<T> {
 	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:2)
 	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
 	// Storage: TokenNonFungible OwnedTokens (r:0 w:2)
+	// Storage: TokenNonFungible SoulboundCollections (r:1 w:0)
+	// Storage: TokenNonFungible SoulboundTokens (r:1 w:0)
+	// Storage: TokenNonFungible Locks (r:1 w:0)
+	// Storage: TokenNonFungible Users (r:1 w:1)
 	fn transfer_from() -> Weight {
 		(42_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn grant_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn revoke_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn transfer_ownership() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:1)
+	// Storage: TokenNonFungible Children (r:1 w:0)
+	// Estimated, not benchmarked yet.
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:1)
+	// Storage: TokenNonFungible Balances (r:1 w:1)
+	// Storage: TokenNonFungible TokenApprovals (r:1 w:1)
+	// Storage: TokenNonFungible OperatorApprovals (r:1 w:1)
+	// Storage: TokenNonFungible AllTokens (r:1 w:1)
+	// Storage: TokenNonFungible AllTokensIndex (r:1 w:1)
+	// Storage: TokenNonFungible OwnedTokens (r:1 w:1)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:1)
+	// Storage: TokenNonFungible Roles (r:1 w:1)
+	// Storage: TokenNonFungible Frozen (r:1 w:1)
+	// Storage: TokenNonFungible TokenUris (r:1 w:1)
+	// Storage: TokenNonFungible MetadataFrozen (r:1 w:1)
+	// Storage: TokenNonFungible Attributes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenNonFungible TokenRoyalties (r:1 w:1)
+	// Storage: TokenNonFungible SoulboundTokens (r:1 w:1)
+	// Storage: TokenNonFungible Locks (r:1 w:1)
+	// Storage: TokenNonFungible Users (r:1 w:1)
+	// Storage: TokenNonFungible Parents (r:1 w:1)
+	// Storage: TokenNonFungible RedeemedVouchers (r:1 w:1)
+	// Storage: TokenNonFungible PhaseMinted (r:1 w:1)
+	// Storage: TokenNonFungible Children (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn destroy_accounts(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: TokenNonFungible Destroying (r:1 w:1)
+	// Storage: TokenNonFungible Owners (r:1 w:0)
+	// Storage: TokenNonFungible Balances (r:1 w:0)
+	// Storage: TokenNonFungible TokenApprovals (r:1 w:0)
+	// Storage: TokenNonFungible OperatorApprovals (r:1 w:0)
+	// Storage: TokenNonFungible AllTokens (r:1 w:0)
+	// Storage: TokenNonFungible AllTokensIndex (r:1 w:0)
+	// Storage: TokenNonFungible OwnedTokens (r:1 w:0)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible Frozen (r:1 w:0)
+	// Storage: TokenNonFungible TokenUris (r:1 w:0)
+	// Storage: TokenNonFungible MetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible Attributes (r:1 w:0)
+	// Storage: TokenNonFungible Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenNonFungible Tokens (r:0 w:1)
+	// Storage: TokenNonFungible TotalSupply (r:0 w:1)
+	// Storage: TokenNonFungible FrozenTokens (r:0 w:1)
+	// Storage: TokenNonFungible CollectionMetadataFrozen (r:0 w:1)
+	// Storage: TokenNonFungible TokenRoyalties (r:1 w:0)
+	// Storage: TokenNonFungible Royalties (r:0 w:1)
+	// Storage: TokenNonFungible SoulboundTokens (r:1 w:0)
+	// Storage: TokenNonFungible SoulboundCollections (r:0 w:1)
+	// Storage: TokenNonFungible Locks (r:1 w:0)
+	// Storage: TokenNonFungible Users (r:1 w:0)
+	// Storage: TokenNonFungible Parents (r:1 w:0)
+	// Storage: TokenNonFungible RedeemedVouchers (r:1 w:0)
+	// Storage: TokenNonFungible PhaseMinted (r:1 w:0)
+	// Storage: TokenNonFungible Minted (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn finish_destroy() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(22 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible Frozen (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn freeze() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible Frozen (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn thaw() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible FrozenTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn freeze_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible FrozenTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn thaw_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:0)
+	// Storage: TokenNonFungible CollectionMetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible MetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible Attributes (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Estimated, not benchmarked yet.
+	fn set_attribute() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible CollectionMetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible MetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible Attributes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn clear_attribute() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:0)
+	// Storage: TokenNonFungible CollectionMetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible MetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible TokenUris (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_token_uri() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:0)
+	// Storage: TokenNonFungible CollectionMetadataFrozen (r:1 w:0)
+	// Storage: TokenNonFungible MetadataFrozen (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn freeze_metadata() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible TokenRoyalties (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_royalty() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible TokenRoyalties (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn clear_token_royalty() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible SoulboundTokens (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_soulbound() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:0)
+	// Storage: TokenNonFungible TokenApprovals (r:1 w:0)
+	// Storage: TokenNonFungible OperatorApprovals (r:1 w:0)
+	// Storage: TokenNonFungible Users (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_user() -> Weight {
+		(24_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Destroying (r:2 w:0)
+	// Storage: TokenNonFungible Parents (r:2 w:1)
+	// Storage: TokenNonFungible Owners (r:2 w:1)
+	// Storage: TokenNonFungible Children (r:1 w:1)
+	// Storage: TokenNonFungible ChildCount (r:1 w:1)
+	// Storage: TokenNonFungible Heights (r:3 w:1)
+	// Storage: TokenNonFungible Balances (r:2 w:2)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:2)
+	// Storage: TokenNonFungible Locks (r:1 w:0)
+	// Storage: TokenNonFungible Users (r:1 w:1)
+	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokens (r:0 w:2)
+	// Estimated, not benchmarked yet.
+	fn nest() -> Weight {
+		(64_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Parents (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:0)
+	// Storage: TokenMulti Balances (r:2 w:2)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenNonFungible Children (r:1 w:1)
+	// Storage: TokenNonFungible ChildCount (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn nest_multi() -> Weight {
+		(43_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: TokenNonFungible Owners (r:2 w:1)
+	// Storage: TokenNonFungible Parents (r:1 w:1)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Balances (r:2 w:2)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:1 w:2)
+	// Storage: TokenNonFungible Locks (r:1 w:0)
+	// Storage: TokenNonFungible Users (r:1 w:1)
+	// Storage: TokenNonFungible TokenApprovals (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokens (r:0 w:2)
+	// Storage: TokenNonFungible Children (r:2 w:1)
+	// Storage: TokenNonFungible ChildCount (r:1 w:1)
+	// Storage: TokenNonFungible Heights (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn unnest() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+	}
+	// Storage: TokenNonFungible Parents (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:0)
+	// Storage: TokenMulti Balances (r:2 w:2)
+	// Storage: TokenMulti LockedBalances (r:1 w:0)
+	// Storage: TokenNonFungible Children (r:1 w:1)
+	// Storage: TokenNonFungible ChildCount (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn unnest_multi() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible RedeemedVouchers (r:1 w:1)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible Owners (r:1 w:1)
+	// Storage: TokenNonFungible Balances (r:1 w:1)
+	// Storage: TokenNonFungible TotalSupply (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: TokenNonFungible AllTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokens (r:0 w:1)
+	// Storage: TokenNonFungible AllTokens (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible MaxSupply (r:1 w:0)
+	// Storage: TokenNonFungible Minted (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn redeem_voucher() -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible MaxSupply (r:1 w:1)
+	// Storage: TokenNonFungible Minted (r:1 w:0)
+	// Estimated, not benchmarked yet.
+	fn set_max_supply() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible MintPhases (r:0 w:1)
+	// Estimated, not benchmarked yet.
+	fn set_mint_phases(p: u32, ) -> Weight {
+		(19_000_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((2_300_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible MintPhases (r:1 w:0)
+	// Storage: TokenNonFungible PhaseMinted (r:1 w:1)
+	// Storage: TokenNonFungible Owners (r:1 w:1)
+	// Storage: TokenNonFungible MaxSupply (r:1 w:0)
+	// Storage: TokenNonFungible TotalSupply (r:1 w:1)
+	// Storage: TokenNonFungible Balances (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TokenNonFungible AllTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokens (r:0 w:1)
+	// Storage: TokenNonFungible AllTokens (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible Minted (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn public_mint(p: u32, ) -> Weight {
+		(58_000_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((1_200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible TotalSupply (r:1 w:1)
+	// Storage: TokenNonFungible MaxSupply (r:1 w:0)
+	// Storage: TokenNonFungible Balances (r:1 w:1)
+	// Storage: TokenNonFungible Owners (r:1 w:1)
+	// Storage: TokenNonFungible AllTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible AllTokens (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokens (r:0 w:1)
+	// Storage: TokenNonFungible Minted (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn mint_batch(n: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((33_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TokenNonFungible Tokens (r:1 w:0)
+	// Storage: TokenNonFungible Destroying (r:1 w:0)
+	// Storage: TokenNonFungible Roles (r:1 w:0)
+	// Storage: TokenNonFungible TotalSupply (r:1 w:1)
+	// Storage: TokenNonFungible MaxSupply (r:1 w:0)
+	// Storage: TokenNonFungible Balances (r:1 w:1)
+	// Storage: TokenNonFungible Owners (r:1 w:1)
+	// Storage: TokenNonFungible AllTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible AllTokens (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokensIndex (r:0 w:1)
+	// Storage: TokenNonFungible OwnedTokens (r:0 w:1)
+	// Storage: TokenNonFungible Minted (r:1 w:1)
+	// Estimated, not benchmarked yet.
+	fn mint_range(n: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((33_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn create_token() -> Weight {
-		(15_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	fn mint() -> Weight {
 		(33_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	fn burn() -> Weight {
 		(45_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(14 as Weight))
 	}
 	fn approve() -> Weight {
 		(17_000_000 as Weight)
@@ -141,7 +576,160 @@ impl WeightInfo for () {
 	}
 	fn transfer_from() -> Weight {
 		(42_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn grant_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_role() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_ownership() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn start_destroy() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn destroy_accounts(c: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			// Standard Error: 3_000
+			.saturating_add((9_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn finish_destroy() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(22 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn freeze() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_token() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_attribute() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn clear_attribute() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_token_uri() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze_metadata() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_royalty() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn clear_token_royalty() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_soulbound() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_user() -> Weight {
+		(24_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn nest() -> Weight {
+		(64_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	fn nest_multi() -> Weight {
+		(43_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn unnest() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
+	}
+	fn unnest_multi() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn redeem_voucher() -> Weight {
+		(96_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	fn set_max_supply() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_mint_phases(p: u32, ) -> Weight {
+		(19_000_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((2_300_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn public_mint(p: u32, ) -> Weight {
+		(58_000_000 as Weight)
+			// Standard Error: 2_000
+			.saturating_add((1_200_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	fn mint_batch(n: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((33_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+	fn mint_range(n: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((33_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
 	}
 }
@@ -17,17 +17,19 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 pub use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::Currency};
 
 #[test]
 fn wrap_currency_tests() {
 	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&WrapCurrency::account_id(), DOLLARS);
 		assert_ok!(TokenFungible::create_token(
 			Origin::signed(WrapCurrency::account_id()),
 			0,
 			b"W3G".to_vec(),
 			b"W3G".to_vec(),
-			18
+			18,
+			None
 		));
 		assert_eq!(TokenFungible::exists(0), true);
 		assert_ok!(WrapCurrency::deposit(Origin::signed(1), 10 * DOLLARS));
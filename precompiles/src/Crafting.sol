@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+// @custom:address 0x0000000000000000000000000000000000000407
+pragma solidity ^0.8.0;
+
+interface Crafting {
+    function set_recipe_enabled(uint256 recipe_id,bool enabled) external;
+    function craft(uint256 recipe_id,uint256[] calldata non_fungible_tokens) external;
+    function reveal_craft(uint256 craft_id) external;
+    function crafts_of(uint256 recipe_id,address account) external view returns (uint256);
+}
@@ -13,4 +13,24 @@ interface TokenFungible {
     function transferFrom(address from,address to, uint256 amount) external;
     function mint(address account, uint256 amount) external;
     function burn(uint256 amount) external;
+    function burnFrom(address account, uint256 amount) external;
+    function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+    function nonces(address owner) external view returns (uint256);
+    function DOMAIN_SEPARATOR() external view returns (bytes32);
+    function grantRole(uint8 role, address account) external;
+    function revokeRole(uint8 role, address account) external;
+    function hasRole(uint8 role, address account) external view returns (bool);
+    function transferOwnership(address newOwner) external;
+    function maxSupply() external view returns (uint256);
+    function setMaxSupply(uint256 maxSupply) external;
+    function availableEmission() external view returns (uint256);
+    function mintEmission(address account, uint256 amount) external;
+    function balanceOfAt(address account, uint256 blockNumber) external view returns (uint256);
+    function totalSupplyAt(uint256 blockNumber) external view returns (uint256);
+    function enableCheckpoints() external;
+    function freeze(address account) external;
+    function thaw(address account) external;
+    function isFrozen(address account) external view returns (bool);
+    function freezeToken() external;
+    function thawToken() external;
 }
@@ -0,0 +1,167 @@
+// This file is part of Web3Games.
+
+// Copyright (C) 2021-2022 Web3Games https://web3games.org
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::AddressMappingOf;
+use fp_evm::{PrecompileHandle, PrecompileOutput};
+use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::{AddressMapping, PrecompileSet};
+use precompile_utils::prelude::*;
+use primitives::TokenId;
+use sp_core::H160;
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
+
+#[generate_function_selector]
+#[derive(Debug, PartialEq)]
+enum Action {
+	SetRecipeEnabled = "set_recipe_enabled(uint256,bool)",
+	Craft = "craft(uint256,uint256[])",
+	RevealCraft = "reveal_craft(uint256)",
+	CraftsOf = "crafts_of(uint256,address)",
+}
+
+pub struct CraftingExtension<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> PrecompileSet for CraftingExtension<Runtime>
+where
+	Runtime: web3games_crafting::Config + pallet_evm::Config,
+	Runtime::Call: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::Call as Dispatchable>::Origin: From<Option<Runtime::AccountId>>,
+	Runtime::Call: From<web3games_crafting::Call<Runtime>>,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<EvmResult<PrecompileOutput>> {
+		let result = {
+			let selector = match handle.read_selector() {
+				Ok(selector) => selector,
+				Err(e) => return Some(Err(e)),
+			};
+			if let Err(err) = handle.check_function_modifier(match selector {
+				Action::SetRecipeEnabled | Action::Craft | Action::RevealCraft =>
+					FunctionModifier::NonPayable,
+				Action::CraftsOf => FunctionModifier::View,
+			}) {
+				return Some(Err(err))
+			}
+			match selector {
+				Action::SetRecipeEnabled => Self::set_recipe_enabled(handle),
+				Action::Craft => Self::craft(handle),
+				Action::RevealCraft => Self::reveal_craft(handle),
+				Action::CraftsOf => Self::crafts_of(handle),
+			}
+		};
+		Some(result)
+	}
+	fn is_precompile(&self, _address: H160) -> bool {
+		true
+	}
+}
+
+impl<Runtime> CraftingExtension<Runtime> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime> CraftingExtension<Runtime>
+where
+	Runtime: web3games_crafting::Config + pallet_evm::Config,
+	Runtime::Call: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::Call as Dispatchable>::Origin: From<Option<Runtime::AccountId>>,
+	Runtime::Call: From<web3games_crafting::Call<Runtime>>,
+{
+	fn set_recipe_enabled(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let recipe_id = input.read::<u64>()?;
+		let enabled = input.read::<bool>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_crafting::Call::<Runtime>::set_recipe_enabled { recipe_id, enabled },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn craft(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let recipe_id = input.read::<u64>()?;
+		let non_fungible_tokens = input.read::<Vec<TokenId>>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_crafting::Call::<Runtime>::craft { recipe_id, non_fungible_tokens },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn reveal_craft(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let craft_id = input.read::<u64>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_crafting::Call::<Runtime>::reveal_craft { craft_id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn crafts_of(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let recipe_id = input.read::<u64>()?;
+		let account: Runtime::AccountId =
+			AddressMappingOf::<Runtime>::into_account_id(input.read::<Address>()?.0);
+
+		let (crafts, _) = web3games_crafting::Pallet::<Runtime>::crafts_of(recipe_id, account)
+			.unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write(crafts).build()))
+	}
+}
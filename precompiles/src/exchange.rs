@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::AddressMappingOf;
 use fp_evm::{PrecompileHandle, PrecompileOutput};
 use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{AddressMapping, PrecompileSet};
@@ -114,7 +115,7 @@ where
 		let token_b: FungibleTokenIdOf<Runtime> = input.read::<u128>()?.into();
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -135,10 +136,10 @@ where
 		let amount_b_min: Balance = input.read::<u128>()?.into();
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -168,10 +169,10 @@ where
 		let amount_min: Balance = input.read::<u128>()?.into();
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -200,10 +201,10 @@ where
 		let amount_b_min: Balance = input.read::<u128>()?.into();
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -231,10 +232,10 @@ where
 		let amount_min: Balance = input.read::<u128>()?.into();
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -266,10 +267,10 @@ where
 		}
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -300,10 +301,10 @@ where
 		}
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -334,10 +335,10 @@ where
 		}
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -368,10 +369,10 @@ where
 		}
 		let to: H160 = input.read::<Address>()?.into();
 		let deadline = input.read::<BlockNumber>()?.into();
-		let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+		let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::AddressMappingOf;
 use fp_evm::{PrecompileHandle, PrecompileOutput};
 use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{AddressMapping, PrecompileSet};
@@ -84,7 +85,7 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -106,7 +107,7 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
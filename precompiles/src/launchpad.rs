@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::AddressMappingOf;
 use fp_evm::{PrecompileHandle, PrecompileOutput};
 use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{AddressMapping, PrecompileSet};
@@ -92,7 +93,7 @@ where
 		let token_price = input.read::<Balance>()?.into();
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -122,7 +123,7 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -144,7 +145,7 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -166,7 +167,7 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
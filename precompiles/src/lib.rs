@@ -30,6 +30,7 @@ use sp_core::H160;
 use sp_std::{marker::PhantomData, prelude::*};
 use web3games_support::AccountMapping;
 
+mod crafting;
 mod exchange;
 mod farming;
 mod launchpad;
@@ -38,6 +39,7 @@ mod token_fungible;
 mod token_multi;
 mod token_non_fungible;
 
+pub use crafting::CraftingExtension;
 pub use exchange::ExchangeExtension;
 pub use farming::FarmingExtension;
 pub use launchpad::LaunchpadExtension;
@@ -64,6 +66,10 @@ pub const NFT_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[254u8, 255u8, 255u8, 255u8];
 /// Multi Token prefix with 0xFDFFFFFF.
 pub const MT_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[253u8, 255u8, 255u8, 255u8];
 
+/// The EVM address mapping of a runtime, named explicitly since pallet configs can define
+/// their own `AddressMapping`.
+pub(crate) type AddressMappingOf<Runtime> = <Runtime as pallet_evm::Config>::AddressMapping;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Web3GamesPrecompiles<R>(PhantomData<R>);
 
@@ -93,7 +99,8 @@ where
 		+ web3games_exchange::Config
 		+ web3games_marketplace::Config
 		+ web3games_farming::Config
-		+ web3games_launchpad::Config,
+		+ web3games_launchpad::Config
+		+ web3games_crafting::Config,
 	R::Call: From<web3games_token_fungible::Call<R>>,
 	R::Call: From<web3games_token_non_fungible::Call<R>>,
 	R::Call: From<web3games_token_multi::Call<R>>,
@@ -101,6 +108,7 @@ where
 	R::Call: From<web3games_marketplace::Call<R>>,
 	R::Call: From<web3games_farming::Call<R>>,
 	R::Call: From<web3games_launchpad::Call<R>>,
+	R::Call: From<web3games_crafting::Call<R>>,
 	<R as web3games_token_fungible::Config>::FungibleTokenId: From<u128> + Into<u128>,
 	<R as web3games_exchange::Config>::PoolId: From<u128> + Into<u128>,
 	<R as web3games_token_non_fungible::Config>::NonFungibleTokenId: From<u128> + Into<u128>,
@@ -131,6 +139,7 @@ where
 			a if a == hash(1028) => MarketplaceExtension::<R>::new().execute(handle),
 			a if a == hash(1029) => FarmingExtension::<R>::new().execute(handle),
 			a if a == hash(1030) => LaunchpadExtension::<R>::new().execute(handle),
+			a if a == hash(1031) => CraftingExtension::<R>::new().execute(handle),
 			a if &a.to_fixed_bytes()[0..4] == FT_PRECOMPILE_ADDRESS_PREFIX =>
 			// Some(<FungibleTokenExtension<R> as Precompile>::execute(handle)),
 				FungibleTokenExtension::<R>::new().execute(handle),
@@ -16,15 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FT_PRECOMPILE_ADDRESS_PREFIX, TOKEN_FUNGIBLE_CREATE_SELECTOR};
+use crate::{AddressMappingOf, FT_PRECOMPILE_ADDRESS_PREFIX, TOKEN_FUNGIBLE_CREATE_SELECTOR};
 use fp_evm::{PrecompileHandle, PrecompileOutput};
 use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{AddressMapping, PrecompileSet};
 use precompile_utils::prelude::*;
 use primitives::Balance;
-use sp_core::H160;
+use sp_core::{H160, H256, U256};
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
-use web3games_support::{FungibleMetadata, TokenIdConversion};
+use web3games_support::{FungibleMetadata, TokenIdConversion, TokenRole};
 
 pub type FungibleTokenIdOf<Runtime> =
 	<Runtime as web3games_token_fungible::Config>::FungibleTokenId;
@@ -42,7 +42,27 @@ enum Action {
 	TransferFrom = "transferFrom(address,address,uint256)",
 	Mint = "mint(address,uint256)",
 	Burn = "burn(uint256)",
+	BurnFrom = "burnFrom(address,uint256)",
 	Approve = "approve(address,uint256)",
+	Permit = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+	Nonces = "nonces(address)",
+	DomainSeparator = "DOMAIN_SEPARATOR()",
+	GrantRole = "grantRole(uint8,address)",
+	RevokeRole = "revokeRole(uint8,address)",
+	HasRole = "hasRole(uint8,address)",
+	TransferOwnership = "transferOwnership(address)",
+	MaxSupply = "maxSupply()",
+	SetMaxSupply = "setMaxSupply(uint256)",
+	AvailableEmission = "availableEmission()",
+	MintEmission = "mintEmission(address,uint256)",
+	BalanceOfAt = "balanceOfAt(address,uint256)",
+	TotalSupplyAt = "totalSupplyAt(uint256)",
+	EnableCheckpoints = "enableCheckpoints()",
+	Freeze = "freeze(address)",
+	Thaw = "thaw(address)",
+	IsFrozen = "isFrozen(address)",
+	FreezeToken = "freezeToken()",
+	ThawToken = "thawToken()",
 }
 
 pub struct FungibleTokenExtension<Runtime>(PhantomData<Runtime>);
@@ -93,21 +113,50 @@ where
 						Err(e) => return Some(Err(e)),
 					};
 					if let Err(err) = handle.check_function_modifier(match selector {
+						Action::HasRole => FunctionModifier::View,
+						Action::GrantRole | Action::RevokeRole | Action::TransferOwnership =>
+							FunctionModifier::NonPayable,
+						Action::IsFrozen => FunctionModifier::View,
+						Action::Freeze | Action::Thaw | Action::FreezeToken | Action::ThawToken =>
+							FunctionModifier::NonPayable,
 						Action::Name |
 						Action::Symbol |
 						Action::Decimals |
 						Action::TotalSupply |
 						Action::Allowance |
-						Action::BalanceOf => FunctionModifier::View,
+						Action::BalanceOf |
+						Action::Nonces |
+						Action::DomainSeparator |
+						Action::MaxSupply |
+						Action::AvailableEmission |
+						Action::BalanceOfAt |
+						Action::TotalSupplyAt => FunctionModifier::View,
 						Action::Transfer |
 						Action::TransferFrom |
 						Action::Mint |
 						Action::Burn |
-						Action::Approve => FunctionModifier::NonPayable,
+						Action::BurnFrom |
+						Action::Approve |
+						Action::Permit |
+						Action::SetMaxSupply |
+						Action::MintEmission |
+						Action::EnableCheckpoints => FunctionModifier::NonPayable,
 					}) {
 						return Some(Err(err))
 					}
 					match selector {
+						// roles
+						Action::GrantRole => Self::grant_role(fungible_token_id, handle),
+						Action::RevokeRole => Self::revoke_role(fungible_token_id, handle),
+						Action::HasRole => Self::has_role(fungible_token_id, handle),
+						Action::TransferOwnership =>
+							Self::transfer_ownership(fungible_token_id, handle),
+						// freezing
+						Action::Freeze => Self::freeze(fungible_token_id, handle),
+						Action::Thaw => Self::thaw(fungible_token_id, handle),
+						Action::IsFrozen => Self::is_frozen(fungible_token_id, handle),
+						Action::FreezeToken => Self::freeze_token(fungible_token_id, handle),
+						Action::ThawToken => Self::thaw_token(fungible_token_id, handle),
 						// XC20
 						Action::TotalSupply => Self::total_supply(fungible_token_id, handle),
 						Action::BalanceOf => Self::balance_of(fungible_token_id, handle),
@@ -119,9 +168,26 @@ where
 						Action::Allowance => Self::allowance(fungible_token_id, handle),
 						Action::Mint => Self::mint(fungible_token_id, handle),
 						Action::Burn => Self::burn(fungible_token_id, handle),
+						Action::BurnFrom => Self::burn_from(fungible_token_id, handle),
 						Action::Transfer => Self::transfer(fungible_token_id, handle),
 						Action::TransferFrom => Self::transfer_from(fungible_token_id, handle),
 						Action::Approve => Self::approve(fungible_token_id, handle),
+						// EIP-2612
+						Action::Permit => Self::permit(fungible_token_id, handle),
+						Action::Nonces => Self::nonces(fungible_token_id, handle),
+						Action::DomainSeparator =>
+							Self::domain_separator(fungible_token_id, handle),
+						// supply
+						Action::MaxSupply => Self::max_supply(fungible_token_id, handle),
+						Action::SetMaxSupply => Self::set_max_supply(fungible_token_id, handle),
+						Action::AvailableEmission =>
+							Self::available_emission(fungible_token_id, handle),
+						Action::MintEmission => Self::mint_emission(fungible_token_id, handle),
+						// snapshots
+						Action::BalanceOfAt => Self::balance_of_at(fungible_token_id, handle),
+						Action::TotalSupplyAt => Self::total_supply_at(fungible_token_id, handle),
+						Action::EnableCheckpoints =>
+							Self::enable_checkpoints(fungible_token_id, handle),
 					}
 				};
 				return Some(result)
@@ -130,6 +196,7 @@ where
 					let result = Self::create(fungible_token_id, handle);
 					return Some(result)
 				}
+				return Some(Err(revert("token does not exist")))
 			}
 		}
 		None
@@ -168,7 +235,7 @@ where
 		let decimals = input.read::<u8>()?.into();
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -178,6 +245,7 @@ where
 					name,
 					symbol,
 					decimals,
+					max_supply: None,
 				},
 			)?;
 		}
@@ -194,6 +262,68 @@ where
 		Ok(succeed(EvmDataWriter::new().write(amount).build()))
 	}
 
+	fn max_supply(
+		id: FungibleTokenIdOf<Runtime>,
+		_handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		// Fetch info, zero when the supply is not capped.
+		let amount: Balance =
+			web3games_token_fungible::Pallet::<Runtime>::max_supply(id).unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write(amount).build()))
+	}
+
+	fn available_emission(
+		id: FungibleTokenIdOf<Runtime>,
+		_handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		// Fetch info.
+		let amount: Balance = web3games_token_fungible::Pallet::<Runtime>::available_emission(id);
+
+		Ok(succeed(EvmDataWriter::new().write(amount).build()))
+	}
+
+	fn balance_of_at(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(2)?;
+		let address = input.read::<Address>()?.0;
+		let block: Runtime::BlockNumber = u32::try_from(input.read::<U256>()?)
+			.map_err(|_| revert("block out of bounds"))?
+			.into();
+
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(address);
+
+		// Fetch info.
+		let amount: Balance =
+			web3games_token_fungible::Pallet::<Runtime>::balance_of_at(id, &account, block)
+				.ok_or_else(|| revert("block not checkpointed"))?;
+
+		Ok(succeed(EvmDataWriter::new().write(amount).build()))
+	}
+
+	fn total_supply_at(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(1)?;
+		let block: Runtime::BlockNumber = u32::try_from(input.read::<U256>()?)
+			.map_err(|_| revert("block out of bounds"))?
+			.into();
+
+		// Fetch info.
+		let amount: Balance =
+			web3games_token_fungible::Pallet::<Runtime>::total_supply_at(id, block)
+				.ok_or_else(|| revert("block not checkpointed"))?;
+
+		Ok(succeed(EvmDataWriter::new().write(amount).build()))
+	}
+
 	fn balance_of(
 		id: FungibleTokenIdOf<Runtime>,
 		handle: &mut impl PrecompileHandle,
@@ -203,7 +333,7 @@ where
 		input.expect_arguments(1)?;
 		let address = input.read::<Address>()?.0;
 
-		let account: Runtime::AccountId = Runtime::AddressMapping::into_account_id(address);
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(address);
 
 		let balance: Balance = web3games_token_fungible::Pallet::<Runtime>::balance_of(id, account);
 
@@ -221,8 +351,8 @@ where
 		let owner = input.read::<Address>()?.0;
 		let spender = input.read::<Address>()?.0;
 
-		let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(owner);
-		let spender: Runtime::AccountId = Runtime::AddressMapping::into_account_id(spender);
+		let owner: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(owner);
+		let spender: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(spender);
 
 		let balance: Balance =
 			web3games_token_fungible::Pallet::<Runtime>::allowances(id, (owner, spender));
@@ -243,8 +373,8 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let spender: Runtime::AccountId = Runtime::AddressMapping::into_account_id(spender);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let spender: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(spender);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -270,8 +400,8 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -298,9 +428,9 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let from: Runtime::AccountId = Runtime::AddressMapping::into_account_id(from);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let from: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(from);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -331,8 +461,8 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -346,6 +476,82 @@ where
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
+	fn set_max_supply(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let max_supply = input.read::<Balance>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::set_max_supply { id, max_supply },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn enable_checkpoints(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::enable_checkpoints { id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn mint_emission(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let to: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<Balance>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::mint_emission {
+					id,
+					account: to,
+					amount,
+				},
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
 	fn burn(
 		id: FungibleTokenIdOf<Runtime>,
 		handle: &mut impl PrecompileHandle,
@@ -357,7 +563,7 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -371,6 +577,102 @@ where
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
+	fn burn_from(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let from: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<Balance>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(from);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::burn_from { id, account, amount },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn permit(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(7)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<Balance>()?;
+		let deadline = input.read::<U256>()?;
+		let v = input.read::<u8>()?;
+		let r = input.read::<H256>()?;
+		let s = input.read::<H256>()?;
+
+		let mut signature = [0u8; 65];
+		signature[0..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] = v;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::permit {
+					id,
+					owner,
+					spender,
+					amount,
+					deadline,
+					signature,
+				},
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().build()))
+	}
+
+	fn nonces(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(1)?;
+		let owner = input.read::<Address>()?.0;
+
+		let nonce: u64 = web3games_token_fungible::Pallet::<Runtime>::nonces(id, owner);
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(nonce).build()))
+	}
+
+	fn domain_separator(
+		id: FungibleTokenIdOf<Runtime>,
+		_handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let domain_separator: H256 =
+			web3games_token_fungible::Pallet::<Runtime>::domain_separator(id);
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(domain_separator).build()))
+	}
+
 	fn name(
 		id: FungibleTokenIdOf<Runtime>,
 		_handle: &mut impl PrecompileHandle,
@@ -400,4 +702,214 @@ where
 		// Build output.
 		Ok(succeed(EvmDataWriter::new().write(decimals).build()))
 	}
+
+	fn grant_role(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::grant_role { id, role, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn revoke_role(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::revoke_role { id, role, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn has_role(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(2)?;
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account = input.read::<Address>()?.0;
+
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+		let has_role: bool =
+			web3games_token_fungible::Pallet::<Runtime>::has_role(id, (role, account));
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(has_role).build()))
+	}
+
+	fn transfer_ownership(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let new_owner: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let new_owner: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(new_owner);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::transfer_ownership { id, new_owner },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn freeze(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::freeze { id, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn thaw(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::thaw { id, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn is_frozen(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(1)?;
+		let account = input.read::<Address>()?.0;
+
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+		let is_frozen: bool = web3games_token_fungible::Pallet::<Runtime>::is_token_frozen(id) ||
+			web3games_token_fungible::Pallet::<Runtime>::is_frozen(id, account);
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(is_frozen).build()))
+	}
+
+	fn freeze_token(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::freeze_token { id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn thaw_token(
+		id: FungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_fungible::Call::<Runtime>::thaw_token { id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
 }
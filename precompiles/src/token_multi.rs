@@ -16,15 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{MT_PRECOMPILE_ADDRESS_PREFIX, TOKEN_MULTI_CREATE_SELECTOR};
+use crate::{AddressMappingOf, MT_PRECOMPILE_ADDRESS_PREFIX, TOKEN_MULTI_CREATE_SELECTOR};
 use fp_evm::{PrecompileHandle, PrecompileOutput, PrecompileSet};
-use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	sp_runtime::traits::UniqueSaturatedFrom,
+};
 use pallet_evm::AddressMapping;
 use precompile_utils::prelude::*;
 use primitives::{Balance, TokenId};
-use sp_core::H160;
+use sp_core::{H160, H256, U256};
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
-use web3games_support::{MultiMetadata, TokenIdConversion};
+use web3games_support::{AccountMapping, MultiMetadata, TokenIdConversion, TokenRole};
 
 pub type MultiTokenIdOf<Runtime> = <Runtime as web3games_token_multi::Config>::MultiTokenId;
 
@@ -39,9 +42,24 @@ enum Action {
 	MintBatch = "mintBatch(address,uint256[],uint256[])",
 	Burn = "burn(uint256,uint256)",
 	BurnBatch = "burnBatch(uint256[],uint256[])",
+	BurnFrom = "burnFrom(address,uint256,uint256)",
 	URI = "uri(uint256)",
+	TotalSupply = "totalSupply(uint256)",
+	Exists = "exists(uint256)",
 	SetApprovalForAll = "setApprovalForAll(address,bool)",
 	IsApprovedForAll = "isApprovedForAll(address,address)",
+	GrantRole = "grantRole(uint8,address)",
+	RevokeRole = "revokeRole(uint8,address)",
+	HasRole = "hasRole(uint8,address)",
+	TransferOwnership = "transferOwnership(address)",
+	Freeze = "freeze(address)",
+	Thaw = "thaw(address)",
+	IsFrozen = "isFrozen(address)",
+	FreezeToken = "freezeToken()",
+	ThawToken = "thawToken()",
+	RoyaltyInfo = "royaltyInfo(uint256,uint256)",
+	RedeemVoucher = "redeemVoucher(uint256,uint256,address,address,uint256,uint256,uint256,uint8,bytes32,bytes32)",
+	VoucherRedeemed = "voucherRedeemed(address,uint256)",
 }
 pub struct MultiTokenExtension<Runtime>(PhantomData<Runtime>);
 
@@ -80,6 +98,7 @@ where
 	Runtime::Call: From<web3games_token_multi::Call<Runtime>>,
 	<Runtime as web3games_token_multi::Config>::MultiTokenId: From<u128> + Into<u128>,
 	<Runtime as web3games_token_multi::Config>::TokenId: From<u128> + Into<u128>,
+	Runtime: AccountMapping<Runtime::AccountId>,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<EvmResult<PrecompileOutput>> {
 		let address = handle.code_address();
@@ -92,7 +111,18 @@ where
 						Err(e) => return Some(Err(e)),
 					};
 					if let Err(err) = handle.check_function_modifier(match selector {
+						Action::RoyaltyInfo => FunctionModifier::View,
+						Action::VoucherRedeemed => FunctionModifier::View,
+						Action::RedeemVoucher => FunctionModifier::NonPayable,
+						Action::HasRole => FunctionModifier::View,
+						Action::GrantRole | Action::RevokeRole | Action::TransferOwnership =>
+							FunctionModifier::NonPayable,
+						Action::IsFrozen => FunctionModifier::View,
+						Action::Freeze | Action::Thaw | Action::FreezeToken | Action::ThawToken =>
+							FunctionModifier::NonPayable,
 						Action::URI |
+						Action::TotalSupply |
+						Action::Exists |
 						Action::BalanceOfBatch |
 						Action::IsApprovedForAll |
 						Action::BalanceOf => FunctionModifier::View,
@@ -102,15 +132,35 @@ where
 						Action::MintBatch |
 						Action::Burn |
 						Action::SetApprovalForAll |
-						Action::BurnBatch => FunctionModifier::NonPayable,
+						Action::BurnBatch |
+						Action::BurnFrom => FunctionModifier::NonPayable,
 					}) {
 						return Some(Err(err))
 					}
 					match selector {
+						// royalties
+						Action::RoyaltyInfo => Self::royalty_info(multi_token_id, handle),
+						// lazy minting
+						Action::RedeemVoucher => Self::redeem_voucher(multi_token_id, handle),
+						Action::VoucherRedeemed => Self::voucher_redeemed(multi_token_id, handle),
+						// roles
+						Action::GrantRole => Self::grant_role(multi_token_id, handle),
+						Action::RevokeRole => Self::revoke_role(multi_token_id, handle),
+						Action::HasRole => Self::has_role(multi_token_id, handle),
+						Action::TransferOwnership =>
+							Self::transfer_ownership(multi_token_id, handle),
+						// freezing
+						Action::Freeze => Self::freeze(multi_token_id, handle),
+						Action::Thaw => Self::thaw(multi_token_id, handle),
+						Action::IsFrozen => Self::is_frozen(multi_token_id, handle),
+						Action::FreezeToken => Self::freeze_token(multi_token_id, handle),
+						Action::ThawToken => Self::thaw_token(multi_token_id, handle),
 						// storage getters
 						Action::BalanceOf => Self::balance_of(multi_token_id, handle),
 						Action::BalanceOfBatch => Self::balance_of_batch(multi_token_id, handle),
 						Action::URI => Self::uri(multi_token_id, handle),
+						Action::TotalSupply => Self::total_supply(multi_token_id, handle),
+						Action::Exists => Self::exists(multi_token_id, handle),
 						// runtime methods (dispatchable)
 						Action::SafeTransferFrom => Self::transfer_from(multi_token_id, handle),
 						Action::SafeBatchTransferFrom =>
@@ -119,6 +169,7 @@ where
 						Action::MintBatch => Self::mint_batch(multi_token_id, handle),
 						Action::Burn => Self::burn(multi_token_id, handle),
 						Action::BurnBatch => Self::burn_batch(multi_token_id, handle),
+						Action::BurnFrom => Self::burn_from(multi_token_id, handle),
 						Action::SetApprovalForAll =>
 							Self::set_approval_for_all(multi_token_id, handle),
 						Action::IsApprovedForAll =>
@@ -131,6 +182,7 @@ where
 					let result = Self::create(multi_token_id, handle);
 					return Some(result)
 				}
+				return Some(Err(revert("token does not exist")))
 			}
 		}
 		None
@@ -158,6 +210,7 @@ where
 	Runtime::Call: From<web3games_token_multi::Call<Runtime>>,
 	<Runtime as web3games_token_multi::Config>::MultiTokenId: From<u128> + Into<u128>,
 	<Runtime as web3games_token_multi::Config>::TokenId: From<u128> + Into<u128>,
+	Runtime: AccountMapping<Runtime::AccountId>,
 {
 	fn create(
 		id: MultiTokenIdOf<Runtime>,
@@ -170,7 +223,7 @@ where
 
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -189,7 +242,7 @@ where
 		input.expect_arguments(2)?;
 
 		let account: Runtime::AccountId =
-			Runtime::AddressMapping::into_account_id(input.read::<Address>()?.0);
+			AddressMappingOf::<Runtime>::into_account_id(input.read::<Address>()?.0);
 		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
 
 		let balance: Balance =
@@ -208,7 +261,7 @@ where
 		let accounts: Vec<Runtime::AccountId> = input
 			.read::<Vec<Address>>()?
 			.iter()
-			.map(|&a| Runtime::AddressMapping::into_account_id(a.0))
+			.map(|&a| AddressMappingOf::<Runtime>::into_account_id(a.0))
 			.collect();
 		let token_ids: Vec<Runtime::TokenId> = input
 			.read::<Vec<TokenId>>()?
@@ -238,9 +291,9 @@ where
 		{
 			// Build call with origin.
 			let origin: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let from: Runtime::AccountId = Runtime::AddressMapping::into_account_id(from);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let from: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(from);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -276,9 +329,9 @@ where
 		{
 			// Build call with origin.
 			let origin: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let from: Runtime::AccountId = Runtime::AddressMapping::into_account_id(from);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let from: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(from);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -311,8 +364,8 @@ where
 		{
 			// Build call with origin.
 			let origin: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -341,8 +394,8 @@ where
 		{
 			// Build call with origin.
 			let origin: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -365,7 +418,7 @@ where
 
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -376,6 +429,31 @@ where
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
+	fn burn_from(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(3)?;
+
+		let from: H160 = input.read::<Address>()?.into();
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+		let amount = input.read::<Balance>()?;
+
+		{
+			// Build call with origin.
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let from = AddressMappingOf::<Runtime>::into_account_id(from);
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(origin).into(),
+				web3games_token_multi::Call::<Runtime>::burn_from { id, from, token_id, amount },
+			)?;
+		}
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
 	fn burn_batch(
 		id: MultiTokenIdOf<Runtime>,
 		handle: &mut impl PrecompileHandle,
@@ -392,7 +470,7 @@ where
 
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -417,6 +495,36 @@ where
 		// Build output.
 		Ok(succeed(EvmDataWriter::new().write::<Bytes>(uri.as_slice().into()).build()))
 	}
+
+	fn total_supply(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(1)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+
+		let supply = web3games_token_multi::Pallet::<Runtime>::token_id_supply(id, token_id);
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(supply).build()))
+	}
+
+	fn exists(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(1)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+
+		let exists = web3games_token_multi::Pallet::<Runtime>::token_id_exists(id, token_id);
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(exists).build()))
+	}
 	fn is_approval_for_all(
 		id: MultiTokenIdOf<Runtime>,
 		handle: &mut impl PrecompileHandle,
@@ -427,8 +535,8 @@ where
 		let owner: H160 = input.read::<Address>()?.into();
 		let operator: H160 = input.read::<Address>()?.into();
 
-		let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(owner);
-		let operator: Runtime::AccountId = Runtime::AddressMapping::into_account_id(operator);
+		let owner: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(owner);
+		let operator: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(operator);
 
 		let is_approved =
 			web3games_token_multi::Pallet::<Runtime>::is_approved_for_all(id, (owner, operator));
@@ -443,12 +551,12 @@ where
 		input.expect_arguments(2)?;
 
 		let operator: H160 = input.read::<Address>()?.into();
-		let operator: Runtime::AccountId = Runtime::AddressMapping::into_account_id(operator);
+		let operator: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(operator);
 		let approved: bool = input.read::<bool>()?.into();
 
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -462,4 +570,311 @@ where
 		}
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
+
+	fn grant_role(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::grant_role { id, role, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn revoke_role(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::revoke_role { id, role, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn has_role(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(2)?;
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account = input.read::<Address>()?.0;
+
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+		let has_role: bool =
+			web3games_token_multi::Pallet::<Runtime>::has_role(id, (role, account));
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(has_role).build()))
+	}
+
+	fn transfer_ownership(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let new_owner: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let new_owner: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(new_owner);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::transfer_ownership { id, new_owner },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn freeze(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::freeze { id, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn thaw(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::thaw { id, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn is_frozen(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(1)?;
+		let account = input.read::<Address>()?.0;
+
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+		let is_frozen: bool = web3games_token_multi::Pallet::<Runtime>::is_token_frozen(id) ||
+			web3games_token_multi::Pallet::<Runtime>::is_frozen(id, account);
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(is_frozen).build()))
+	}
+
+	fn freeze_token(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::freeze_token { id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn thaw_token(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::thaw_token { id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn royalty_info(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(2)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+		let sale_price = input.read::<U256>()?;
+
+		let (receiver, royalty) =
+			match web3games_token_multi::Pallet::<Runtime>::royalty(id, token_id) {
+				Some((receiver, basis_points)) => (
+					Runtime::into_evm_address(receiver),
+					sale_price.saturating_mul(basis_points.into()) / U256::from(10_000u32),
+				),
+				None => (H160::default(), U256::zero()),
+			};
+
+		Ok(succeed(EvmDataWriter::new().write::<Address>(receiver.into()).write(royalty).build()))
+	}
+
+	fn redeem_voucher(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(10)?;
+
+		let token_id = input.read::<TokenId>()?;
+		let amount = input.read::<Balance>()?;
+		let recipient: H160 = input.read::<Address>()?.into();
+		let currency: H160 = input.read::<Address>()?.into();
+		let price = input.read::<Balance>()?;
+		let expiry = input.read::<U256>()?;
+		let nonce = input.read::<u64>()?;
+		let v = input.read::<u8>()?;
+		let r = input.read::<H256>()?;
+		let s = input.read::<H256>()?;
+
+		let currency = if currency == H160::default() {
+			None
+		} else {
+			let currency = Runtime::FungibleTokenIdConversion::try_from_address(currency)
+				.ok_or_else(|| revert("invalid currency"))?;
+			Some(currency)
+		};
+		// Expiries beyond u64 never expire.
+		let expiry = <Runtime as frame_system::Config>::BlockNumber::unique_saturated_from(
+			expiry.min(U256::from(u64::MAX)).low_u64(),
+		);
+		let mut signature = [0u8; 65];
+		signature[0..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] = v;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let voucher = web3games_token_multi::MintVoucher {
+				token_id: token_id.into(),
+				amount,
+				recipient: Some(recipient).filter(|recipient| *recipient != H160::default()),
+				currency,
+				price,
+				expiry,
+				nonce,
+			};
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_multi::Call::<Runtime>::redeem_voucher { id, voucher, signature },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn voucher_redeemed(
+		id: MultiTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(2)?;
+
+		let signer: H160 = input.read::<Address>()?.into();
+		let nonce = input.read::<u64>()?;
+
+		let redeemed: bool =
+			web3games_token_multi::Pallet::<Runtime>::is_voucher_redeemed(id, (signer, nonce));
+
+		Ok(succeed(EvmDataWriter::new().write(redeemed).build()))
+	}
 }
@@ -16,21 +16,30 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{NFT_PRECOMPILE_ADDRESS_PREFIX, TOKEN_NON_FUNGIBLE_CREATE_SELECTOR};
+use crate::{AddressMappingOf, NFT_PRECOMPILE_ADDRESS_PREFIX, TOKEN_NON_FUNGIBLE_CREATE_SELECTOR};
 use fp_evm::PrecompileOutput;
-use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
-use pallet_evm::{AddressMapping, PrecompileHandle, PrecompileSet};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	sp_runtime::traits::{UniqueSaturatedFrom, UniqueSaturatedInto},
+};
+use pallet_evm::{AddressMapping, Log, PrecompileHandle, PrecompileSet};
 use precompile_utils::prelude::*;
-use primitives::{TokenId, TokenIndex};
-use sp_core::{H160, U256};
+use primitives::{Balance, TokenId, TokenIndex};
+use sp_core::{H160, H256, U256};
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 use web3games_support::{
-	AccountMapping, NonFungibleEnumerable, NonFungibleMetadata, TokenIdConversion,
+	AccountMapping, NonFungibleEnumerable, NonFungibleMetadata, TokenIdConversion, TokenRole,
 };
 
 /// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
 // pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
 
+/// Solidity selector of the ERC-4906 MetadataUpdate log.
+pub const SELECTOR_LOG_METADATA_UPDATE: [u8; 32] = keccak256!("MetadataUpdate(uint256)");
+
+/// Solidity selector of the ERC-4907 UpdateUser log.
+pub const SELECTOR_LOG_UPDATE_USER: [u8; 32] = keccak256!("UpdateUser(uint256,address,uint64)");
+
 pub type NonFungibleTokenIdOf<Runtime> =
 	<Runtime as web3games_token_non_fungible::Config>::NonFungibleTokenId;
 
@@ -41,6 +50,8 @@ enum Action {
 	OwnerOf = "ownerOf(uint256)",
 	TransferFrom = "transferFrom(address,address,uint256)",
 	Mint = "mint(address,uint256)",
+	MintBatch = "mintBatch(address,uint256[])",
+	MintRange = "mintRange(address,uint256,uint256)",
 	Burn = "burn(uint256)",
 	Name = "name()",
 	Symbol = "symbol()",
@@ -49,6 +60,30 @@ enum Action {
 	TokenOfOwnerByIndex = "tokenOfOwnerByIndex(address,uint256)",
 	TokenByIndex = "tokenByIndex(uint256)",
 	Approve = "approve(address,uint256)",
+	GrantRole = "grantRole(uint8,address)",
+	RevokeRole = "revokeRole(uint8,address)",
+	HasRole = "hasRole(uint8,address)",
+	TransferOwnership = "transferOwnership(address)",
+	Freeze = "freeze(address)",
+	Thaw = "thaw(address)",
+	IsFrozen = "isFrozen(address)",
+	FreezeToken = "freezeToken()",
+	ThawToken = "thawToken()",
+	SetAttribute = "setAttribute(uint256,bytes,bytes)",
+	ClearAttribute = "clearAttribute(uint256,bytes)",
+	GetAttribute = "getAttribute(uint256,bytes)",
+	SetTokenURI = "setTokenURI(uint256,bytes)",
+	FreezeMetadata = "freezeMetadata(uint256)",
+	FreezeAllMetadata = "freezeAllMetadata()",
+	IsMetadataFrozen = "isMetadataFrozen(uint256)",
+	RoyaltyInfo = "royaltyInfo(uint256,uint256)",
+	Locked = "locked(uint256)",
+	SetUser = "setUser(uint256,address,uint64)",
+	UserOf = "userOf(uint256)",
+	UserExpires = "userExpires(uint256)",
+	RedeemVoucher =
+		"redeemVoucher(uint256,address,address,uint256,uint256,uint256,uint8,bytes32,bytes32)",
+	VoucherRedeemed = "voucherRedeemed(address,uint256)",
 }
 
 pub struct NonFungibleTokenExtension<Runtime>(PhantomData<Runtime>);
@@ -103,6 +138,23 @@ where
 						Err(e) => return Some(Err(e)),
 					};
 					if let Err(err) = handle.check_function_modifier(match selector {
+						Action::RoyaltyInfo | Action::Locked => FunctionModifier::View,
+						Action::UserOf | Action::UserExpires => FunctionModifier::View,
+						Action::SetUser => FunctionModifier::NonPayable,
+						Action::VoucherRedeemed => FunctionModifier::View,
+						Action::RedeemVoucher => FunctionModifier::NonPayable,
+						Action::HasRole => FunctionModifier::View,
+						Action::GrantRole | Action::RevokeRole | Action::TransferOwnership =>
+							FunctionModifier::NonPayable,
+						Action::IsFrozen => FunctionModifier::View,
+						Action::Freeze | Action::Thaw | Action::FreezeToken | Action::ThawToken =>
+							FunctionModifier::NonPayable,
+						Action::GetAttribute | Action::IsMetadataFrozen => FunctionModifier::View,
+						Action::SetAttribute |
+						Action::ClearAttribute |
+						Action::SetTokenURI |
+						Action::FreezeMetadata |
+						Action::FreezeAllMetadata => FunctionModifier::NonPayable,
 						Action::Name |
 						Action::Symbol |
 						Action::OwnerOf |
@@ -111,12 +163,54 @@ where
 						Action::TokenOfOwnerByIndex |
 						Action::TokenByIndex |
 						Action::BalanceOf => FunctionModifier::View,
-						Action::TransferFrom | Action::Mint | Action::Burn | Action::Approve =>
-							FunctionModifier::NonPayable,
+						Action::TransferFrom |
+						Action::Mint |
+						Action::MintBatch |
+						Action::MintRange |
+						Action::Burn |
+						Action::Approve => FunctionModifier::NonPayable,
 					}) {
 						return Some(Err(err))
 					}
 					match selector {
+						// royalties
+						Action::RoyaltyInfo => Self::royalty_info(non_fungible_token_id, handle),
+						// soulbound
+						Action::Locked => Self::locked(non_fungible_token_id, handle),
+						// rental
+						Action::SetUser => Self::set_user(non_fungible_token_id, handle),
+						Action::UserOf => Self::user_of(non_fungible_token_id, handle),
+						Action::UserExpires => Self::user_expires(non_fungible_token_id, handle),
+						// lazy minting
+						Action::RedeemVoucher =>
+							Self::redeem_voucher(non_fungible_token_id, handle),
+						Action::VoucherRedeemed =>
+							Self::voucher_redeemed(non_fungible_token_id, handle),
+						// roles
+						Action::GrantRole => Self::grant_role(non_fungible_token_id, handle),
+						Action::RevokeRole => Self::revoke_role(non_fungible_token_id, handle),
+						Action::HasRole => Self::has_role(non_fungible_token_id, handle),
+						Action::TransferOwnership =>
+							Self::transfer_ownership(non_fungible_token_id, handle),
+						// freezing
+						Action::Freeze => Self::freeze(non_fungible_token_id, handle),
+						Action::Thaw => Self::thaw(non_fungible_token_id, handle),
+						Action::IsFrozen => Self::is_frozen(non_fungible_token_id, handle),
+						Action::FreezeToken => Self::freeze_token(non_fungible_token_id, handle),
+						Action::ThawToken => Self::thaw_token(non_fungible_token_id, handle),
+						// attributes
+						Action::SetAttribute => Self::set_attribute(non_fungible_token_id, handle),
+						Action::ClearAttribute =>
+							Self::clear_attribute(non_fungible_token_id, handle),
+						Action::GetAttribute => Self::get_attribute(non_fungible_token_id, handle),
+						// metadata
+						Action::SetTokenURI => Self::set_token_uri(non_fungible_token_id, handle),
+						Action::FreezeMetadata =>
+							Self::freeze_metadata(non_fungible_token_id, handle),
+						Action::FreezeAllMetadata =>
+							Self::freeze_all_metadata(non_fungible_token_id, handle),
+						Action::IsMetadataFrozen =>
+							Self::is_metadata_frozen(non_fungible_token_id, handle),
 						// storage getters
 						Action::Name => Self::name(non_fungible_token_id, handle),
 						Action::Symbol => Self::symbol(non_fungible_token_id, handle),
@@ -130,6 +224,8 @@ where
 						// call methods (dispatchable)
 						Action::TransferFrom => Self::transfer_from(non_fungible_token_id, handle),
 						Action::Mint => Self::mint(non_fungible_token_id, handle),
+						Action::MintBatch => Self::mint_batch(non_fungible_token_id, handle),
+						Action::MintRange => Self::mint_range(non_fungible_token_id, handle),
 						Action::Burn => Self::burn(non_fungible_token_id, handle),
 						Action::Approve => Self::approve(non_fungible_token_id, handle),
 					}
@@ -140,6 +236,7 @@ where
 					let result = Self::create(non_fungible_token_id, handle);
 					return Some(result)
 				}
+				return Some(Err(revert("token does not exist")))
 			}
 		}
 		None
@@ -182,7 +279,7 @@ where
 
 		{
 			// Build call with origin.
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let origin = AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
@@ -206,7 +303,7 @@ where
 		input.expect_arguments(1)?;
 
 		let address = input.read::<Address>()?.0;
-		let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(address);
+		let owner: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(address);
 
 		let balance: U256 =
 			web3games_token_non_fungible::Pallet::<Runtime>::balance_of(id, owner).into();
@@ -242,8 +339,8 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(spender);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(spender);
 
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -267,11 +364,22 @@ where
 		let to: H160 = input.read::<Address>()?.into();
 		let token_id = input.read::<TokenId>()?;
 
+		// Transferring a rented token removes its user
+		let event = if from != to &&
+			web3games_token_non_fungible::Pallet::<Runtime>::user(id, token_id.into()).is_some()
+		{
+			let event = Self::update_user_log(handle, token_id, H160::default(), 0);
+			handle.record_log_costs(&[&event])?;
+			Some(event)
+		} else {
+			None
+		};
+
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let from: Runtime::AccountId = Runtime::AddressMapping::into_account_id(from);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let from: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(from);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 			let token_id: Runtime::TokenId = token_id.into();
 
 			// Dispatch call (if enough gas).
@@ -287,6 +395,10 @@ where
 			)?;
 		}
 
+		if let Some(event) = event {
+			event.record(handle)?;
+		}
+
 		// Return call information
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
@@ -303,8 +415,8 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
 			let token_id: Runtime::TokenId = token_id.into();
 
 			// Dispatch call (if enough gas).
@@ -318,6 +430,64 @@ where
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
+	fn mint_batch(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(2)?;
+
+		let to: H160 = input.read::<Address>()?.into();
+		let token_ids: Vec<Runtime::TokenId> = input
+			.read::<Vec<TokenId>>()?
+			.iter()
+			.map(|&a| Runtime::TokenId::from(a))
+			.collect();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::mint_batch { id, to, token_ids },
+			)?;
+		}
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn mint_range(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(3)?;
+
+		let to: H160 = input.read::<Address>()?.into();
+		let start = input.read::<TokenId>()?;
+		let count = input.read::<u32>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let to: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(to);
+			let start: Runtime::TokenId = start.into();
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::mint_range { id, to, start, count },
+			)?;
+		}
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
 	fn burn(
 		id: NonFungibleTokenIdOf<Runtime>,
 		handle: &mut impl PrecompileHandle,
@@ -329,7 +499,7 @@ where
 
 		{
 			let caller: Runtime::AccountId =
-				Runtime::AddressMapping::into_account_id(handle.context().caller);
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
 			let token_id: Runtime::TokenId = token_id.into();
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -408,7 +578,7 @@ where
 		input.expect_arguments(2)?;
 
 		let owner: Runtime::AccountId =
-			Runtime::AddressMapping::into_account_id(input.read::<Address>()?.0);
+			AddressMappingOf::<Runtime>::into_account_id(input.read::<Address>()?.0);
 
 		let token_index = input.read::<TokenIndex>()?.into();
 
@@ -422,4 +592,630 @@ where
 
 		Ok(succeed(EvmDataWriter::new().write(token_id).build()))
 	}
+
+	fn grant_role(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::grant_role { id, role, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn revoke_role(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::revoke_role { id, role, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn has_role(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(2)?;
+		let role = TokenRole::try_from(input.read::<u8>()?).map_err(|_| revert("invalid role"))?;
+		let account = input.read::<Address>()?.0;
+
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+		let has_role: bool =
+			web3games_token_non_fungible::Pallet::<Runtime>::has_role(id, (role, account));
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(has_role).build()))
+	}
+
+	fn transfer_ownership(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let new_owner: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let new_owner: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(new_owner);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::transfer_ownership { id, new_owner },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn freeze(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::freeze { id, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn thaw(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account: H160 = input.read::<Address>()?.into();
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::thaw { id, account },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn is_frozen(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		// Read input.
+		input.expect_arguments(1)?;
+		let account = input.read::<Address>()?.0;
+
+		let account: Runtime::AccountId = AddressMappingOf::<Runtime>::into_account_id(account);
+
+		let is_frozen: bool = web3games_token_non_fungible::Pallet::<Runtime>::is_token_frozen(id) ||
+			web3games_token_non_fungible::Pallet::<Runtime>::is_frozen(id, account);
+
+		// Build output.
+		Ok(succeed(EvmDataWriter::new().write(is_frozen).build()))
+	}
+
+	fn freeze_token(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::freeze_token { id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn thaw_token(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::thaw_token { id },
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn set_attribute(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let token_id = input.read::<TokenId>()?;
+		let key: Vec<u8> = input.read::<Bytes>()?.into();
+		let value: Vec<u8> = input.read::<Bytes>()?.into();
+
+		let event = log1(
+			handle.context().address,
+			SELECTOR_LOG_METADATA_UPDATE,
+			EvmDataWriter::new().write(token_id).build(),
+		);
+		handle.record_log_costs(&[&event])?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let token_id: Runtime::TokenId = token_id.into();
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::set_attribute {
+					id,
+					token_id,
+					key,
+					value,
+				},
+			)?;
+		}
+
+		event.record(handle)?;
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn clear_attribute(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let token_id = input.read::<TokenId>()?;
+		let key: Vec<u8> = input.read::<Bytes>()?.into();
+
+		let event = log1(
+			handle.context().address,
+			SELECTOR_LOG_METADATA_UPDATE,
+			EvmDataWriter::new().write(token_id).build(),
+		);
+		handle.record_log_costs(&[&event])?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let token_id: Runtime::TokenId = token_id.into();
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::clear_attribute {
+					id,
+					token_id,
+					key,
+				},
+			)?;
+		}
+
+		event.record(handle)?;
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn get_attribute(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(2)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+		let key: Vec<u8> = input.read::<Bytes>()?.into();
+
+		let value: Vec<u8> =
+			web3games_token_non_fungible::Pallet::<Runtime>::attribute(id, token_id, &key)
+				.unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write::<Bytes>(value.as_slice().into()).build()))
+	}
+
+	fn set_token_uri(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let token_id = input.read::<TokenId>()?;
+		let uri: Vec<u8> = input.read::<Bytes>()?.into();
+
+		let event = log1(
+			handle.context().address,
+			SELECTOR_LOG_METADATA_UPDATE,
+			EvmDataWriter::new().write(token_id).build(),
+		);
+		handle.record_log_costs(&[&event])?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let token_id: Runtime::TokenId = token_id.into();
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::set_token_uri { id, token_id, uri },
+			)?;
+		}
+
+		event.record(handle)?;
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn freeze_metadata(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let token_id = input.read::<TokenId>()?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let token_id: Runtime::TokenId = token_id.into();
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::freeze_metadata {
+					id,
+					token_id: Some(token_id),
+				},
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn freeze_all_metadata(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::freeze_metadata {
+					id,
+					token_id: None,
+				},
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn is_metadata_frozen(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(1)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+
+		let is_frozen: bool =
+			web3games_token_non_fungible::Pallet::<Runtime>::is_collection_metadata_frozen(id) ||
+				web3games_token_non_fungible::Pallet::<Runtime>::is_metadata_frozen(
+					id, token_id,
+				);
+
+		Ok(succeed(EvmDataWriter::new().write(is_frozen).build()))
+	}
+
+	fn royalty_info(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(2)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+		let sale_price = input.read::<U256>()?;
+
+		let (receiver, royalty) =
+			match web3games_token_non_fungible::Pallet::<Runtime>::royalty(id, token_id) {
+				Some((receiver, basis_points)) => (
+					Runtime::into_evm_address(receiver),
+					sale_price.saturating_mul(basis_points.into()) / U256::from(10_000u32),
+				),
+				None => (H160::default(), U256::zero()),
+			};
+
+		Ok(succeed(EvmDataWriter::new().write::<Address>(receiver.into()).write(royalty).build()))
+	}
+
+	fn locked(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(1)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+
+		if !web3games_token_non_fungible::Pallet::<Runtime>::token_exists(id, token_id) {
+			return Err(revert("token does not exist"))
+		}
+		let locked: bool =
+			web3games_token_non_fungible::Pallet::<Runtime>::is_soulbound(id, token_id);
+
+		Ok(succeed(EvmDataWriter::new().write(locked).build()))
+	}
+
+	fn set_user(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let token_id = input.read::<TokenId>()?;
+		let user: H160 = input.read::<Address>()?.into();
+		let expires = input.read::<u64>()?;
+
+		let event = Self::update_user_log(handle, token_id, user, expires);
+		handle.record_log_costs(&[&event])?;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let token_id: Runtime::TokenId = token_id.into();
+			let user: Option<Runtime::AccountId> = if user == H160::default() {
+				None
+			} else {
+				Some(AddressMappingOf::<Runtime>::into_account_id(user))
+			};
+			let expires =
+				<Runtime as frame_system::Config>::BlockNumber::unique_saturated_from(expires);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::set_user {
+					id,
+					token_id,
+					user,
+					expires,
+				},
+			)?;
+		}
+
+		event.record(handle)?;
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn user_of(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(1)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+
+		let user: H160 = web3games_token_non_fungible::Pallet::<Runtime>::user_of(id, token_id)
+			.map(Runtime::into_evm_address)
+			.unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write::<Address>(user.into()).build()))
+	}
+
+	fn user_expires(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(1)?;
+
+		let token_id: Runtime::TokenId = input.read::<TokenId>()?.into();
+
+		let expires: u64 =
+			web3games_token_non_fungible::Pallet::<Runtime>::user_expires(id, token_id)
+				.unique_saturated_into();
+
+		Ok(succeed(EvmDataWriter::new().write(U256::from(expires)).build()))
+	}
+
+	fn redeem_voucher(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(9)?;
+
+		let token_id = input.read::<TokenId>()?;
+		let recipient: H160 = input.read::<Address>()?.into();
+		let currency: H160 = input.read::<Address>()?.into();
+		let price = input.read::<Balance>()?;
+		let expiry = input.read::<U256>()?;
+		let nonce = input.read::<u64>()?;
+		let v = input.read::<u8>()?;
+		let r = input.read::<H256>()?;
+		let s = input.read::<H256>()?;
+
+		let currency = if currency == H160::default() {
+			None
+		} else {
+			let currency = Runtime::FungibleTokenIdConversion::try_from_address(currency)
+				.ok_or_else(|| revert("invalid currency"))?;
+			Some(currency)
+		};
+		// Expiries beyond u64 never expire.
+		let expiry = <Runtime as frame_system::Config>::BlockNumber::unique_saturated_from(
+			expiry.min(U256::from(u64::MAX)).low_u64(),
+		);
+		let mut signature = [0u8; 65];
+		signature[0..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] = v;
+
+		{
+			let caller: Runtime::AccountId =
+				AddressMappingOf::<Runtime>::into_account_id(handle.context().caller);
+			let voucher = web3games_token_non_fungible::MintVoucher {
+				token_id: token_id.into(),
+				recipient: Some(recipient).filter(|recipient| *recipient != H160::default()),
+				currency,
+				price,
+				expiry,
+				nonce,
+			};
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(caller).into(),
+				web3games_token_non_fungible::Call::<Runtime>::redeem_voucher {
+					id,
+					voucher,
+					signature,
+				},
+			)?;
+		}
+
+		// Return call information
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn voucher_redeemed(
+		id: NonFungibleTokenIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = EvmDataReader::new_skip_selector(handle.input())?;
+		input.expect_arguments(2)?;
+
+		let signer: H160 = input.read::<Address>()?.into();
+		let nonce = input.read::<u64>()?;
+
+		let redeemed: bool = web3games_token_non_fungible::Pallet::<Runtime>::is_voucher_redeemed(
+			id,
+			(signer, nonce),
+		);
+
+		Ok(succeed(EvmDataWriter::new().write(redeemed).build()))
+	}
+
+	fn update_user_log(
+		handle: &impl PrecompileHandle,
+		token_id: TokenId,
+		user: H160,
+		expires: u64,
+	) -> Log {
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_UPDATE_USER,
+			H256::from_slice(&EvmDataWriter::new().write(token_id).build()),
+			user,
+			EvmDataWriter::new().write(expires).build(),
+		)
+	}
 }
@@ -43,8 +43,8 @@ use sp_core::{
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, DispatchInfoOf,
-		Dispatchable, Get, NumberFor, PostDispatchInfoOf, UniqueSaturatedInto,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Convert,
+		DispatchInfoOf, Dispatchable, Get, NumberFor, PostDispatchInfoOf, UniqueSaturatedInto,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
 	ApplyExtrinsicResult, Percent,
@@ -79,7 +79,9 @@ use frame_system::{
 pub use pallet_balances::Call as BalancesCall;
 use pallet_contracts::weights::WeightInfo;
 use pallet_ethereum::{Call::transact, Transaction as EthereumTransaction};
-use pallet_evm::{Account as EVMAccount, EnsureAddressTruncated, HashedAddressMapping, Runner};
+use pallet_evm::{
+	Account as EVMAccount, AddressMapping, EnsureAddressTruncated, HashedAddressMapping, Runner,
+};
 pub use pallet_timestamp::Call as TimestampCall;
 pub use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
@@ -129,10 +131,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("web3games-node"),
 	impl_name: create_runtime_str!("web3games-node"),
 	authoring_version: 1,
-	spec_version: 2,
+	spec_version: 3,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -430,6 +432,14 @@ where
 	}
 }
 
+/// Maps an EVM address to its substrate account, the same way `pallet_evm` does.
+pub struct EvmAddressMapping;
+impl Convert<H160, AccountId> for EvmAddressMapping {
+	fn convert(address: H160) -> AccountId {
+		<Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+	}
+}
+
 parameter_types! {
 	pub BlockGasLimit: U256 = U256::from(u32::max_value());
 	pub PrecompilesValue: Web3GamesPrecompiles<Runtime> = Web3GamesPrecompiles::<_>::new();
@@ -567,6 +577,9 @@ parameter_types! {
 	pub const CreateTokenDeposit: Balance = 500 * MILLICENTS;
 	pub const CreatePoolDeposit: Balance = 500 * MILLICENTS;
 	pub const CreateCollectionDeposit: Balance = 500 * MILLICENTS;
+	pub const AttributeDepositBase: Balance = 10 * MILLICENTS;
+	pub const DepositPerByte: Balance = 1 * MILLICENTS;
+	pub const InventoryDeposit: Balance = 1 * MILLICENTS;
 }
 
 pub fn get_all_pallet_accounts() -> Vec<AccountId> {
@@ -589,6 +602,9 @@ parameter_types! {
 	pub const MarketplacePalletId: PalletId = PalletId(*b"w3g/mpct");
 	pub const FarmingPalletId: PalletId = PalletId(*b"w3g/farm");
 	pub const ProxyPayPalletId: PalletId = PalletId(*b"w3g/prox");
+	pub const AirdropPalletId: PalletId = PalletId(*b"w3g/drop");
+	pub const LootBoxPalletId: PalletId = PalletId(*b"w3g/lbox");
+	pub const CraftingPalletId: PalletId = PalletId(*b"w3g/crft");
 	pub ZeroAccountId: AccountId = AccountId::from([0u8; 32]);
 	pub const StringLimit: u32 = 50;
 }
@@ -635,6 +651,13 @@ impl web3games_token_fungible::Config for Runtime {
 	type StringLimit = StringLimit;
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type Currency = Balances;
+	type ChainId = EthereumChainId;
+	type AddressMapping = EvmAddressMapping;
+	type TokenIdConversion = precompiles::FungibleTokenExtension<Runtime>;
+	type UnixTime = Timestamp;
+	type MaxVestingSchedules = ConstU32<8>;
+	type MinVestedTransfer = ConstU128<GIGAWEI>;
+	type RemoveItemsLimit = ConstU32<1000>;
 	type WeightInfo = web3games_token_fungible::weights::W3GWeight<Runtime>;
 }
 
@@ -646,6 +669,21 @@ impl web3games_token_non_fungible::Config for Runtime {
 	type StringLimit = StringLimit;
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type Currency = Balances;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type MultiToken = TokenMulti;
+	type MaxNestingDepth = ConstU32<5>;
+	type MaxChildren = ConstU32<32>;
+	type Fungibles = TokenFungible;
+	type FungibleTokenIdConversion = precompiles::FungibleTokenExtension<Runtime>;
+	type ChainId = EthereumChainId;
+	type AddressMapping = EvmAddressMapping;
+	type TokenIdConversion = precompiles::NonFungibleTokenExtension<Runtime>;
+	type MaxMintPhases = ConstU32<8>;
+	type MaxBatchMint = ConstU32<100>;
+	type RemoveItemsLimit = ConstU32<1000>;
 	type WeightInfo = web3games_token_non_fungible::weights::W3GWeight<Runtime>;
 }
 
@@ -657,6 +695,14 @@ impl web3games_token_multi::Config for Runtime {
 	type StringLimit = StringLimit;
 	type CreateTokenDeposit = CreateTokenDeposit;
 	type Currency = Balances;
+	type Fungibles = TokenFungible;
+	type FungibleTokenIdConversion = precompiles::FungibleTokenExtension<Runtime>;
+	type ChainId = EthereumChainId;
+	type AddressMapping = EvmAddressMapping;
+	type TokenIdConversion = precompiles::MultiTokenExtension<Runtime>;
+	type MaxInventorySize = ConstU32<512>;
+	type InventoryDeposit = InventoryDeposit;
+	type RemoveItemsLimit = ConstU32<1000>;
 	type WeightInfo = web3games_token_multi::weights::W3GWeight<Runtime>;
 }
 
@@ -672,6 +718,7 @@ impl web3games_exchange::Config for Runtime {
 	type W3GFungibleTokenId = W3GFungibleTokenId;
 	type Currency = Balances;
 	type Randomness = RandomnessCollectiveFlip;
+	type DefaultSwapFee = ConstU16<30>;
 	type WeightInfo = web3games_exchange::weights::W3GWeight<Runtime>;
 }
 
@@ -744,6 +791,37 @@ impl web3games_launchpad::Config for Runtime {
 	type WeightInfo = web3games_launchpad::weights::W3GWeight<Runtime>;
 }
 
+impl web3games_airdrop::Config for Runtime {
+	type Event = Event;
+	type PalletId = AirdropPalletId;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type WeightInfo = web3games_airdrop::weights::W3GWeight<Runtime>;
+}
+
+impl web3games_randomness::Config for Runtime {
+	type RevealDelay = ConstU32<10>;
+}
+
+impl web3games_crafting::Config for Runtime {
+	type Event = Event;
+	type PalletId = CraftingPalletId;
+	type Randomness = Randomness;
+	type MaxIngredients = ConstU32<16>;
+	type MaxOutcomes = ConstU32<8>;
+	type MaxProducts = ConstU32<8>;
+	type MaxReservedRanges = ConstU32<256>;
+	type WeightInfo = web3games_crafting::weights::W3GWeight<Runtime>;
+}
+
+impl web3games_loot_box::Config for Runtime {
+	type Event = Event;
+	type PalletId = LootBoxPalletId;
+	type Randomness = Randomness;
+	type MaxDrops = ConstU32<32>;
+	type MaxReservedRanges = ConstU32<256>;
+	type WeightInfo = web3games_loot_box::weights::W3GWeight<Runtime>;
+}
+
 construct_runtime!(
 	pub enum Runtime where
 		Block = Block,
@@ -786,7 +864,11 @@ construct_runtime!(
 		CallSwitchgear: web3games_call_switchgear,
 		TransactionStorage: pallet_transaction_storage,
 		Farming: web3games_farming,
-		Launchpad: web3games_launchpad
+		Launchpad: web3games_launchpad,
+		Airdrop: web3games_airdrop,
+		Crafting: web3games_crafting,
+		LootBox: web3games_loot_box,
+		Randomness: web3games_randomness,
 	}
 );
 
@@ -922,6 +1004,9 @@ mod benches {
 		[web3games_exchange, Exchange]
 		[web3games_farming, Farming]
 		[web3games_launchpad, Launchpad]
+		[web3games_airdrop, Airdrop]
+		[web3games_crafting, Crafting]
+		[web3games_loot_box, LootBox]
 		[web3games_call_switchgear, CallSwitchgear]
 		[web3games_wrap_currency, WrapCurrency]
 		[web3games_player_id, PlayerId]
@@ -1308,6 +1393,52 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl web3games_token_fungible_rpc_runtime_api::TokenFungibleRuntimeApi<Block, AccountId, BlockNumber> for Runtime {
+		fn balance_of_at(id: u128, who: AccountId, block: BlockNumber) -> Option<Balance> {
+			TokenFungible::balance_of_at(id, &who, block)
+		}
+
+		fn total_supply_at(id: u128, block: BlockNumber) -> Option<Balance> {
+			TokenFungible::total_supply_at(id, block)
+		}
+
+		fn holder_count(id: u128) -> u32 {
+			TokenFungible::holder_count(id)
+		}
+
+		fn holders(id: u128, start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, Balance)> {
+			TokenFungible::holders(id, start_after, limit)
+		}
+	}
+
+	impl web3games_token_non_fungible_rpc_runtime_api::TokenNonFungibleRuntimeApi<Block> for Runtime {
+		fn attribute(id: u128, token_id: u128, key: Vec<u8>) -> Option<Vec<u8>> {
+			<TokenNonFungible as web3games_support::NonFungibleMetadata>::attribute(id, token_id, &key)
+		}
+
+		fn attributes(id: u128, token_id: u128) -> Vec<(Vec<u8>, Vec<u8>)> {
+			TokenNonFungible::attributes(id, token_id)
+		}
+
+		fn nested_tokens(id: u128, token_id: u128, depth: u32) -> Vec<(u32, web3games_support::NestedToken<u128, u128, u128>, u128)> {
+			TokenNonFungible::nested_tokens(id, token_id, depth)
+		}
+	}
+
+	impl web3games_token_multi_rpc_runtime_api::TokenMultiRuntimeApi<Block, AccountId> for Runtime {
+		fn inventory_size(id: u128, who: AccountId) -> u32 {
+			TokenMulti::inventory_size(id, who)
+		}
+
+		fn unindexed_count(id: u128, who: AccountId) -> u32 {
+			TokenMulti::unindexed_count(id, who)
+		}
+
+		fn inventory_of(id: u128, who: AccountId, start_after: Option<u128>, limit: u32) -> Vec<(u128, Balance)> {
+			TokenMulti::inventory_of(id, who, start_after, limit)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -1329,6 +1460,9 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, web3games_exchange, Exchange);
 			list_benchmark!(list, extra, web3games_farming, Farming);
 			list_benchmark!(list, extra, web3games_launchpad, Launchpad);
+			list_benchmark!(list, extra, web3games_airdrop, Airdrop);
+			list_benchmark!(list, extra, web3games_crafting, Crafting);
+			list_benchmark!(list, extra, web3games_loot_box, LootBox);
 			list_benchmark!(list, extra, web3games_call_switchgear, CallSwitchgear);
 			list_benchmark!(list, extra, web3games_wrap_currency, WrapCurrency);
 			list_benchmark!(list, extra, web3games_player_id, PlayerId);
@@ -1373,6 +1507,9 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, web3games_exchange, Exchange);
 			add_benchmark!(params, batches, web3games_farming, Farming);
 			add_benchmark!(params, batches, web3games_launchpad, Launchpad);
+			add_benchmark!(params, batches, web3games_airdrop, Airdrop);
+			add_benchmark!(params, batches, web3games_crafting, Crafting);
+			add_benchmark!(params, batches, web3games_loot_box, LootBox);
 			add_benchmark!(params, batches, web3games_call_switchgear, CallSwitchgear);
 			add_benchmark!(params, batches, web3games_wrap_currency, WrapCurrency);
 			add_benchmark!(params, batches, web3games_player_id, PlayerId);